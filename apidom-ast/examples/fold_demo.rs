@@ -128,6 +128,7 @@ fn create_test_data() -> Element {
                             meta: MetaElement::default(),
                             attributes: AttributesElement::default(),
                             content: 85.5,
+                            is_integer: false,
                         }),
                         Element::String(StringElement::new("92")),
                         Element::Number(NumberElement {
@@ -135,6 +136,7 @@ fn create_test_data() -> Element {
                             meta: MetaElement::default(),
                             attributes: AttributesElement::default(),
                             content: 78.0,
+                            is_integer: true,
                         }),
                     ],
                 })),
@@ -0,0 +1,135 @@
+//! Stateful, step-by-step navigation over an element tree.
+//!
+//! A [`Cursor`] keeps a stack of the elements it descended through, so
+//! interactive tools (REPLs, debuggers) can move into and out of a document
+//! without writing recursive walkers.
+
+use crate::minim_model::{escape_pointer_token, Element};
+
+/// Cursor over an element tree borrowed for `'a`
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    root: &'a Element,
+    /// Elements entered so far, with the key or index used to reach them
+    stack: Vec<(String, &'a Element)>,
+}
+
+impl<'a> Cursor<'a> {
+    /// Create a cursor positioned at `root`
+    pub fn new(root: &'a Element) -> Self {
+        Self {
+            root,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Element the cursor is positioned at
+    pub fn current(&self) -> &'a Element {
+        self.stack.last().map_or(self.root, |(_, element)| element)
+    }
+
+    /// Number of steps taken from the root
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Keys and indices leading from the root to the current element
+    pub fn path(&self) -> Vec<&str> {
+        self.stack.iter().map(|(segment, _)| segment.as_str()).collect()
+    }
+
+    /// JSON pointer of the current element (`""` at the root)
+    pub fn current_pointer(&self) -> String {
+        self.stack
+            .iter()
+            .map(|(segment, _)| format!("/{}", escape_pointer_token(segment)))
+            .collect()
+    }
+
+    /// Move into the member `key` of the current object.
+    ///
+    /// Returns the new current element, or `None` (leaving the cursor where
+    /// it was) when the current element is not an object or has no such key.
+    pub fn enter_key(&mut self, key: &str) -> Option<&'a Element> {
+        let Element::Object(obj) = self.current() else {
+            return None;
+        };
+        let value = obj.get(key)?;
+        self.stack.push((key.to_string(), value));
+        Some(value)
+    }
+
+    /// Move into item `index` of the current array.
+    ///
+    /// Returns the new current element, or `None` (leaving the cursor where
+    /// it was) when the current element is not an array or is too short.
+    pub fn enter_index(&mut self, index: usize) -> Option<&'a Element> {
+        let Element::Array(arr) = self.current() else {
+            return None;
+        };
+        let item = arr.content.get(index)?;
+        self.stack.push((index.to_string(), item));
+        Some(item)
+    }
+
+    /// Move back to the parent element, returning it, or `None` at the root
+    pub fn parent(&mut self) -> Option<&'a Element> {
+        self.stack.pop()?;
+        Some(self.current())
+    }
+
+    /// Move back to the root
+    pub fn reset(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fold::json_source_to_ast;
+
+    #[test]
+    fn test_navigate_to_operation() {
+        let doc = json_source_to_ast(
+            r#"{ "paths": { "/pets": { "get": { "operationId": "listPets" } } }, "tags": [ { "name": "pets" } ] }"#,
+        );
+        let mut cursor = Cursor::new(&doc);
+        assert_eq!(cursor.current_pointer(), "");
+
+        cursor.enter_key("paths").unwrap();
+        cursor.enter_key("/pets").unwrap();
+        let operation = cursor.enter_key("get").unwrap();
+
+        assert_eq!(cursor.current_pointer(), "/paths/~1pets/get");
+        assert_eq!(cursor.path(), vec!["paths", "/pets", "get"]);
+        assert_eq!(
+            operation.as_object().unwrap().get("operationId").unwrap().as_string().unwrap().content,
+            "listPets"
+        );
+
+        // A failed step leaves the cursor in place
+        assert!(cursor.enter_key("post").is_none());
+        assert!(cursor.enter_index(0).is_none());
+        assert_eq!(cursor.depth(), 3);
+
+        cursor.parent().unwrap();
+        assert_eq!(cursor.current_pointer(), "/paths/~1pets");
+    }
+
+    #[test]
+    fn test_navigate_arrays_and_back_to_root() {
+        let doc = json_source_to_ast(r#"{ "tags": [ { "name": "pets" } ] }"#);
+        let mut cursor = Cursor::new(&doc);
+
+        cursor.enter_key("tags").unwrap();
+        cursor.enter_index(0).unwrap();
+        cursor.enter_key("name").unwrap();
+        assert_eq!(cursor.current_pointer(), "/tags/0/name");
+        assert_eq!(cursor.current().as_string().unwrap().content, "pets");
+
+        cursor.reset();
+        assert_eq!(cursor.depth(), 0);
+        assert!(cursor.parent().is_none());
+    }
+}
@@ -70,6 +70,7 @@ pub trait Fold {
             meta: self.fold_meta_element(element.meta),
             attributes: self.fold_attributes_element(element.attributes),
             content: element.content,
+            is_integer: element.is_integer,
         })
     }
 
@@ -544,6 +545,7 @@ pub mod folders {
                             meta: str.meta.clone(),
                             attributes: str.attributes.clone(),
                             content: num,
+                            is_integer: is_integer_token(&str.content),
                         })
                     } else {
                         element
@@ -739,6 +741,13 @@ impl JsonFolder {
                     }
                 })
             );
+            meta.properties.insert(
+                "sourceMap".to_string(),
+                serde_json::json!({
+                    "start": node.start_byte,
+                    "end": node.end_byte
+                })
+            );
         }
         
         if node.has_error() {
@@ -925,6 +934,7 @@ impl FoldFromCst for JsonFolder {
             meta,
             attributes: AttributesElement::default(),
             content,
+            is_integer: is_integer_token(&text),
         })
     }
     
@@ -977,6 +987,11 @@ impl FoldFromCst for JsonFolder {
     }
 }
 
+/// Whether a JSON number token is written without a fraction or exponent
+fn is_integer_token(text: &str) -> bool {
+    !text.contains(['.', 'e', 'E'])
+}
+
 /// Convenience function to convert JSON CST to AST
 /// 
 /// This function provides a simple interface for converting a JSON CST
@@ -1022,6 +1037,92 @@ pub fn json_source_to_ast(source: &str) -> Element {
     json_cst_to_ast(&cst)
 }
 
+/// Convenience function to convert YAML source directly to AST
+/// 
+/// The document is loaded with anchors and aliases (including `<<` merge
+/// keys) expanded, converted to JSON and folded with [`json_source_to_ast`],
+/// so YAML and JSON sources produce the same elements. Non-string mapping
+/// keys such as response codes are stringified. JSON input is valid YAML and
+/// converts the same way.
+/// 
+/// # Arguments
+/// * `source` - The YAML source code to parse and convert
+/// 
+/// # Returns
+/// An Element representing the AST, or a null element if the source is not
+/// valid YAML
+/// 
+/// # Example
+/// ```ignore
+/// use apidom_ast::fold::yaml_source_to_ast;
+/// 
+/// let ast = yaml_source_to_ast("name: Alice\nage: 25\n");
+/// ```
+pub fn yaml_source_to_ast(source: &str) -> Element {
+    let Ok(mut yaml) = serde_yaml::from_str::<serde_yaml::Value>(source) else {
+        return Element::Null(NullElement::default());
+    };
+    if yaml.apply_merge().is_err() {
+        return Element::Null(NullElement::default());
+    }
+    let mut json = String::new();
+    write_yaml_as_json(&yaml, &mut json);
+    json_source_to_ast(&json)
+}
+
+/// Write a YAML value as JSON text, keeping mapping order
+fn write_yaml_as_json(yaml: &serde_yaml::Value, out: &mut String) {
+    use serde_yaml::Value as Yaml;
+
+    match yaml {
+        Yaml::Null => out.push_str("null"),
+        Yaml::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Yaml::Number(n) if n.as_f64().is_some_and(|f| !f.is_finite()) => out.push_str("null"),
+        Yaml::Number(n) => out.push_str(&n.to_string()),
+        Yaml::String(s) => out.push_str(&Value::String(s.clone()).to_string()),
+        Yaml::Sequence(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_yaml_as_json(item, out);
+            }
+            out.push(']');
+        }
+        Yaml::Mapping(mapping) => {
+            out.push('{');
+            for (index, (key, value)) in mapping.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(yaml_key_to_string(key)).to_string());
+                out.push(':');
+                write_yaml_as_json(value, out);
+            }
+            out.push('}');
+        }
+        Yaml::Tagged(tagged) => write_yaml_as_json(&tagged.value, out),
+    }
+}
+
+/// Stringify a mapping key; complex keys are written as JSON text
+fn yaml_key_to_string(key: &serde_yaml::Value) -> String {
+    use serde_yaml::Value as Yaml;
+
+    match key {
+        Yaml::String(s) => s.clone(),
+        Yaml::Null => "null".to_string(),
+        Yaml::Bool(b) => b.to_string(),
+        Yaml::Number(n) => n.to_string(),
+        other => {
+            let mut json = String::new();
+            write_yaml_as_json(other, &mut json);
+            json
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1067,6 +1168,7 @@ mod tests {
                     meta: MetaElement::default(),
                     attributes: AttributesElement::default(),
                     content: 42.0,
+                    is_integer: true,
                 }),
                 Element::String(StringElement::new("test2")),
             ],
@@ -1189,6 +1291,68 @@ mod cst_tests {
     use super::*;
     use apidom_cst::CstParser;
 
+    #[test]
+    fn test_source_map_offsets() {
+        let source = r#"{"openapi": "3.0.3", "info": {"title": "Pets"}}"#;
+        let ast = json_source_to_ast(source);
+        let info = ast.as_object().and_then(|o| o.get("info")).and_then(Element::as_object).unwrap();
+
+        let start = source.find(r#"{"title""#).unwrap();
+        let end = start + r#"{"title": "Pets"}"#.len();
+        assert_eq!(
+            info.meta.properties.get("sourceMap"),
+            Some(&serde_json::json!({ "start": start, "end": end }))
+        );
+        assert_eq!(&source[start..end], r#"{"title": "Pets"}"#);
+
+        let mut folder = JsonFolder::with_options(false, false);
+        let plain = folder.fold_from_cst(&CstParser::parse(source));
+        assert!(!plain.as_object().unwrap().meta.properties.contains_key("sourceMap"));
+    }
+
+    #[test]
+    fn test_yaml_source_conversion() {
+        let source = r#"
+defaults: &defaults
+  type: string
+  nullable: true
+responses:
+  200:
+    description: ok
+  default:
+    description: error
+name:
+  <<: *defaults
+  maxLength: 10
+alias: *defaults
+"#;
+        let ast = yaml_source_to_ast(source);
+        let Element::Object(root) = &ast else {
+            panic!("Expected Object element");
+        };
+
+        let responses = root.get("responses").and_then(Element::as_object).unwrap();
+        assert!(responses.has_key("200"));
+        assert!(responses.has_key("default"));
+
+        let expected = serde_json::json!({ "type": "string", "nullable": true });
+        assert_eq!(root.get("alias").unwrap().to_value(), expected);
+        let name = root.get("name").and_then(Element::as_object).unwrap();
+        assert!(name.has_key("type") && name.has_key("maxLength"));
+        let keys: Vec<&str> = root
+            .content
+            .iter()
+            .filter_map(|m| m.key.as_string())
+            .map(|k| k.content.as_str())
+            .collect();
+        assert_eq!(keys, vec!["defaults", "responses", "name", "alias"]);
+        assert!(!name.has_key("<<"));
+
+        let json = json_source_to_ast(r#"{"a": [1, "two", null]}"#);
+        assert_eq!(yaml_source_to_ast(r#"{"a": [1, "two", null]}"#).to_value(), json.to_value());
+        assert!(matches!(yaml_source_to_ast("a: [unclosed"), Element::Null(_)));
+    }
+
     #[test]
     fn test_json_object_conversion() {
         let source = r#"{"name": "John", "age": 30}"#;
@@ -1,6 +1,8 @@
 pub mod minim_model;
 pub mod fold;
+pub mod cursor;
 
 // Re-export commonly used items for convenience
 pub use fold::{Fold, DefaultFolder, CompositeFolder};
-pub use minim_model::{Element, ElementRegistry};
\ No newline at end of file
+pub use minim_model::{Element, ElementRegistry};
+pub use cursor::Cursor;
\ No newline at end of file
@@ -67,6 +67,226 @@ impl Element {
             Element::Custom(_, e) => e.content.clone(),
         }
     }
+
+    /// Deep equality of content, ignoring `meta`, `attributes` and `classes`
+    ///
+    /// Object members are compared by key regardless of their order, array
+    /// items in order.
+    pub fn structural_eq(&self, other: &Element) -> bool {
+        match (self, other) {
+            (Element::Null(_), Element::Null(_)) => true,
+            (Element::Boolean(a), Element::Boolean(b)) => a.content == b.content,
+            (Element::Number(a), Element::Number(b)) => a.content == b.content,
+            (Element::String(a), Element::String(b)) => a.content == b.content,
+            (Element::Array(a), Element::Array(b)) => {
+                a.content.len() == b.content.len()
+                    && a.content.iter().zip(&b.content).all(|(x, y)| x.structural_eq(y))
+            }
+            (Element::Object(a), Element::Object(b)) => {
+                a.content.len() == b.content.len()
+                    && a.content.iter().all(|member| match member.key.as_ref() {
+                        Element::String(key) => b
+                            .get(&key.content)
+                            .is_some_and(|value| member.value.structural_eq(value)),
+                        key => b.content.iter().any(|m| {
+                            m.key.structural_eq(key) && m.value.structural_eq(&member.value)
+                        }),
+                    })
+            }
+            (Element::Member(a), Element::Member(b)) => {
+                a.key.structural_eq(&b.key) && a.value.structural_eq(&b.value)
+            }
+            (Element::Ref(a), Element::Ref(b)) => a.path == b.path,
+            (Element::Link(a), Element::Link(b)) => a.relation == b.relation && a.href == b.href,
+            (Element::Custom(a_name, a), Element::Custom(b_name, b)) => {
+                a_name == b_name && a.content == b.content
+            }
+            _ => false,
+        }
+    }
+
+    /// Every `$ref` string value in the tree, in document order
+    pub fn collect_refs(&self) -> Vec<String> {
+        self.collect_refs_with_pointers()
+            .into_iter()
+            .map(|(_, reference)| reference)
+            .collect()
+    }
+
+    /// Every `$ref` string value in the tree, in document order, paired with
+    /// the JSON Pointer of the object holding it
+    pub fn collect_refs_with_pointers(&self) -> Vec<(String, String)> {
+        let mut refs = Vec::new();
+        self.collect_refs_at(&mut String::new(), &mut refs);
+        refs
+    }
+
+    fn collect_refs_at(&self, pointer: &mut String, refs: &mut Vec<(String, String)>) {
+        fn descend(pointer: &mut String, segment: &str, child: &Element, refs: &mut Vec<(String, String)>) {
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&escape_pointer_token(segment));
+            child.collect_refs_at(pointer, refs);
+            pointer.truncate(len);
+        }
+        match self {
+            Element::Object(obj) => {
+                if let Some(Element::String(reference)) = obj.get("$ref") {
+                    refs.push((pointer.clone(), reference.content.clone()));
+                }
+                for member in &obj.content {
+                    if let Element::String(key) = member.key.as_ref() {
+                        descend(pointer, &key.content, &member.value, refs);
+                    }
+                }
+            }
+            Element::Array(arr) => {
+                for (index, item) in arr.content.iter().enumerate() {
+                    descend(pointer, &index.to_string(), item, refs);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Serialize as compact JSON text, keeping object member order
+    ///
+    /// Numbers written as integers keep their integer form; classes,
+    /// metadata and attributes are not serialized.
+    pub fn to_json_string(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out, None, 0);
+        out
+    }
+
+    /// Serialize as JSON text indented by two spaces per level, see
+    /// [`Element::to_json_string`]
+    pub fn to_json_string_pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out, Some(2), 0);
+        out
+    }
+
+    fn write_json(&self, out: &mut String, indent: Option<usize>, depth: usize) {
+        match self {
+            Element::Null(_) | Element::Member(_) => out.push_str("null"),
+            Element::Boolean(b) => out.push_str(if b.content { "true" } else { "false" }),
+            Element::Number(n) => match n.as_i64() {
+                Some(i) => out.push_str(&i.to_string()),
+                None => out.push_str(&self.to_value().to_string()),
+            },
+            Element::String(_) | Element::Ref(_) | Element::Link(_) | Element::Custom(..) => {
+                out.push_str(&self.to_value().to_string())
+            }
+            Element::Array(arr) => {
+                write_json_container(out, ('[', ']'), &arr.content, indent, depth, |out, item| {
+                    item.write_json(out, indent, depth + 1)
+                })
+            }
+            Element::Object(obj) => {
+                write_json_container(out, ('{', '}'), &obj.content, indent, depth, |out, member| {
+                    let key = match member.key.as_ref() {
+                        Element::String(k) => k.content.clone(),
+                        other => other.to_json_string(),
+                    };
+                    out.push_str(&Value::String(key).to_string());
+                    out.push_str(if indent.is_some() { ": " } else { ":" });
+                    member.value.write_json(out, indent, depth + 1);
+                })
+            }
+        }
+    }
+
+    /// Collect size statistics for this element tree in a single traversal
+    pub fn statistics(&self) -> ElementStats {
+        let mut stats = ElementStats::default();
+        stats.record(self, 1);
+        stats
+    }
+}
+
+/// Write `items` between `delimiters`, one per line when indenting
+fn write_json_container<T>(
+    out: &mut String,
+    delimiters: (char, char),
+    items: &[T],
+    indent: Option<usize>,
+    depth: usize,
+    mut write_item: impl FnMut(&mut String, &T),
+) {
+    out.push(delimiters.0);
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        if let Some(width) = indent {
+            out.push('\n');
+            out.push_str(&" ".repeat(width * (depth + 1)));
+        }
+        write_item(out, item);
+    }
+    if let Some(width) = indent
+        && !items.is_empty()
+    {
+        out.push('\n');
+        out.push_str(&" ".repeat(width * depth));
+    }
+    out.push(delimiters.1);
+}
+
+/// Size statistics of an element tree, see [`Element::statistics`]
+///
+/// Member keys are not counted as strings; each key/value pair counts once
+/// towards `members` instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElementStats {
+    pub objects: usize,
+    pub arrays: usize,
+    pub strings: usize,
+    pub numbers: usize,
+    pub booleans: usize,
+    pub nulls: usize,
+    /// Ref, Link and Custom elements
+    pub others: usize,
+    /// Total number of object members
+    pub members: usize,
+    /// Maximum nesting depth, the root being at depth 1
+    pub max_depth: usize,
+}
+
+impl ElementStats {
+    /// Total number of value elements (members excluded)
+    pub fn total(&self) -> usize {
+        self.objects + self.arrays + self.strings + self.numbers + self.booleans + self.nulls + self.others
+    }
+
+    fn record(&mut self, element: &Element, depth: usize) {
+        self.max_depth = self.max_depth.max(depth);
+        match element {
+            Element::Null(_) => self.nulls += 1,
+            Element::Boolean(_) => self.booleans += 1,
+            Element::Number(_) => self.numbers += 1,
+            Element::String(_) => self.strings += 1,
+            Element::Array(arr) => {
+                self.arrays += 1;
+                for item in &arr.content {
+                    self.record(item, depth + 1);
+                }
+            }
+            Element::Object(obj) => {
+                self.objects += 1;
+                for member in &obj.content {
+                    self.members += 1;
+                    self.record(&member.value, depth + 1);
+                }
+            }
+            Element::Member(member) => {
+                self.members += 1;
+                self.record(&member.value, depth);
+            }
+            Element::Ref(_) | Element::Link(_) | Element::Custom(..) => self.others += 1,
+        }
+    }
 }
 
 pub struct ElementRegistry {
@@ -144,6 +364,23 @@ impl ObjectElement {
         self.get_member(key).map(|m| m.value.as_ref())
     }
 
+    /// Element at an RFC 6901 JSON Pointer such as
+    /// `/components/schemas/User/properties/id`, relative to this object.
+    /// Numeric segments index arrays; `~1` and `~0` decode to `/` and `~`.
+    /// Returns `None` for the empty pointer and for any missing segment.
+    pub fn get_path(&self, pointer: &str) -> Option<&Element> {
+        let mut segments = pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"));
+        let first = self.get(&segments.next()?)?;
+        segments.try_fold(first, |current, segment| match current {
+            Element::Object(obj) => obj.get(&segment),
+            Element::Array(arr) => array_index(&segment).and_then(|index| arr.get(index)),
+            _ => None,
+        })
+    }
+
     pub fn set(&mut self, key: &str, value: Element) {
         if let Some(member) = self.content.iter_mut().find(|m| {
             matches!(
@@ -165,6 +402,20 @@ impl ObjectElement {
     }
 }
 
+/// Escape a key for use as a JSON pointer segment (`~` as `~0`, `/` as `~1`)
+pub fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Array index of a pointer segment: decimal digits without leading zeros
+fn array_index(segment: &str) -> Option<usize> {
+    let digits = !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit());
+    if !digits || (segment.len() > 1 && segment.starts_with('0')) {
+        return None;
+    }
+    segment.parse().ok()
+}
+
 impl StringElement {
     pub fn new(s: &str) -> Self {
         Self {
@@ -247,6 +498,17 @@ pub struct NumberElement {
     pub meta: MetaElement,
     pub attributes: AttributesElement,
     pub content: f64,
+    /// Whether the number was written as an integer (no fraction or
+    /// exponent), which `content` alone cannot tell for values like `42.0`
+    pub is_integer: bool,
+}
+
+impl NumberElement {
+    /// The value as an integer, if the number was written as one
+    pub fn as_i64(&self) -> Option<i64> {
+        let in_range = self.content >= i64::MIN as f64 && self.content < i64::MAX as f64;
+        (self.is_integer && self.content.fract() == 0.0 && in_range).then_some(self.content as i64)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -434,3 +696,148 @@ pub trait SchemaElement {
         self.set_field(key, Element::Object(value));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fold::json_source_to_ast;
+
+    #[test]
+    fn test_statistics() {
+        let doc = json_source_to_ast(
+            r#"{
+                "name": "pet",
+                "tags": ["a", "b"],
+                "meta": { "age": 3, "vaccinated": true, "owner": null }
+            }"#,
+        );
+
+        let stats = doc.statistics();
+        assert_eq!(
+            stats,
+            ElementStats {
+                objects: 2,
+                arrays: 1,
+                strings: 3,
+                numbers: 1,
+                booleans: 1,
+                nulls: 1,
+                others: 0,
+                members: 6,
+                max_depth: 3,
+            }
+        );
+        assert_eq!(stats.total(), 9);
+    }
+
+    #[test]
+    fn test_json_string_round_trip() {
+        let source = r#"{ "b": 1, "a": [2.5, 2.0, -3, true, null], "s": "quote \" and \\", "o": {}, "e": [] }"#;
+        let doc = json_source_to_ast(source);
+
+        let compact = doc.to_json_string();
+        assert_eq!(compact, r#"{"b":1,"a":[2.5,2.0,-3,true,null],"s":"quote \" and \\","o":{},"e":[]}"#);
+
+        let pretty = doc.to_json_string_pretty();
+        assert!(pretty.starts_with("{\n  \"b\": 1,\n  \"a\": [\n    2.5,"));
+        assert!(pretty.ends_with("\"o\": {},\n  \"e\": []\n}"));
+
+        for text in [compact, pretty] {
+            let reparsed = json_source_to_ast(&text);
+            assert_eq!(reparsed.to_value(), doc.to_value());
+            assert_eq!(reparsed.to_json_string(), doc.to_json_string());
+        }
+    }
+
+    #[test]
+    fn test_structural_eq_ignores_metadata() {
+        let doc = json_source_to_ast(r#"{ "info": { "title": "Pets", "version": "1.0" }, "tags": ["a", "b"] }"#);
+
+        let mut annotated = json_source_to_ast(r#"{ "tags": ["a", "b"], "info": { "version": "1.0", "title": "Pets" } }"#);
+        if let Element::Object(root) = &mut annotated {
+            root.add_class("openapi");
+            root.meta.properties.insert("validated".to_string(), Value::Bool(true));
+        }
+        assert!(doc.structural_eq(&annotated));
+        assert!(annotated.structural_eq(&doc));
+
+        let changed = json_source_to_ast(r#"{ "info": { "title": "Pets", "version": "2.0" }, "tags": ["a", "b"] }"#);
+        assert!(!doc.structural_eq(&changed));
+
+        let reordered = json_source_to_ast(r#"{ "info": { "title": "Pets", "version": "1.0" }, "tags": ["b", "a"] }"#);
+        assert!(!doc.structural_eq(&reordered));
+
+        let extra = json_source_to_ast(r#"{ "info": { "title": "Pets", "version": "1.0" }, "tags": ["a", "b"], "x": 1 }"#);
+        assert!(!doc.structural_eq(&extra));
+    }
+
+    #[test]
+    fn test_collect_refs_in_deep_chain() {
+        let doc = json_source_to_ast(
+            r##"{
+                "openapi": "3.0.3",
+                "paths": { "/values": { "get": { "responses": { "200": { "description": "ok",
+                    "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Level1" } } } } } } } },
+                "components": { "schemas": {
+                    "Level1": { "$ref": "#/components/schemas/Level2" },
+                    "Level2": { "$ref": "#/components/schemas/Level3" },
+                    "Level3": { "$ref": "#/components/schemas/Level4" },
+                    "Level4": { "$ref": "#/components/schemas/Final" },
+                    "Final": { "type": "object", "properties": { "value": { "type": "string" } } }
+                } }
+            }"##,
+        );
+
+        let refs = doc.collect_refs();
+        assert_eq!(
+            refs,
+            ["Level1", "Level2", "Level3", "Level4", "Final"]
+                .map(|name| format!("#/components/schemas/{}", name))
+        );
+
+        let located = doc.collect_refs_with_pointers();
+        assert_eq!(
+            located[0].0,
+            "/paths/~1values/get/responses/200/content/application~1json/schema"
+        );
+        assert_eq!(located[1].0, "/components/schemas/Level1");
+    }
+
+    #[test]
+    fn test_number_integer_flag() {
+        let doc = json_source_to_ast(r#"{ "count": 42, "ratio": 3.14, "whole": 2.0, "big": 1e3 }"#);
+        let number = |key: &str| doc.as_object().and_then(|o| o.get(key)).and_then(Element::as_number).unwrap();
+
+        assert!(number("count").is_integer);
+        assert_eq!(number("count").as_i64(), Some(42));
+        assert!(!number("ratio").is_integer);
+        assert_eq!(number("ratio").as_i64(), None);
+        assert!(!number("whole").is_integer);
+        assert!(!number("big").is_integer);
+    }
+
+    #[test]
+    fn test_get_path() {
+        let doc = json_source_to_ast(
+            r#"{
+                "components": { "schemas": { "User": { "properties": { "id": { "type": "integer" } } } } },
+                "servers": [ { "url": "https://a" }, { "url": "https://b" } ],
+                "paths": { "/users/{id}": { "get": {} } }
+            }"#,
+        );
+        let root = doc.as_object().unwrap();
+
+        let id = root.get_path("/components/schemas/User/properties/id").unwrap();
+        assert_eq!(id.to_value(), serde_json::json!({ "type": "integer" }));
+
+        let url = root.get_path("/servers/1/url").and_then(Element::as_string).unwrap();
+        assert_eq!(url.content, "https://b");
+        assert!(root.get_path("/paths/~1users~1{id}/get").is_some());
+
+        assert!(root.get_path("/components/schemas/Missing").is_none());
+        assert!(root.get_path("/servers/2/url").is_none());
+        assert!(root.get_path("/servers/01/url").is_none());
+        assert!(root.get_path("/servers/0/url/deeper").is_none());
+        assert!(root.get_path("").is_none());
+    }
+}
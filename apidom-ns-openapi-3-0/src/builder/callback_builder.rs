@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use crate::elements::callback::CallbackElement;
 use serde_json::Value;
@@ -177,6 +178,7 @@ where
 pub fn build_and_decorate_callback(element: &Element, folder: Option<&mut dyn Fold>) -> Option<CallbackElement> {
     with_callback_builder(element, folder, |callback| {
         callback.decorate_path_items_with_expressions();
+        decorate_element(&mut callback.object, "callback");
     })
 }
 
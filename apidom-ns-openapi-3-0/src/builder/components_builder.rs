@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::components::ComponentsElement;
@@ -75,6 +76,7 @@ pub fn build_and_decorate_components(
             add_specification_extension_metadata(&mut components_element);
         }
         
+        decorate_element(&mut components_element.object, "components");
         Some(components_element)
     } else {
         None
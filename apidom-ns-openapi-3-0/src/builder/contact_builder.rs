@@ -68,6 +68,7 @@
 //! - ✅ Reference handling
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::contact::ContactElement;
@@ -156,6 +157,7 @@ where
     // Add processing metadata
     add_processing_metadata(&mut contact);
     
+    decorate_element(&mut contact.object, "contact");
     Some(contact)
 }
 
@@ -286,6 +288,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 12345.0,
+            is_integer: true,
         }));
         // Test type conversion from boolean to string
         obj.set("email", Element::Boolean(BooleanElement::new(true)));
@@ -0,0 +1,175 @@
+//! Uniform decoration shared by the `build_and_decorate_*` builders.
+//!
+//! Every decorated element carries the same markers, so downstream tools can
+//! tell built elements from raw ones regardless of which builder ran.
+//!
+//! Custom per-element-type decorators can be added with a
+//! [`DecoratorRegistry`]; while it is installed on the current thread, every
+//! `build_and_decorate_*` builder runs the matching decorators after the
+//! uniform markers are set.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use apidom_ast::minim_model::ObjectElement;
+use serde_json::Value;
+
+/// Metadata key naming the producer of a built element
+pub const BUILT_BY_KEY: &str = "built-by";
+/// Value stored under [`BUILT_BY_KEY`]
+pub const BUILT_BY: &str = "apidom";
+/// Metadata key holding the version of the builders that produced the element
+pub const BUILDER_VERSION_KEY: &str = "builder-version";
+
+/// Set the element type and the uniform build markers on a built element
+pub fn decorate_element(obj: &mut ObjectElement, element_type: &str) {
+    obj.set_element_type(element_type);
+    obj.meta.properties.insert(
+        "element-type".to_string(),
+        Value::String(element_type.to_string()),
+    );
+    obj.meta.properties.insert(
+        BUILT_BY_KEY.to_string(),
+        Value::String(BUILT_BY.to_string()),
+    );
+    obj.meta.properties.insert(
+        BUILDER_VERSION_KEY.to_string(),
+        Value::String(env!("CARGO_PKG_VERSION").to_string()),
+    );
+    ACTIVE_DECORATORS.with(|active| {
+        if let Some(registry) = active.borrow().as_ref() {
+            registry.apply(element_type, obj);
+        }
+    });
+}
+
+/// Whether `obj` went through a `build_and_decorate_*` builder
+pub fn is_decorated(obj: &ObjectElement) -> bool {
+    obj.meta.properties.get(BUILT_BY_KEY) == Some(&Value::String(BUILT_BY.to_string()))
+}
+
+/// Post-build decorator for one element type
+pub type Decorator = Box<dyn Fn(&mut ObjectElement)>;
+
+thread_local! {
+    static ACTIVE_DECORATORS: RefCell<Option<DecoratorRegistry>> = const { RefCell::new(None) };
+}
+
+/// Custom decorators keyed by element type (`schema`, `operation`, ...)
+#[derive(Default)]
+pub struct DecoratorRegistry {
+    decorators: HashMap<String, Vec<Decorator>>,
+}
+
+impl DecoratorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `decorator` on every element of `element_type` after it is built;
+    /// decorators of the same type run in registration order
+    pub fn register<D>(&mut self, element_type: &str, decorator: D)
+    where
+        D: Fn(&mut ObjectElement) + 'static,
+    {
+        self.decorators
+            .entry(element_type.to_string())
+            .or_default()
+            .push(Box::new(decorator));
+    }
+
+    /// Builder-style variant of [`DecoratorRegistry::register`]
+    pub fn with_decorator<D>(mut self, element_type: &str, decorator: D) -> Self
+    where
+        D: Fn(&mut ObjectElement) + 'static,
+    {
+        self.register(element_type, decorator);
+        self
+    }
+
+    pub fn has_decorators(&self, element_type: &str) -> bool {
+        self.decorators.get(element_type).is_some_and(|d| !d.is_empty())
+    }
+
+    /// Run the decorators registered for `element_type` on `obj`
+    pub fn apply(&self, element_type: &str, obj: &mut ObjectElement) {
+        for decorator in self.decorators.get(element_type).into_iter().flatten() {
+            decorator(obj);
+        }
+    }
+
+    /// Install the registry on the current thread while `f` runs; the
+    /// previously installed registry, if any, is restored afterwards
+    pub fn scoped<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<DecoratorRegistry>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                ACTIVE_DECORATORS.with(|active| *active.borrow_mut() = previous);
+            }
+        }
+
+        let previous = ACTIVE_DECORATORS.with(|active| active.borrow_mut().replace(self));
+        let _restore = Restore(previous);
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::info_builder::build_and_decorate_info;
+    use crate::fold::OpenApiBuilderFolder;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_built_info_carries_uniform_markers() {
+        let raw = json_source_to_ast(r#"{ "title": "Pets", "version": "1.0" }"#);
+        assert!(!is_decorated(raw.as_object().unwrap()));
+
+        let info = build_and_decorate_info(&raw, None::<&mut OpenApiBuilderFolder>).unwrap();
+        assert!(is_decorated(&info.object));
+        assert_eq!(info.object.element, "info");
+        assert_eq!(
+            info.object.meta.properties.get("element-type"),
+            Some(&Value::String("info".to_string()))
+        );
+        assert_eq!(
+            info.object.meta.properties.get(BUILDER_VERSION_KEY),
+            Some(&Value::String(env!("CARGO_PKG_VERSION").to_string()))
+        );
+    }
+
+    #[test]
+    fn test_registered_decorator_runs_for_its_element_type_only() {
+        use crate::builder::schema_builder::build_and_decorate_schema;
+
+        fn has_custom_class(obj: &ObjectElement) -> bool {
+            obj.classes
+                .content
+                .iter()
+                .any(|c| c.as_string().is_some_and(|s| s.content == "custom-class"))
+        }
+
+        let schema = json_source_to_ast(r#"{ "type": "object", "properties": { "name": { "type": "string" } } }"#);
+        let info = json_source_to_ast(r#"{ "title": "Pets", "version": "1.0" }"#);
+
+        let registry = DecoratorRegistry::new()
+            .with_decorator("schema", |obj: &mut ObjectElement| obj.add_class("custom-class"));
+        assert!(registry.has_decorators("schema"));
+        assert!(!registry.has_decorators("info"));
+
+        let (built_schema, built_info) = registry.scoped(|| {
+            (
+                build_and_decorate_schema(&schema, None::<&mut OpenApiBuilderFolder>).unwrap(),
+                build_and_decorate_info(&info, None::<&mut OpenApiBuilderFolder>).unwrap(),
+            )
+        });
+        assert!(has_custom_class(&built_schema.base.object));
+        assert!(!has_custom_class(&built_info.object));
+
+        let unregistered = build_and_decorate_schema(&schema, None::<&mut OpenApiBuilderFolder>).unwrap();
+        assert!(!has_custom_class(&unregistered.base.object));
+    }
+}
@@ -49,6 +49,7 @@
 //! - ❌ Specification extensions (disabled by design, matching TypeScript)
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::discriminator::DiscriminatorElement;
@@ -133,6 +134,7 @@ where
     add_processing_metadata(&mut discriminator);
     add_spec_path_metadata(&mut discriminator);
     
+    decorate_element(&mut discriminator.object, "discriminator");
     Some(discriminator)
 }
 
@@ -292,6 +294,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         }));
 
         let mut folder = DefaultFolder;
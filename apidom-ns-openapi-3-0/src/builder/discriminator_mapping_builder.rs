@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::discriminator_mapping::DiscriminatorMappingElement;
@@ -51,6 +52,7 @@ where
     add_processing_metadata(&mut mapping);
     add_spec_path_metadata(&mut mapping);
     
+    decorate_element(&mut mapping.object, "discriminatorMapping");
     Some(mapping)
 }
 
@@ -164,6 +166,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         }));
         // Test type conversion from boolean to string
         obj.set("boolKey", Element::Boolean(BooleanElement::new(true)));
@@ -38,6 +38,7 @@
 //! - ✅ Polymorphic handling (ReferenceLike vs Header)
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::encoding::EncodingElement;
@@ -141,6 +142,7 @@ where
     add_processing_metadata(&mut encoding);
     add_spec_path_metadata(&mut encoding);
     
+    decorate_element(&mut encoding.object, "encoding");
     Some(encoding)
 }
 
@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::encoding_headers::EncodingHeadersElement;
@@ -63,6 +64,7 @@ where
     // Add processing metadata
     add_processing_metadata(&mut headers);
     
+    decorate_element(&mut headers.object, "encodingHeaders");
     Some(headers)
 }
 
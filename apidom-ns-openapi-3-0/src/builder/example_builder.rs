@@ -75,6 +75,7 @@
 //! - ✅ SpecPath metadata injection: `["document", "objects", "Example"]`
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::example::ExampleElement;
@@ -164,6 +165,7 @@ where
     add_processing_metadata(&mut example);
     add_spec_path_metadata(&mut example);
     
+    decorate_element(&mut example.object, "example");
     Some(example)
 }
 
@@ -266,6 +268,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         }));
         value_obj.set("name", Element::String(StringElement::new("John Doe")));
         obj.set("value", Element::Object(value_obj));
@@ -355,6 +358,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 42.0,
+            is_integer: true,
         }));
         obj.set("x-boolean-ext", Element::Boolean(BooleanElement::new(true)));
 
@@ -414,6 +418,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         }));
         obj.set("description", Element::Boolean(BooleanElement::new(true)));
         obj.set("externalValue", Element::Number(NumberElement {
@@ -421,6 +426,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 456.0,
+            is_integer: true,
         }));
 
         let example = build_and_decorate_example::<DefaultFolder>(&Element::Object(obj), None);
@@ -483,6 +489,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 42.0,
+            is_integer: true,
         }));
 
         let example = build_and_decorate_example::<DefaultFolder>(&Element::Object(obj), None);
@@ -603,6 +610,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 1.0,
+            is_integer: true,
         }));
         pet_obj.set("name", Element::String(StringElement::new("Fluffy")));
         pet_obj.set("status", Element::String(StringElement::new("available")));
@@ -614,6 +622,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 1.0,
+            is_integer: true,
         }));
         category_obj.set("name", Element::String(StringElement::new("Dogs")));
         pet_obj.set("category", Element::Object(category_obj));
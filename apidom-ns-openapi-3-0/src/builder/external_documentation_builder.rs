@@ -38,6 +38,7 @@
 //! - Enables complex document transformation workflows
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::external_documentation::ExternalDocumentationElement;
@@ -116,6 +117,7 @@ where
     add_spec_path_metadata(&mut external_docs);
     validate_external_docs(&mut external_docs);
     
+    decorate_element(&mut external_docs.object, "externalDocumentation");
     Some(external_docs)
 }
 
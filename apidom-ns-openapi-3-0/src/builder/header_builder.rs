@@ -50,6 +50,7 @@
 //! - Enables complex document transformation workflows
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::header::HeaderElement;
@@ -195,6 +196,7 @@ where
     add_spec_path_metadata(&mut header);
     validate_header(&mut header);
     
+    decorate_element(&mut header.object, "header");
     Some(header)
 }
 
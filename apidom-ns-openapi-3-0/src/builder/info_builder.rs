@@ -14,6 +14,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use crate::elements::info::InfoElement;
 use crate::builder::{build_contact, build_license};
@@ -54,6 +55,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             info.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut info, &ref_str.content);
+            decorate_element(&mut info.object, "info");
             return Some(info);
         }
     }
@@ -158,6 +160,7 @@ where
     // Validate required fields
     validate_info(&mut info);
     
+    decorate_element(&mut info.object, "info");
     Some(info)
 }
 
@@ -405,6 +408,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 42.0,
+            is_integer: true,
         }));
 
         let mut folder = DefaultFolder;
@@ -439,6 +443,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         })); // Should convert to string
         obj.set("version", Element::Boolean(BooleanElement::new(true))); // Should convert to string
         obj.set("description", Element::String(StringElement::new("Valid description")));
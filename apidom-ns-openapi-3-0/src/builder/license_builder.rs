@@ -12,6 +12,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use crate::elements::license::LicenseElement;
 use serde_json::Value;
@@ -50,6 +51,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             license.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut license, &ref_str.content);
+            decorate_element(&mut license.object, "license");
             return Some(license);
         }
     }
@@ -114,6 +116,7 @@ where
     // Validate required fields
     validate_license(&mut license);
     
+    decorate_element(&mut license.object, "license");
     Some(license)
 }
 
@@ -326,6 +329,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 42.0,
+            is_integer: true,
         }));
 
         let mut folder = DefaultFolder;
@@ -360,6 +364,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         })); // Should convert to string
         obj.set("url", Element::Boolean(BooleanElement::new(true))); // Should convert to string
 
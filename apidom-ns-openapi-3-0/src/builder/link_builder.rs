@@ -15,6 +15,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::link::{LinkElement, LinkParametersElement};
@@ -64,6 +65,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             link.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut link, &ref_str.content);
+            decorate_element(&mut link.object, "link");
             return Some(link);
         }
     }
@@ -204,6 +206,7 @@ where
         Value::String("link".to_string())
     );
     
+    decorate_element(&mut link.object, "link");
     Some(link)
 }
 
@@ -496,6 +499,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 100.0,
+            is_integer: true,
         }));
         obj.set("x-auth-required", Element::Boolean(BooleanElement::new(true)));
         
@@ -612,6 +616,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 30.0,
+            is_integer: true,
         }));
         obj.set("x-cache-enabled", Element::Boolean(BooleanElement::new(true)));
         
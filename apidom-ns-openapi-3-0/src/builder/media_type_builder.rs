@@ -43,6 +43,7 @@
 //! - ✅ Comprehensive metadata injection and validation
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::media_type::MediaTypeElement;
@@ -141,6 +142,7 @@ where
     add_spec_path_metadata(&mut media_type);
     validate_media_type(&mut media_type);
     
+    decorate_element(&mut media_type.object, "mediaType");
     Some(media_type)
 }
 
@@ -458,6 +460,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         }));
         complex_value.set("name", Element::String(StringElement::new("Test")));
         complex_example.set("value", Element::Object(complex_value));
@@ -616,6 +619,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 42.0,
+            is_integer: true,
         }));
 
         let media_type = build_and_decorate_media_type::<DefaultFolder>(&Element::Object(obj), None);
@@ -1,6 +1,7 @@
 pub mod callback_builder;
 pub mod components_builder;
 pub mod contact_builder;
+pub mod decorate;
 pub mod discriminator_builder;
 pub mod discriminator_mapping_builder;
 pub mod encoding_builder;
@@ -35,6 +36,7 @@ pub mod xml_builder;
 pub use callback_builder::*;
 pub use components_builder::*;
 pub use contact_builder::*;
+pub use decorate::*;
 pub use discriminator_builder::*;
 pub use discriminator_mapping_builder::*;
 pub use encoding_builder::*;
@@ -14,6 +14,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use crate::elements::oauth_flow::OAuthFlowElement;
 use serde_json::Value;
@@ -87,6 +88,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             flow.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut flow, &ref_str.content);
+            decorate_element(&mut flow.object, "oAuthFlow");
             return Some(flow);
         }
     }
@@ -187,6 +189,7 @@ where
     // Validate OAuth flow constraints
     validate_oauth_flow(&mut flow);
     
+    decorate_element(&mut flow.object, "oAuthFlow");
     Some(flow)
 }
 
@@ -381,6 +384,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 1000.0,
+            is_integer: true,
         }));
 
         let mut folder = DefaultFolder;
@@ -492,6 +496,7 @@ mod tests {
             meta: MetaElement::default(),
             attributes: AttributesElement::default(),
             content: 123.0,
+            is_integer: true,
         })); // Should convert to string but fail URL validation
         obj.set("scopes", Element::Object(ObjectElement::new()));
 
@@ -14,6 +14,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use crate::elements::oauth_flows::OAuthFlowsElement;
 use crate::builder::oauth_flow_builder::{build_oauth_flow, build_and_decorate_oauth_flow};
@@ -79,6 +80,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             flows.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut flows, &ref_str.content);
+            decorate_element(&mut flows.object, "oAuthFlows");
             return Some(flows);
         }
     }
@@ -154,6 +156,7 @@ where
     // Validate OAuth flows constraints
     validate_oauth_flows(&mut flows);
     
+    decorate_element(&mut flows.object, "oAuthFlows");
     Some(flows)
 }
 
@@ -391,6 +394,7 @@ mod tests {
                 meta: MetaElement::default(),
                 attributes: AttributesElement::default(),
                 content: 30.0,
+                is_integer: true,
             }));
             config
         }));
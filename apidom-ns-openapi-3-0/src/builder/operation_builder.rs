@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::operation::{
     OperationElement, OperationParametersElement, OperationSecurityElement,
     OperationServersElement, OperationTagsElement, OperationCallbacksElement
@@ -34,6 +35,7 @@ pub fn build_and_decorate_operation(mut obj: ObjectElement) -> Option<OperationE
     // Inject comprehensive metadata
     inject_operation_metadata(&mut operation.object, &obj);
     
+    decorate_element(&mut operation.object, "operation");
     Some(operation)
 }
 
@@ -439,7 +441,8 @@ mod tests {
                         element: "number".to_string(),
                         meta: MetaElement::default(),
                         attributes: AttributesElement::default(),
-                        content: f 
+                        content: f,
+                        is_integer: n.is_i64() || n.is_u64(),
                     })
                 } else {
                     Element::String(StringElement::new(&n.to_string()))
@@ -1,5 +1,7 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::parameter::ParameterElement;
+use crate::passes::support::json_pointer;
 use serde_json::Value;
 
 /// Build a basic ParameterElement from a generic Element
@@ -150,9 +152,16 @@ where
         Value::String("parameter".to_string())
     );
     
+    // Flag serialization styles that are illegal for the parameter location
+    validate_parameter_style(&mut parameter.object);
+    
+    // Point schema-only consumers at the schema inside single-entry content
+    expose_effective_schema(&mut parameter.object);
+    
     // Validate parameter structure
     validate_parameter(&parameter)?;
     
+    decorate_element(&mut parameter.object, "parameter");
     Some(parameter)
 }
 
@@ -219,6 +228,86 @@ fn add_reference_metadata(obj: &mut ObjectElement, ref_path: &str, element_type:
     );
 }
 
+/// Serialization styles allowed for a parameter location (`in` value)
+pub fn allowed_parameter_styles(location: &str) -> &'static [&'static str] {
+    match location {
+        "path" => &["matrix", "label", "simple"],
+        "query" => &["form", "spaceDelimited", "pipeDelimited", "deepObject"],
+        "header" => &["simple"],
+        "cookie" => &["form"],
+        _ => &[],
+    }
+}
+
+/// Add a validation warning when `style` is not allowed for `in`
+fn validate_parameter_style(obj: &mut ObjectElement) {
+    let field = |key: &str| obj.get(key).and_then(Element::as_string).map(|s| s.content.clone());
+    let (Some(location), Some(style)) = (field("in"), field("style")) else {
+        return;
+    };
+    let allowed = allowed_parameter_styles(&location);
+    if allowed.is_empty() || allowed.contains(&style.as_str()) {
+        return;
+    }
+    add_validation_warning(
+        obj,
+        "style",
+        &format!(
+            "Style '{}' is not allowed for {} parameters (expected one of: {})",
+            style,
+            location,
+            allowed.join(", ")
+        ),
+    );
+}
+
+/// Metadata key holding the JSON pointer (relative to the parameter) of the
+/// schema a `content`-based parameter effectively uses
+pub const EFFECTIVE_SCHEMA_KEY: &str = "effective-schema";
+
+/// Metadata key holding the media type the effective schema was taken from
+pub const EFFECTIVE_SCHEMA_MEDIA_TYPE_KEY: &str = "effective-schema-media-type";
+
+/// Record where the schema of a parameter described by `content` lives.
+/// `content` must hold exactly one media type; `content` itself is kept.
+fn expose_effective_schema(obj: &mut ObjectElement) {
+    if obj.has_key("schema") {
+        return;
+    }
+    let Some(Element::Object(content)) = obj.get("content") else {
+        return;
+    };
+    let [entry] = content.content.as_slice() else {
+        return;
+    };
+    let Element::String(media_type) = entry.key.as_ref() else {
+        return;
+    };
+    let has_schema = entry
+        .value
+        .as_object()
+        .is_some_and(|media_type_obj| media_type_obj.has_key("schema"));
+    if !has_schema {
+        return;
+    }
+
+    let media_type = media_type.content.clone();
+    let pointer = json_pointer(&["content".to_string(), media_type.clone(), "schema".to_string()]);
+    obj.meta
+        .properties
+        .insert(EFFECTIVE_SCHEMA_KEY.to_string(), Value::String(pointer));
+    obj.meta.properties.insert(
+        EFFECTIVE_SCHEMA_MEDIA_TYPE_KEY.to_string(),
+        Value::String(media_type),
+    );
+}
+
+/// Add validation warning metadata
+fn add_validation_warning(obj: &mut ObjectElement, field: &str, message: &str) {
+    let warning_key = format!("validation-warning-{}", field);
+    obj.meta.properties.insert(warning_key, Value::String(message.to_string()));
+}
+
 /// Validate parameter structure
 fn validate_parameter(parameter: &ParameterElement) -> Option<()> {
     // If this is a $ref parameter, skip standard validation
@@ -423,4 +512,59 @@ mod tests {
             Some(&Value::String("parameter".to_string()))
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_style_checked_against_location() {
+        let mut obj = ObjectElement::new();
+        obj.set("name", Element::String(StringElement::new("color")));
+        obj.set("in", Element::String(StringElement::new("query")));
+        obj.set("style", Element::String(StringElement::new("matrix")));
+
+        let param = build_and_decorate_parameter(&Element::Object(obj.clone()), None::<&mut crate::fold::OpenApiBuilderFolder>).unwrap();
+        let warning = param.object.meta.properties.get("validation-warning-style");
+        assert!(warning.and_then(Value::as_str).is_some_and(|w| w.contains("'matrix'")));
+
+        obj.set("style", Element::String(StringElement::new("form")));
+        let param = build_and_decorate_parameter(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>).unwrap();
+        assert!(!param.object.meta.properties.contains_key("validation-warning-style"));
+    }
+
+    #[test]
+    fn test_content_parameter_exposes_effective_schema() {
+        use crate::passes::support::{element_at, pointer_segments};
+        use apidom_ast::fold::json_source_to_ast;
+
+        let element = json_source_to_ast(
+            r#"{
+                "name": "filter",
+                "in": "query",
+                "content": { "application/json": { "schema": { "type": "object", "x-marker": true } } }
+            }"#,
+        );
+        let parameter =
+            build_and_decorate_parameter(&element, None::<&mut crate::fold::OpenApiBuilderFolder>).unwrap();
+
+        let meta = &parameter.object.meta.properties;
+        assert_eq!(
+            meta.get(EFFECTIVE_SCHEMA_KEY),
+            Some(&Value::String("/content/application~1json/schema".to_string()))
+        );
+        assert_eq!(
+            meta.get(EFFECTIVE_SCHEMA_MEDIA_TYPE_KEY),
+            Some(&Value::String("application/json".to_string()))
+        );
+        assert!(parameter.object.has_key("content"));
+
+        let built = Element::Object(parameter.object.clone());
+        let pointer = meta[EFFECTIVE_SCHEMA_KEY].as_str().unwrap();
+        let schema = element_at(&built, &pointer_segments(pointer))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert!(schema.has_key("x-marker"));
+
+        let with_schema = json_source_to_ast(r#"{ "name": "limit", "in": "query", "schema": { "type": "integer" } }"#);
+        let parameter =
+            build_and_decorate_parameter(&with_schema, None::<&mut crate::fold::OpenApiBuilderFolder>).unwrap();
+        assert!(!parameter.object.meta.properties.contains_key(EFFECTIVE_SCHEMA_KEY));
+    }
+}
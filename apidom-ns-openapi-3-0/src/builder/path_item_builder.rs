@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::path_item::PathItemElement;
 use serde_json::Value;
 
@@ -167,6 +168,7 @@ where
     // Validate PathItem structure
     validate_path_item(&path_item)?;
     
+    decorate_element(&mut path_item.object, "pathItem");
     Some(path_item)
 }
 
@@ -516,6 +518,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 100.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_path_item(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
@@ -607,6 +610,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         obj.set("description", Element::Boolean(BooleanElement::new(true)));
         
@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::paths::PathsElement;
 use crate::builder::path_item_builder::build_and_decorate_path_item;
 use serde_json::Value;
@@ -53,6 +54,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             paths.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut paths, &ref_str.content);
+            decorate_element(&mut paths.object, "paths");
             return Some(paths);
         }
     }
@@ -140,6 +142,7 @@ where
     // Validate Paths structure
     validate_paths(&mut paths)?;
     
+    decorate_element(&mut paths.object, "paths");
     Some(paths)
 }
 
@@ -473,6 +476,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 1000.0,
+            is_integer: true,
         }));
         
         // Add fallback field
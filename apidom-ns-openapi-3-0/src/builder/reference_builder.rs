@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::reference::ReferenceElement;
 use serde_json::Value;
 
@@ -24,6 +25,7 @@ pub fn build_and_decorate_reference(mut obj: ObjectElement) -> Option<ReferenceE
     // Inject comprehensive metadata
     inject_reference_metadata(&mut reference.object, &obj);
     
+    decorate_element(&mut reference.object, "reference");
     Some(reference)
 }
 
@@ -241,7 +243,8 @@ mod tests {
                         element: "number".to_string(),
                         meta: MetaElement::default(),
                         attributes: AttributesElement::default(),
-                        content: f 
+                        content: f,
+                        is_integer: n.is_i64() || n.is_u64(),
                     })
                 } else {
                     Element::String(StringElement::new(&n.to_string()))
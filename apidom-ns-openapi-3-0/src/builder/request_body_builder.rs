@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::request_body::RequestBodyElement;
 use serde_json::Value;
 
@@ -139,6 +140,7 @@ where
     // Validate RequestBody structure
     validate_request_body(&request_body)?;
     
+    decorate_element(&mut request_body.object, "requestBody");
     Some(request_body)
 }
 
@@ -426,6 +428,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_request_body(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
@@ -476,6 +479,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         obj.set("required", Element::String(StringElement::new("true")));
         
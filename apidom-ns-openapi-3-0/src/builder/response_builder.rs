@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::response::{ResponseElement, ResponseContentElement, ResponseHeadersElement, ResponseLinksElement};
@@ -41,6 +42,7 @@ where
     // Inject comprehensive metadata
     inject_response_metadata(&mut response.object, &obj);
     
+    decorate_element(&mut response.object, "response");
     Some(response)
 }
 
@@ -505,7 +507,8 @@ mod tests {
                         element: "number".to_string(),
                         meta: MetaElement::default(),
                         attributes: AttributesElement::default(),
-                        content: f 
+                        content: f,
+                        is_integer: n.is_i64() || n.is_u64(),
                     })
                 } else {
                     Element::String(StringElement::new(&n.to_string()))
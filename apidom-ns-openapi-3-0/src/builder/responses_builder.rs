@@ -38,6 +38,7 @@
 //! - ✅ Comprehensive metadata injection and validation
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use regex::Regex;
@@ -113,6 +114,7 @@ where
     add_processing_metadata(&mut responses);
     add_spec_path_metadata(&mut responses);
     
+    decorate_element(&mut responses.object, "responses");
     Some(responses)
 }
 
@@ -36,6 +36,7 @@
 //! - ✅ Comprehensive metadata injection and validation
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::schema::OpenApiSchemaElement;
@@ -58,11 +59,38 @@ pub fn build_openapi_schema(element: &Element) -> Option<OpenApiSchemaElement> {
     }
 }
 
+/// Options controlling how schemas are built
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaBuildOptions {
+    /// Sort `enum` values by their serialized form for deterministic output.
+    /// Reordered enums are tagged with `enum-canonicalized` metadata.
+    pub canonicalize_enums: bool,
+}
+
+impl SchemaBuildOptions {
+    pub fn with_canonical_enums(mut self, enabled: bool) -> Self {
+        self.canonicalize_enums = enabled;
+        self
+    }
+}
+
 /// Enhanced schema builder with visitor pattern features
 /// Equivalent to TypeScript SchemaVisitor with comprehensive field processing
 pub fn build_and_decorate_schema<F>(
     element: &Element,
-    mut folder: Option<&mut F>
+    folder: Option<&mut F>
+) -> Option<OpenApiSchemaElement>
+where
+    F: Fold,
+{
+    build_and_decorate_schema_with_options(element, folder, &SchemaBuildOptions::default())
+}
+
+/// Enhanced schema builder honoring [`SchemaBuildOptions`]
+pub fn build_and_decorate_schema_with_options<F>(
+    element: &Element,
+    mut folder: Option<&mut F>,
+    options: &SchemaBuildOptions,
 ) -> Option<OpenApiSchemaElement>
 where
     F: Fold,
@@ -193,6 +221,21 @@ where
                 }
             },
             
+            "enum" if options.canonicalize_enums => {
+                let value = match &processed_value {
+                    Element::Array(values) => canonicalize_enum(values).map(|sorted| {
+                        schema.base.object.meta.properties.insert(
+                            "enum-canonicalized".to_string(),
+                            Value::Bool(true)
+                        );
+                        Element::Array(sorted)
+                    }),
+                    _ => None,
+                };
+                schema.base.object.set("enum", value.unwrap_or(processed_value));
+                add_fixed_field_metadata(&mut schema, "enum");
+            },
+
             // Standard JSON Schema fields
             "title" | "description" | "default" | "enum" | "const" | "format" | "pattern" |
             "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf" |
@@ -215,7 +258,9 @@ where
     add_processing_metadata(&mut schema);
     add_spec_path_metadata(&mut schema);
     validate_schema(&mut schema);
+    validate_enum_types(&mut schema);
     
+    decorate_element(&mut schema.base.object, "schema");
     Some(schema)
 }
 
@@ -353,6 +398,29 @@ fn process_type_field(element: &Element, schema: &mut OpenApiSchemaElement) -> E
     }
 }
 
+/// Sort enum values by their serialized JSON form.
+///
+/// Values of different types compare by that form too, so the order is
+/// deterministic for any enum. Returns `None` when the values are already
+/// in canonical order.
+pub fn canonicalize_enum(values: &ArrayElement) -> Option<ArrayElement> {
+    let keys: Vec<String> = values
+        .content
+        .iter()
+        .map(|value| value.to_value().to_string())
+        .collect();
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+
+    if order.iter().enumerate().all(|(position, &index)| position == index) {
+        return None;
+    }
+
+    let mut sorted = values.clone();
+    sorted.content = order.into_iter().map(|index| values.content[index].clone()).collect();
+    Some(sorted)
+}
+
 /// Check if element is reference-like (has $ref)
 fn is_reference_like_element(element: &Element) -> bool {
     if let Element::Object(obj) = element {
@@ -404,6 +472,12 @@ fn add_validation_error_metadata(schema: &mut OpenApiSchemaElement, field_name:
     schema.base.object.meta.properties.insert(key, Value::String(error_msg.to_string()));
 }
 
+/// Add validation warning metadata
+fn add_validation_warning_metadata(schema: &mut OpenApiSchemaElement, field_name: &str, warning_msg: &str) {
+    let key = format!("validationWarning_{}", field_name);
+    schema.base.object.meta.properties.insert(key, Value::String(warning_msg.to_string()));
+}
+
 /// Add specification extension metadata
 fn add_specification_extension_metadata(schema: &mut OpenApiSchemaElement, field_name: &str) {
     schema.base.object.meta.properties.insert(
@@ -512,6 +586,59 @@ fn validate_schema(schema: &mut OpenApiSchemaElement) {
     }
 }
 
+/// Warn about `enum` values whose JSON type is not allowed by `type`.
+/// Each mismatch is recorded under `validationWarning_enum_<index>`.
+fn validate_enum_types(schema: &mut OpenApiSchemaElement) {
+    let declared: Vec<String> = match schema.base.object.get("type") {
+        Some(Element::String(t)) => vec![t.content.clone()],
+        Some(Element::Array(types)) => types
+            .content
+            .iter()
+            .filter_map(|t| t.as_string().map(|s| s.content.clone()))
+            .collect(),
+        _ => return,
+    };
+    let Some(Element::Array(values)) = schema.base.object.get("enum") else {
+        return;
+    };
+    let nullable = matches!(schema.base.object.get("nullable"), Some(Element::Boolean(b)) if b.content);
+
+    let mismatches: Vec<(usize, String)> = values
+        .content
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !(nullable && matches!(value, Element::Null(_))))
+        .filter(|(_, value)| !declared.iter().any(|t| enum_value_has_type(value, t)))
+        .map(|(index, value)| {
+            let message = format!(
+                "Enum value at index {} ({}) does not match type '{}'",
+                index,
+                value.to_value(),
+                declared.join("', '")
+            );
+            (index, message)
+        })
+        .collect();
+
+    for (index, message) in mismatches {
+        add_validation_warning_metadata(schema, &format!("enum_{}", index), &message);
+    }
+}
+
+/// Whether `value` is an instance of the JSON Schema type `type_name`
+fn enum_value_has_type(value: &Element, type_name: &str) -> bool {
+    match (type_name, value) {
+        ("string", Element::String(_)) => true,
+        ("number", Element::Number(_)) => true,
+        ("integer", Element::Number(n)) => n.content.fract() == 0.0,
+        ("boolean", Element::Boolean(_)) => true,
+        ("null", Element::Null(_)) => true,
+        ("array", Element::Array(_)) => true,
+        ("object", Element::Object(_)) => true,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -801,4 +928,54 @@ mod tests {
         assert!(classes.contains(&"openapi-schema".to_string()));
         assert!(classes.contains(&"json-schema-draft-4".to_string()));
     }
-}
\ No newline at end of file
+
+    fn canonical_enum(value: serde_json::Value) -> (Value, bool) {
+        let obj = create_test_object(value);
+        let options = SchemaBuildOptions::default().with_canonical_enums(true);
+        let schema = build_and_decorate_schema_with_options(&Element::Object(obj), None::<&mut DefaultFolder>, &options).unwrap();
+        let tagged = schema.base.object.meta.properties.contains_key("enum-canonicalized");
+        (schema.base.object.get("enum").unwrap().to_value(), tagged)
+    }
+
+    #[test]
+    fn test_canonicalize_string_enum() {
+        let (values, tagged) = canonical_enum(json!({ "type": "string", "enum": ["pending", "available", "sold"] }));
+        assert_eq!(values, json!(["available", "pending", "sold"]));
+        assert!(tagged);
+
+        let (values, tagged) = canonical_enum(json!({ "enum": ["a", "b"] }));
+        assert_eq!(values, json!(["a", "b"]));
+        assert!(!tagged);
+    }
+
+    #[test]
+    fn test_canonicalize_mixed_enum_is_deterministic() {
+        let (first, _) = canonical_enum(json!({ "enum": ["b", 2, null, true, "a", 1] }));
+        let (second, _) = canonical_enum(json!({ "enum": [1, true, "a", null, 2, "b"] }));
+        assert_eq!(first, second);
+        assert_eq!(first.as_array().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_enum_order_kept_by_default() {
+        let obj = create_test_object(json!({ "enum": ["b", "a"] }));
+        let schema = build_and_decorate_schema(&Element::Object(obj), None::<&mut DefaultFolder>).unwrap();
+        assert_eq!(schema.base.object.get("enum").unwrap().to_value(), json!(["b", "a"]));
+    }
+
+    #[test]
+    fn test_enum_value_type_mismatch_warns() {
+        let obj = create_test_object(json!({ "type": "integer", "enum": ["a", 2] }));
+        let schema = build_and_decorate_schema::<DefaultFolder>(&Element::Object(obj), None).unwrap();
+        let meta = &schema.base.object.meta.properties;
+
+        let warning = meta.get("validationWarning_enum_0").and_then(Value::as_str).unwrap();
+        assert!(warning.contains("index 0"));
+        assert!(warning.contains("integer"));
+        assert!(!meta.contains_key("validationWarning_enum_1"));
+
+        let untyped = create_test_object(json!({ "enum": ["a", 2] }));
+        let schema = build_and_decorate_schema::<DefaultFolder>(&Element::Object(untyped), None).unwrap();
+        assert!(!schema.base.object.meta.properties.keys().any(|k| k.starts_with("validationWarning_enum")));
+    }
+}
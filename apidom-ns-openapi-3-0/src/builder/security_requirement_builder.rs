@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::security_requirement::SecurityRequirementElement;
@@ -69,6 +70,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             sec_req.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut sec_req, &ref_str.content);
+            decorate_element(&mut sec_req.object, "securityRequirement");
             return Some(sec_req);
         }
     }
@@ -168,6 +170,7 @@ where
     // Validate SecurityRequirement structure
     validate_security_requirement(&mut sec_req)?;
     
+    decorate_element(&mut sec_req.object, "securityRequirement");
     Some(sec_req)
 }
 
@@ -451,6 +454,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 100.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_security_requirement(&Element::Object(obj), None::<&mut OpenApiBuilderFolder>);
@@ -565,6 +569,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 3600.0,
+            is_integer: true,
         }));
         
         // Add fallback field
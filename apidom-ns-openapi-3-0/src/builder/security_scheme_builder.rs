@@ -16,6 +16,7 @@
  */
 
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use apidom_ast::fold::Fold;
 use serde_json::Value;
 use crate::elements::security_scheme::SecuritySchemeElement;
@@ -66,6 +67,7 @@ where
         if let Some(ref_str) = ref_value.as_string() {
             sec_scheme.object.set("$ref", Element::String(ref_str.clone()));
             add_ref_metadata(&mut sec_scheme, &ref_str.content);
+            decorate_element(&mut sec_scheme.object, "securityScheme");
             return Some(sec_scheme);
         }
     }
@@ -235,6 +237,7 @@ where
     // Type-specific validation
     validate_security_scheme_constraints(&mut sec_scheme, scheme_type);
     
+    decorate_element(&mut sec_scheme.object, "securityScheme");
     Some(sec_scheme)
 }
 
@@ -489,6 +492,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 3600.0,
+            is_integer: true,
         }));
         obj.set("x-refresh-enabled", Element::Boolean(BooleanElement::new(true)));
         
@@ -616,6 +620,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 300.0,
+            is_integer: true,
         }));
         
         // Add fallback field
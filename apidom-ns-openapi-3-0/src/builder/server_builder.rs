@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::server::ServerElement;
 use serde_json::Value;
 
@@ -31,7 +32,7 @@ pub fn build_and_decorate_server<F>(
     mut folder: Option<&mut F>
 ) -> Option<ServerElement>
 where
-    F: apidom_ast::fold::Fold,
+    F: apidom_ast::fold::Fold + ?Sized,
 {
     let obj = element.as_object()?;
     let mut server = ServerElement::new();
@@ -135,9 +136,30 @@ where
     // Validate server structure
     validate_server(&server)?;
     
+    decorate_element(&mut server.object, "server");
     Some(server)
 }
 
+/// Build and decorate every Server object in a `servers` array.
+/// Entries that are not valid Server objects are kept unchanged.
+pub fn build_and_decorate_servers<F>(
+    element: &Element,
+    mut folder: Option<&mut F>
+) -> Option<ArrayElement>
+where
+    F: apidom_ast::fold::Fold + ?Sized,
+{
+    let array = element.as_array()?;
+    let mut servers = array.clone();
+    servers.content = array.content.iter()
+        .map(|item| match build_and_decorate_server(item, folder.as_deref_mut()) {
+            Some(server) => Element::Object(server.object),
+            None => item.clone(),
+        })
+        .collect();
+    Some(servers)
+}
+
 /// Convert element to StringElement with type safety
 fn convert_to_string_element(element: &Element) -> Option<StringElement> {
     match element {
@@ -291,6 +313,28 @@ mod tests {
         assert!(server.object.meta.properties.contains_key("spec-path"));
     }
 
+    #[test]
+    fn test_build_and_decorate_servers() {
+        let mut production = ObjectElement::new();
+        production.set("url", Element::String(StringElement::new("https://api.example.com")));
+        let mut staging = ObjectElement::new();
+        staging.set("url", Element::String(StringElement::new("https://staging.example.com")));
+        let mut array = ArrayElement::new_empty();
+        array.content.push(Element::Object(production));
+        array.content.push(Element::Object(staging));
+        array.content.push(Element::String(StringElement::new("not a server")));
+        
+        let servers = build_and_decorate_servers(&Element::Array(array), None::<&mut crate::fold::OpenApiBuilderFolder>).unwrap();
+        
+        assert_eq!(servers.content.len(), 3);
+        for server in &servers.content[..2] {
+            let obj = server.as_object().unwrap();
+            assert_eq!(obj.element, "server");
+            assert!(obj.meta.properties.contains_key("fixed-field-url"));
+        }
+        assert!(matches!(&servers.content[2], Element::String(_)));
+    }
+
     #[test]
     fn test_server_with_variables() {
         let mut obj = ObjectElement::new();
@@ -336,6 +380,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 1000.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_server(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
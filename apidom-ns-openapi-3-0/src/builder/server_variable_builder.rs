@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::server_variable::ServerVariableElement;
 use serde_json::Value;
 
@@ -120,6 +121,7 @@ where
     // Validate server variable structure
     validate_server_variable(&server_var)?;
     
+    decorate_element(&mut server_var.object, "serverVariable");
     Some(server_var)
 }
 
@@ -318,6 +320,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_server_variable(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
@@ -368,6 +371,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 8080.0,
+            is_integer: true,
         }));
         obj.set("description", Element::Boolean(BooleanElement::new(true)));
         
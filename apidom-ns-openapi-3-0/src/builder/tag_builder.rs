@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::tag::TagElement;
 use serde_json::Value;
 
@@ -136,6 +137,7 @@ where
     // Validate tag structure
     validate_tag(&tag)?;
     
+    decorate_element(&mut tag.object, "tag");
     Some(tag)
 }
 
@@ -328,6 +330,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 1.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_tag(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
@@ -402,6 +405,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         obj.set("description", Element::Boolean(BooleanElement::new(true)));
         
@@ -439,6 +443,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 1.0,
+            is_integer: true,
         }));
         
         // Add fallback field
@@ -1,4 +1,5 @@
 use apidom_ast::minim_model::*;
+use crate::builder::decorate::decorate_element;
 use crate::elements::xml::XmlElement;
 use serde_json::Value;
 
@@ -132,6 +133,7 @@ where
     // Validate XML structure (XML has no required fields in OpenAPI)
     validate_xml(&xml)?;
     
+    decorate_element(&mut xml.object, "xml");
     Some(xml)
 }
 
@@ -353,6 +355,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_xml(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
@@ -403,6 +406,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 42.0,
+            is_integer: true,
         }));
         obj.set("attribute", Element::String(StringElement::new("true")));
         obj.set("wrapped", Element::Number(NumberElement {
@@ -410,6 +414,7 @@ mod tests {
             meta: Default::default(),
             attributes: Default::default(),
             content: 0.0,
+            is_integer: true,
         }));
         
         let result = build_and_decorate_xml(&Element::Object(obj), None::<&mut crate::fold::OpenApiBuilderFolder>);
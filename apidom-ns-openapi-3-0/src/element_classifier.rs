@@ -0,0 +1,465 @@
+//! Positional element classification for OpenAPI 3.0 documents.
+//!
+//! Generic ASTs (for example those produced by `json_source_to_ast`) tag every
+//! object as `object`. The helpers here infer the OpenAPI element type of each
+//! object from where it sits in the document, so analysis passes work the same
+//! on raw documents and on documents that already went through the builders.
+//! Vendor documents that place standard objects at nonstandard locations can
+//! be supported with [`ClassificationOverrides`].
+
+use std::collections::HashMap;
+
+use apidom_ast::minim_model::{Element, ObjectElement};
+
+/// Element type assigned to the document root
+pub const ROOT_ELEMENT_TYPE: &str = "openApi3_0";
+
+/// HTTP methods that map to operation objects inside a path item
+pub const OPERATION_KEYS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Describes how the value stored under a key relates to an element type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementSlot {
+    /// The value is a single element of the given type
+    Single(&'static str),
+    /// The value is a map whose values are elements of the given type
+    Map(&'static str),
+    /// The value is an array whose items are elements of the given type
+    List(&'static str),
+}
+
+/// Classify the value stored under `key` inside an element of `parent_type`
+pub fn classify_openapi_element(parent_type: &str, key: &str) -> Option<ElementSlot> {
+    use ElementSlot::*;
+
+    if key.starts_with("x-") {
+        return None;
+    }
+
+    let slot = match (parent_type, key) {
+        ("openApi3_0", "info") => Single("info"),
+        ("openApi3_0", "servers") => List("server"),
+        ("openApi3_0", "paths") => Single("paths"),
+        ("openApi3_0", "components") => Single("components"),
+        ("openApi3_0", "security") => List("securityRequirement"),
+        ("openApi3_0", "tags") => List("tag"),
+        ("openApi3_0", "externalDocs") => Single("externalDocumentation"),
+        // OpenAPI 3.1 webhooks, classified so their path items are analyzed too
+        ("openApi3_0", "webhooks") => Map("pathItem"),
+
+        ("info", "contact") => Single("contact"),
+        ("info", "license") => Single("license"),
+
+        ("server", "variables") => Map("serverVariable"),
+
+        ("components", "schemas") => Map("schema"),
+        ("components", "responses") => Map("response"),
+        ("components", "parameters") => Map("parameter"),
+        ("components", "examples") => Map("example"),
+        ("components", "requestBodies") => Map("requestBody"),
+        ("components", "headers") => Map("header"),
+        ("components", "securitySchemes") => Map("securityScheme"),
+        ("components", "links") => Map("link"),
+        ("components", "callbacks") => Map("callback"),
+
+        ("paths", _) => Single("pathItem"),
+
+        ("pathItem", "servers") => List("server"),
+        ("pathItem", "parameters") => List("parameter"),
+        ("pathItem", method) if OPERATION_KEYS.contains(&method) => Single("operation"),
+
+        ("operation", "externalDocs") => Single("externalDocumentation"),
+        ("operation", "parameters") => List("parameter"),
+        ("operation", "requestBody") => Single("requestBody"),
+        ("operation", "responses") => Single("responses"),
+        ("operation", "callbacks") => Map("callback"),
+        ("operation", "security") => List("securityRequirement"),
+        ("operation", "servers") => List("server"),
+
+        ("parameter", "schema") | ("header", "schema") | ("mediaType", "schema") => {
+            Single("schema")
+        }
+        ("parameter", "examples") | ("header", "examples") | ("mediaType", "examples") => {
+            Map("example")
+        }
+        ("parameter", "content") | ("header", "content") => Map("mediaType"),
+
+        ("requestBody", "content") => Map("mediaType"),
+
+        ("mediaType", "encoding") => Map("encoding"),
+
+        ("encoding", "headers") => Map("header"),
+
+        ("responses", _) => Single("response"),
+
+        ("response", "headers") => Map("header"),
+        ("response", "content") => Map("mediaType"),
+        ("response", "links") => Map("link"),
+
+        ("callback", _) => Single("pathItem"),
+
+        ("link", "server") => Single("server"),
+
+        ("tag", "externalDocs") => Single("externalDocumentation"),
+
+        ("schema", "items")
+        | ("schema", "not")
+        | ("schema", "additionalProperties") => Single("schema"),
+        ("schema", "allOf") | ("schema", "oneOf") | ("schema", "anyOf") => List("schema"),
+        ("schema", "properties") => Map("schema"),
+        ("schema", "discriminator") => Single("discriminator"),
+        ("schema", "xml") => Single("xml"),
+        ("schema", "externalDocs") => Single("externalDocumentation"),
+
+        ("securityScheme", "flows") => Single("oAuthFlows"),
+
+        ("oAuthFlows", "implicit")
+        | ("oAuthFlows", "password")
+        | ("oAuthFlows", "clientCredentials")
+        | ("oAuthFlows", "authorizationCode") => Single("oAuthFlow"),
+
+        _ => return None,
+    };
+
+    Some(slot)
+}
+
+/// User-supplied classifications that take precedence over
+/// [`classify_openapi_element`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassificationOverrides {
+    slots: HashMap<(String, String), ElementSlot>,
+}
+
+impl ClassificationOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify the value under `key` inside a `parent_type` element as `slot`
+    pub fn with_override(mut self, parent_type: &str, key: &str, slot: ElementSlot) -> Self {
+        self.insert(parent_type, key, slot);
+        self
+    }
+
+    pub fn insert(&mut self, parent_type: &str, key: &str, slot: ElementSlot) {
+        self.slots
+            .insert((parent_type.to_string(), key.to_string()), slot);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Classify like [`classify_openapi_element`], consulting the overrides
+    /// first. Overrides also apply to `x-` keys.
+    pub fn classify(&self, parent_type: &str, key: &str) -> Option<ElementSlot> {
+        self.slots
+            .get(&(parent_type.to_string(), key.to_string()))
+            .copied()
+            .or_else(|| classify_openapi_element(parent_type, key))
+    }
+}
+
+/// Element type of a document root, if it can be determined
+pub fn root_element_type(element: &Element) -> Option<String> {
+    let obj = element.as_object()?;
+    if obj.element != "object" {
+        Some(obj.element.clone())
+    } else if obj.has_key("openapi") {
+        Some(ROOT_ELEMENT_TYPE.to_string())
+    } else {
+        None
+    }
+}
+
+/// Visit every classifiable object in `root` together with its element type
+/// and its path (member keys and array indices) from the root
+pub fn walk_openapi_objects<'a, F>(root: &'a Element, visit: F)
+where
+    F: FnMut(&str, &[String], &'a ObjectElement),
+{
+    walk_openapi_objects_with(root, &ClassificationOverrides::default(), visit);
+}
+
+/// Like [`walk_openapi_objects`], classifying with `overrides`
+pub fn walk_openapi_objects_with<'a, F>(
+    root: &'a Element,
+    overrides: &ClassificationOverrides,
+    mut visit: F,
+) where
+    F: FnMut(&str, &[String], &'a ObjectElement),
+{
+    let mut path = Vec::new();
+    let root_type = root_element_type(root);
+    walk(root, root_type, overrides, &mut path, &mut visit);
+}
+
+/// Like [`walk_openapi_objects`], but classify the root as `root_type`
+/// instead of inferring it
+pub fn walk_openapi_objects_as<'a, F>(root: &'a Element, root_type: &str, mut visit: F)
+where
+    F: FnMut(&str, &[String], &'a ObjectElement),
+{
+    let mut path = Vec::new();
+    let overrides = ClassificationOverrides::default();
+    walk(root, Some(root_type.to_string()), &overrides, &mut path, &mut visit);
+}
+
+/// Mutable variant of [`walk_openapi_objects`]. Each object is visited before
+/// its children, so changes made by `visit` are seen by the rest of the walk.
+pub fn walk_openapi_objects_mut<F>(root: &mut Element, visit: F)
+where
+    F: FnMut(&str, &[String], &mut ObjectElement),
+{
+    walk_openapi_objects_mut_with(root, &ClassificationOverrides::default(), visit);
+}
+
+/// Like [`walk_openapi_objects_mut`], classifying with `overrides`
+pub fn walk_openapi_objects_mut_with<F>(
+    root: &mut Element,
+    overrides: &ClassificationOverrides,
+    mut visit: F,
+) where
+    F: FnMut(&str, &[String], &mut ObjectElement),
+{
+    let mut path = Vec::new();
+    let root_type = root_element_type(root);
+    walk_mut(root, root_type, overrides, &mut path, &mut visit);
+}
+
+/// Set the inferred element type on every classifiable object that is still
+/// tagged as a plain `object`
+pub fn tag_element_types(root: &mut Element, overrides: &ClassificationOverrides) {
+    walk_openapi_objects_mut_with(root, overrides, |element_type, _, obj| {
+        if obj.element == "object" {
+            obj.set_element_type(element_type);
+        }
+    });
+}
+
+fn element_type_of(obj: &ObjectElement, positional: Option<String>) -> Option<String> {
+    positional.or_else(|| (obj.element != "object").then(|| obj.element.clone()))
+}
+
+fn member_key(key: &Element) -> String {
+    match key {
+        Element::String(s) => s.content.clone(),
+        other => format!("{:?}", other.to_value()),
+    }
+}
+
+fn walk<'a, F>(
+    element: &'a Element,
+    element_type: Option<String>,
+    overrides: &ClassificationOverrides,
+    path: &mut Vec<String>,
+    visit: &mut F,
+) where
+    F: FnMut(&str, &[String], &'a ObjectElement),
+{
+    match element {
+        Element::Object(obj) => {
+            let ty = element_type_of(obj, element_type);
+            if let Some(ty) = &ty {
+                visit(ty, path, obj);
+            }
+            for member in &obj.content {
+                let key = member_key(&member.key);
+                let slot = ty.as_deref().and_then(|t| overrides.classify(t, &key));
+                path.push(key);
+                walk_slot(&member.value, slot, overrides, path, visit);
+                path.pop();
+            }
+        }
+        Element::Array(arr) => {
+            for (index, item) in arr.content.iter().enumerate() {
+                path.push(index.to_string());
+                walk(item, None, overrides, path, visit);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_slot<'a, F>(
+    value: &'a Element,
+    slot: Option<ElementSlot>,
+    overrides: &ClassificationOverrides,
+    path: &mut Vec<String>,
+    visit: &mut F,
+) where
+    F: FnMut(&str, &[String], &'a ObjectElement),
+{
+    match (slot, value) {
+        (Some(ElementSlot::Single(ty)), _) => {
+            walk(value, Some(ty.to_string()), overrides, path, visit)
+        }
+        (Some(ElementSlot::Map(ty)), Element::Object(map)) => {
+            for member in &map.content {
+                path.push(member_key(&member.key));
+                walk(&member.value, Some(ty.to_string()), overrides, path, visit);
+                path.pop();
+            }
+        }
+        (Some(ElementSlot::List(ty)), Element::Array(list)) => {
+            for (index, item) in list.content.iter().enumerate() {
+                path.push(index.to_string());
+                walk(item, Some(ty.to_string()), overrides, path, visit);
+                path.pop();
+            }
+        }
+        _ => walk(value, None, overrides, path, visit),
+    }
+}
+
+fn walk_mut<F>(
+    element: &mut Element,
+    element_type: Option<String>,
+    overrides: &ClassificationOverrides,
+    path: &mut Vec<String>,
+    visit: &mut F,
+) where
+    F: FnMut(&str, &[String], &mut ObjectElement),
+{
+    match element {
+        Element::Object(obj) => {
+            let ty = element_type_of(obj, element_type);
+            if let Some(ty) = &ty {
+                visit(ty, path, obj);
+            }
+            for member in &mut obj.content {
+                let key = member_key(&member.key);
+                let slot = ty.as_deref().and_then(|t| overrides.classify(t, &key));
+                path.push(key);
+                walk_slot_mut(&mut member.value, slot, overrides, path, visit);
+                path.pop();
+            }
+        }
+        Element::Array(arr) => {
+            for (index, item) in arr.content.iter_mut().enumerate() {
+                path.push(index.to_string());
+                walk_mut(item, None, overrides, path, visit);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_slot_mut<F>(
+    value: &mut Element,
+    slot: Option<ElementSlot>,
+    overrides: &ClassificationOverrides,
+    path: &mut Vec<String>,
+    visit: &mut F,
+) where
+    F: FnMut(&str, &[String], &mut ObjectElement),
+{
+    match (slot, value) {
+        (Some(ElementSlot::Single(ty)), value) => {
+            walk_mut(value, Some(ty.to_string()), overrides, path, visit)
+        }
+        (Some(ElementSlot::Map(ty)), Element::Object(map)) => {
+            for member in &mut map.content {
+                path.push(member_key(&member.key));
+                walk_mut(&mut member.value, Some(ty.to_string()), overrides, path, visit);
+                path.pop();
+            }
+        }
+        (Some(ElementSlot::List(ty)), Element::Array(list)) => {
+            for (index, item) in list.content.iter_mut().enumerate() {
+                path.push(index.to_string());
+                walk_mut(item, Some(ty.to_string()), overrides, path, visit);
+                path.pop();
+            }
+        }
+        (_, value) => walk_mut(value, None, overrides, path, visit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_classify_positions() {
+        assert_eq!(
+            classify_openapi_element("components", "securitySchemes"),
+            Some(ElementSlot::Map("securityScheme"))
+        );
+        assert_eq!(
+            classify_openapi_element("pathItem", "get"),
+            Some(ElementSlot::Single("operation"))
+        );
+        assert_eq!(classify_openapi_element("paths", "x-internal"), None);
+        assert_eq!(classify_openapi_element("info", "title"), None);
+    }
+
+    #[test]
+    fn test_walk_raw_document() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "paths": { "/pets": { "get": { "responses": { "200": { "description": "ok" } } } } }
+            }"#,
+        );
+
+        let mut seen = Vec::new();
+        walk_openapi_objects(&doc, |ty, path, _| seen.push((ty.to_string(), path.join("/"))));
+
+        assert!(seen.contains(&("openApi3_0".to_string(), String::new())));
+        assert!(seen.contains(&("operation".to_string(), "paths//pets/get".to_string())));
+        assert!(seen.contains(&(
+            "response".to_string(),
+            "paths//pets/get/responses/200".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_overrides_classify_vendor_locations() {
+        let mut doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "x-my-schemas": {
+                    "Pet": { "type": "object", "properties": { "name": { "type": "string" } } }
+                },
+                "x-other": { "Thing": { "type": "object" } }
+            }"#,
+        );
+        let overrides = ClassificationOverrides::new().with_override(
+            ROOT_ELEMENT_TYPE,
+            "x-my-schemas",
+            ElementSlot::Map("schema"),
+        );
+        assert_eq!(
+            overrides.classify("components", "schemas"),
+            Some(ElementSlot::Map("schema"))
+        );
+
+        let mut seen = Vec::new();
+        walk_openapi_objects_with(&doc, &overrides, |ty, path, _| {
+            seen.push((ty.to_string(), path.join("/")))
+        });
+        assert!(seen.contains(&("schema".to_string(), "x-my-schemas/Pet".to_string())));
+        assert!(seen.contains(&(
+            "schema".to_string(),
+            "x-my-schemas/Pet/properties/name".to_string()
+        )));
+        assert!(!seen.iter().any(|(_, path)| path.starts_with("x-other")));
+
+        tag_element_types(&mut doc, &overrides);
+        let pet = doc
+            .as_object()
+            .and_then(|o| o.get("x-my-schemas"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("Pet"))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert_eq!(pet.element, "schema");
+    }
+}
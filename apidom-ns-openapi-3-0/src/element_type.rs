@@ -0,0 +1,215 @@
+//! Interned OpenAPI 3.0 element types.
+//!
+//! `ObjectElement.element` stores the element type as a `String`. The known
+//! OpenAPI 3.0 types are mapped onto [`OpenApiElementType`] so callers can
+//! `match` on an enum instead of comparing strings, and get a `&'static str`
+//! back when setting the type on new elements.
+
+use std::fmt;
+
+use apidom_ast::minim_model::ObjectElement;
+
+/// Element type of an OpenAPI 3.0 object
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OpenApiElementType {
+    OpenApi3_0,
+    Info,
+    Paths,
+    PathItem,
+    Operation,
+    Parameter,
+    RequestBody,
+    Responses,
+    Response,
+    MediaType,
+    Schema,
+    Example,
+    Header,
+    Components,
+    SecurityRequirement,
+    SecurityScheme,
+    Server,
+    ServerVariable,
+    Callback,
+    Link,
+    Xml,
+    Encoding,
+    Tag,
+    Discriminator,
+    License,
+    Contact,
+    OAuthFlow,
+    OAuthFlows,
+    Reference,
+    ExternalDocumentation,
+    /// Any element type that is not part of OpenAPI 3.0
+    Custom(String),
+}
+
+impl OpenApiElementType {
+    /// Every known (non-custom) element type
+    pub const KNOWN: [OpenApiElementType; 30] = [
+        Self::OpenApi3_0,
+        Self::Info,
+        Self::Paths,
+        Self::PathItem,
+        Self::Operation,
+        Self::Parameter,
+        Self::RequestBody,
+        Self::Responses,
+        Self::Response,
+        Self::MediaType,
+        Self::Schema,
+        Self::Example,
+        Self::Header,
+        Self::Components,
+        Self::SecurityRequirement,
+        Self::SecurityScheme,
+        Self::Server,
+        Self::ServerVariable,
+        Self::Callback,
+        Self::Link,
+        Self::Xml,
+        Self::Encoding,
+        Self::Tag,
+        Self::Discriminator,
+        Self::License,
+        Self::Contact,
+        Self::OAuthFlow,
+        Self::OAuthFlows,
+        Self::Reference,
+        Self::ExternalDocumentation,
+    ];
+
+    /// Look up a known element type without allocating
+    pub fn lookup(element_type: &str) -> Option<Self> {
+        let known = match element_type {
+            "openApi3_0" => Self::OpenApi3_0,
+            "info" => Self::Info,
+            "paths" => Self::Paths,
+            "pathItem" => Self::PathItem,
+            "operation" => Self::Operation,
+            "parameter" => Self::Parameter,
+            "requestBody" => Self::RequestBody,
+            "responses" => Self::Responses,
+            "response" => Self::Response,
+            "mediaType" => Self::MediaType,
+            "schema" => Self::Schema,
+            "example" => Self::Example,
+            "header" => Self::Header,
+            "components" => Self::Components,
+            "securityRequirement" => Self::SecurityRequirement,
+            "securityScheme" => Self::SecurityScheme,
+            "server" => Self::Server,
+            "serverVariable" => Self::ServerVariable,
+            "callback" => Self::Callback,
+            "link" => Self::Link,
+            "xml" => Self::Xml,
+            "encoding" => Self::Encoding,
+            "tag" => Self::Tag,
+            "discriminator" => Self::Discriminator,
+            "license" => Self::License,
+            "contact" => Self::Contact,
+            "oAuthFlow" => Self::OAuthFlow,
+            "oAuthFlows" => Self::OAuthFlows,
+            "reference" => Self::Reference,
+            "externalDocumentation" => Self::ExternalDocumentation,
+            _ => return None,
+        };
+        Some(known)
+    }
+
+    /// Element type of an object
+    pub fn of(obj: &ObjectElement) -> Self {
+        Self::from(obj.element.as_str())
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Custom(name) => name,
+            known => known.static_name().unwrap_or_default(),
+        }
+    }
+
+    /// Interned name of a known element type, `None` for `Custom`
+    pub fn static_name(&self) -> Option<&'static str> {
+        let name = match self {
+            Self::OpenApi3_0 => "openApi3_0",
+            Self::Info => "info",
+            Self::Paths => "paths",
+            Self::PathItem => "pathItem",
+            Self::Operation => "operation",
+            Self::Parameter => "parameter",
+            Self::RequestBody => "requestBody",
+            Self::Responses => "responses",
+            Self::Response => "response",
+            Self::MediaType => "mediaType",
+            Self::Schema => "schema",
+            Self::Example => "example",
+            Self::Header => "header",
+            Self::Components => "components",
+            Self::SecurityRequirement => "securityRequirement",
+            Self::SecurityScheme => "securityScheme",
+            Self::Server => "server",
+            Self::ServerVariable => "serverVariable",
+            Self::Callback => "callback",
+            Self::Link => "link",
+            Self::Xml => "xml",
+            Self::Encoding => "encoding",
+            Self::Tag => "tag",
+            Self::Discriminator => "discriminator",
+            Self::License => "license",
+            Self::Contact => "contact",
+            Self::OAuthFlow => "oAuthFlow",
+            Self::OAuthFlows => "oAuthFlows",
+            Self::Reference => "reference",
+            Self::ExternalDocumentation => "externalDocumentation",
+            Self::Custom(_) => return None,
+        };
+        Some(name)
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+}
+
+impl From<&str> for OpenApiElementType {
+    fn from(element_type: &str) -> Self {
+        Self::lookup(element_type).unwrap_or_else(|| Self::Custom(element_type.to_string()))
+    }
+}
+
+impl fmt::Display for OpenApiElementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_types_round_trip() {
+        for known in OpenApiElementType::KNOWN {
+            let name = known.static_name().unwrap();
+            assert_eq!(OpenApiElementType::from(name), known);
+            assert_eq!(known.as_str(), name);
+        }
+        assert_eq!(
+            OpenApiElementType::from("securityScheme"),
+            OpenApiElementType::SecurityScheme
+        );
+    }
+
+    #[test]
+    fn test_unknown_type_is_custom() {
+        let custom = OpenApiElementType::from("asyncChannel");
+        assert_eq!(custom, OpenApiElementType::Custom("asyncChannel".to_string()));
+        assert!(custom.is_custom());
+        assert_eq!(custom.as_str(), "asyncChannel");
+        assert_eq!(custom.static_name(), None);
+        assert_eq!(OpenApiElementType::lookup("object"), None);
+    }
+}
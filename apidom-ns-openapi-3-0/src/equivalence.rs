@@ -0,0 +1,147 @@
+//! Structural comparison of documents modulo references.
+//!
+//! A bundled document, with every `$ref` replaced by a copy of its target,
+//! differs from its source document although both describe the same API.
+//! [`equal_modulo_refs`] compares two element trees treating a reference
+//! object as equal to the element it resolves to, which is what a bundling
+//! test needs to assert.
+
+use std::collections::HashSet;
+
+use apidom_ast::minim_model::Element;
+
+/// Whether `left` and `right` are equal once references are replaced by
+/// their targets.
+///
+/// `resolver` maps a `$ref` value to the referenced element and is used for
+/// both sides; a reference it cannot resolve is compared as the plain
+/// reference object. Object members are compared regardless of their order
+/// and metadata is ignored. A pair of references already being compared
+/// higher up counts as equal, so recursive schemas terminate.
+pub fn equal_modulo_refs<R>(left: &Element, right: &Element, resolver: R) -> bool
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let mut in_progress = HashSet::new();
+    equal(left, right, &resolver, &mut in_progress)
+}
+
+type RefPair = (Option<String>, Option<String>);
+
+fn equal<R>(
+    left: &Element,
+    right: &Element,
+    resolver: &R,
+    in_progress: &mut HashSet<RefPair>,
+) -> bool
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let left_ref = reference_of(left);
+    let right_ref = reference_of(right);
+    let left_target = left_ref.as_deref().and_then(resolver);
+    let right_target = right_ref.as_deref().and_then(resolver);
+    if left_target.is_none() && right_target.is_none() {
+        return equal_resolved(left, right, resolver, in_progress);
+    }
+
+    let pair = (left_ref, right_ref);
+    if !in_progress.insert(pair.clone()) {
+        return true;
+    }
+    let result = equal(
+        left_target.as_ref().unwrap_or(left),
+        right_target.as_ref().unwrap_or(right),
+        resolver,
+        in_progress,
+    );
+    in_progress.remove(&pair);
+    result
+}
+
+fn equal_resolved<R>(
+    left: &Element,
+    right: &Element,
+    resolver: &R,
+    in_progress: &mut HashSet<RefPair>,
+) -> bool
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    match (left, right) {
+        (Element::Object(l), Element::Object(r)) => {
+            l.content.len() == r.content.len()
+                && l.content.iter().all(|member| {
+                    let Some(key) = member.key.as_string() else {
+                        return false;
+                    };
+                    r.get(&key.content)
+                        .is_some_and(|other| equal(&member.value, other, resolver, in_progress))
+                })
+        }
+        (Element::Array(l), Element::Array(r)) => {
+            l.content.len() == r.content.len()
+                && l.content
+                    .iter()
+                    .zip(&r.content)
+                    .all(|(a, b)| equal(a, b, resolver, in_progress))
+        }
+        (Element::Object(_) | Element::Array(_), _) | (_, Element::Object(_) | Element::Array(_)) => {
+            false
+        }
+        _ => left.to_value() == right.to_value(),
+    }
+}
+
+fn reference_of(element: &Element) -> Option<String> {
+    element
+        .as_object()
+        .and_then(|obj| obj.get("$ref"))
+        .and_then(Element::as_string)
+        .map(|reference| reference.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fold_pass::FoldPass;
+    use crate::passes::InlineReferencesPass;
+    use crate::schema_analysis::local_ref_resolver;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r##"{
+        "openapi": "3.0.3",
+        "paths": { "/pets": { "get": { "responses": {
+            "200": { "description": "ok", "content": { "application/json": {
+                "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } }
+            } } }
+        } } } },
+        "components": { "schemas": {
+            "Pet": { "type": "object", "properties": { "name": { "type": "string" } } },
+            "Node": { "type": "object", "properties": { "next": { "$ref": "#/components/schemas/Node" } } }
+        } }
+    }"##;
+
+    #[test]
+    fn test_inlined_document_is_equal_modulo_refs() {
+        let doc = json_source_to_ast(DOCUMENT);
+        let inlined = InlineReferencesPass::new()
+            .apply(&doc)
+            .expect("references should be inlined");
+
+        assert_ne!(doc.to_value(), inlined.to_value());
+        assert!(equal_modulo_refs(&doc, &inlined, local_ref_resolver(&doc)));
+        assert!(equal_modulo_refs(&inlined, &doc, local_ref_resolver(&doc)));
+    }
+
+    #[test]
+    fn test_different_target_is_not_equal() {
+        let doc = json_source_to_ast(DOCUMENT);
+        let changed = json_source_to_ast(&DOCUMENT.replace(
+            r##"{ "$ref": "#/components/schemas/Pet" }"##,
+            r#"{ "type": "object", "properties": { "name": { "type": "integer" } } }"#,
+        ));
+
+        assert!(!equal_modulo_refs(&doc, &changed, local_ref_resolver(&doc)));
+    }
+}
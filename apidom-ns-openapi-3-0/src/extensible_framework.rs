@@ -5,7 +5,7 @@ use serde_json::Value;
 use apidom_ast::minim_model::*;
 use apidom_ast::Fold;
 use crate::specification::{VisitorSpec, VisitorRef};
-use crate::fold_pass::FoldPass;
+use crate::fold_pass::{FoldPass, FoldPipeline, PassStage};
 use crate::reference_resolver::ReferenceResolver;
 use crate::patterned_fields::PatternedFieldsProcessor;
 
@@ -27,6 +27,7 @@ pub struct ExtensibleFramework {
 }
 
 /// Framework configuration
+#[derive(Clone)]
 pub struct FrameworkConfig {
     /// Enable reference resolution
     pub enable_reference_resolution: bool,
@@ -38,8 +39,8 @@ pub struct FrameworkConfig {
     pub enable_validation: bool,
     /// Maximum processing iterations
     pub max_iterations: usize,
-    /// Custom processing hooks
-    pub custom_hooks: HashMap<String, Box<dyn ProcessingHook>>,
+    /// Custom processing hooks, shared between clones of the configuration
+    pub custom_hooks: HashMap<String, Arc<dyn ProcessingHook>>,
 }
 
 impl fmt::Debug for FrameworkConfig {
@@ -55,19 +56,6 @@ impl fmt::Debug for FrameworkConfig {
     }
 }
 
-impl Clone for FrameworkConfig {
-    fn clone(&self) -> Self {
-        Self {
-            enable_reference_resolution: self.enable_reference_resolution,
-            enable_pattern_processing: self.enable_pattern_processing,
-            enable_semantic_enhancement: self.enable_semantic_enhancement,
-            enable_validation: self.enable_validation,
-            max_iterations: self.max_iterations,
-            custom_hooks: HashMap::new(), // Don't clone trait objects
-        }
-    }
-}
-
 /// Supported specification types
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum SpecificationType {
@@ -231,6 +219,8 @@ pub enum SpecificationError {
     ProcessingFailed(String),
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
+    #[error("Unable to detect specification type: {0}")]
+    UnknownSpecification(String),
 }
 
 /// Processing errors
@@ -312,9 +302,8 @@ impl ExtensibleFramework {
     }
 
     /// Internal processing with a specific handler
-    fn process_with_specification(&self, mut element: Element, handler: &dyn SpecificationHandler, context: &ProcessingContext) -> Result<Element, SpecificationError> {
-        // Get fold passes from the handler
-        let passes = handler.get_fold_passes();
+    fn process_with_specification(&self, element: Element, handler: &dyn SpecificationHandler, context: &ProcessingContext) -> Result<Element, SpecificationError> {
+        let pipeline = self.build_pipeline(handler);
         
         // Execute before processing hooks
         for hook in &self.config.custom_hooks {
@@ -322,34 +311,15 @@ impl ExtensibleFramework {
                 .map_err(|e| SpecificationError::ProcessingFailed(e.to_string()))?;
         }
         
-        // Execute fold passes
-        for (_iteration, pass) in passes.iter().enumerate() {
-            // Execute before pass hooks
-            for hook in &self.config.custom_hooks {
-                hook.1.after_pass(&element, pass.name(), context)
-                    .map_err(|e| SpecificationError::ProcessingFailed(e.to_string()))?;
-            }
-            
-            let previous_element = element.clone();
-            
-            // Execute pass
-            let pass_result = pass.apply(&element);
-            
-            if let Some(result) = pass_result {
-                element = result;
-            }
-            
-            // Execute after pass hooks
-            for hook in &self.config.custom_hooks {
-                hook.1.after_pass(&element, pass.name(), context)
-                    .map_err(|e| SpecificationError::ProcessingFailed(e.to_string()))?;
-            }
-            
-            // Check if we should continue
-            if !pass.should_continue(&previous_element, &element) {
-                break;
-            }
-        }
+        // Execute fold passes until the document stops changing, notifying
+        // hooks after every pass
+        let element = pipeline
+            .run_until_fixed_with(&element, |pass_name, current| {
+                self.config.custom_hooks.values()
+                    .try_for_each(|hook| hook.after_pass(current, pass_name, context))
+            })
+            .map_err(|e| SpecificationError::ProcessingFailed(e.to_string()))?
+            .ok_or_else(|| SpecificationError::ProcessingFailed("fold pipeline produced no result".to_string()))?;
         
         // Execute after processing hooks
         for hook in &self.config.custom_hooks {
@@ -360,16 +330,112 @@ impl ExtensibleFramework {
         Ok(element)
     }
 
+    /// Assemble the handler's fold passes into a pipeline, omitting stages
+    /// disabled in the framework configuration
+    fn build_pipeline(&self, handler: &dyn SpecificationHandler) -> FoldPipeline {
+        handler.get_fold_passes()
+            .into_iter()
+            .filter(|pass| self.is_stage_enabled(pass.stage()))
+            .fold(FoldPipeline::new(), |pipeline, pass| pipeline.add_pass(pass))
+            .max_iterations(self.config.max_iterations)
+    }
+
+    /// Whether a processing stage is enabled in the framework configuration
+    fn is_stage_enabled(&self, stage: PassStage) -> bool {
+        match stage {
+            PassStage::Transform => true,
+            PassStage::ReferenceResolution => self.config.enable_reference_resolution,
+            PassStage::PatternProcessing => self.config.enable_pattern_processing,
+            PassStage::SemanticEnhancement => self.config.enable_semantic_enhancement,
+            PassStage::Validation => self.config.enable_validation,
+        }
+    }
+
     /// Detect the specification type of an element
     fn detect_specification_type(&self, element: &Element) -> Result<SpecificationType, SpecificationError> {
-        // Try each registered specification
+        // Inspect well-known root keys first, but only settle on a type
+        // that has a registered handler
+        let root_key_result = Self::detect_from_root_keys(element);
+        if let Ok(Some(spec_type)) = &root_key_result
+            && self.specifications.contains_key(spec_type)
+        {
+            return Ok(spec_type.clone());
+        }
+
+        // Fall back to registered (possibly custom) specifications
         for (spec_type, handler) in &self.specifications {
             if handler.can_handle_element(element) {
                 return Ok(spec_type.clone());
             }
         }
         
-        Err(SpecificationError::UnsupportedSpecification(SpecificationType::Custom("unknown".to_string())))
+        match root_key_result {
+            Ok(Some(spec_type)) => Err(SpecificationError::UnsupportedSpecification(spec_type)),
+            Err(e) => Err(e),
+            Ok(None) => Err(SpecificationError::UnknownSpecification(
+                "document has no recognizable 'openapi', 'asyncapi' or '$schema' root key".to_string(),
+            )),
+        }
+    }
+
+    /// Map the `openapi`, `asyncapi` or `$schema` root key to a specification type
+    fn detect_from_root_keys(element: &Element) -> Result<Option<SpecificationType>, SpecificationError> {
+        let Element::Object(obj) = element else {
+            return Ok(None);
+        };
+
+        let root_string = |key: &str| match obj.get(key) {
+            Some(Element::String(value)) => Ok(Some(value.content.clone())),
+            Some(_) => Err(SpecificationError::UnknownSpecification(format!("'{}' must be a string", key))),
+            None => Ok(None),
+        };
+
+        if let Some(version) = root_string("openapi")? {
+            return if version.starts_with("3.0") {
+                Ok(Some(SpecificationType::OpenApi30))
+            } else if version.starts_with("3.1") {
+                Ok(Some(SpecificationType::OpenApi31))
+            } else {
+                Err(SpecificationError::UnknownSpecification(format!("unsupported OpenAPI version '{}'", version)))
+            };
+        }
+
+        if let Some(version) = root_string("asyncapi")? {
+            let spec_type = [
+                ("2.0", SpecificationType::AsyncApi20),
+                ("2.1", SpecificationType::AsyncApi21),
+                ("2.2", SpecificationType::AsyncApi22),
+                ("2.3", SpecificationType::AsyncApi23),
+                ("2.4", SpecificationType::AsyncApi24),
+                ("2.5", SpecificationType::AsyncApi25),
+                ("2.6", SpecificationType::AsyncApi26),
+                ("3.0", SpecificationType::AsyncApi30),
+            ]
+            .into_iter()
+            .find(|(prefix, _)| version.starts_with(prefix))
+            .map(|(_, spec_type)| spec_type);
+            return spec_type.map(Some).ok_or_else(|| {
+                SpecificationError::UnknownSpecification(format!("unsupported AsyncAPI version '{}'", version))
+            });
+        }
+
+        if let Some(uri) = root_string("$schema")? {
+            let spec_type = [
+                ("draft-04", SpecificationType::JsonSchemaDraft4),
+                ("draft-06", SpecificationType::JsonSchemaDraft6),
+                ("draft-07", SpecificationType::JsonSchemaDraft7),
+                ("2019-09", SpecificationType::JsonSchema201909),
+                ("2020-12", SpecificationType::JsonSchema202012),
+            ]
+            .into_iter()
+            .find(|(draft, _)| uri.contains(draft))
+            .map(|(_, spec_type)| spec_type);
+            return spec_type.map(Some).ok_or_else(|| {
+                SpecificationError::UnknownSpecification(format!("unsupported JSON Schema dialect '{}'", uri))
+            });
+        }
+
+        Ok(None)
     }
 
     /// Validate an element
@@ -408,6 +474,11 @@ impl ExtensibleFramework {
         self.specifications.keys().cloned().collect()
     }
 
+    /// Check whether a handler is registered for a specification type
+    pub fn has_handler(&self, spec_type: &SpecificationType) -> bool {
+        self.specifications.contains_key(spec_type)
+    }
+
     /// Get specification metadata
     pub fn get_specification_metadata(&self, spec_type: &SpecificationType) -> Option<HashMap<String, Value>> {
         self.specifications.get(spec_type).map(|handler| handler.get_metadata())
@@ -697,6 +768,9 @@ impl JsonSchema202012Handler {
         schema_fields.insert("description".to_string(), VisitorRef::Direct(simple_visitor));
         schema_fields.insert("properties".to_string(), VisitorRef::Reference("properties".to_string()));
         schema_fields.insert("items".to_string(), VisitorRef::Reference("schema".to_string()));
+        schema_fields.insert("allOf".to_string(), VisitorRef::Reference("schemaArray".to_string()));
+        schema_fields.insert("anyOf".to_string(), VisitorRef::Reference("schemaArray".to_string()));
+        schema_fields.insert("oneOf".to_string(), VisitorRef::Reference("schemaArray".to_string()));
         schema_fields.insert("required".to_string(), VisitorRef::Direct(simple_visitor));
         schema_fields.insert("enum".to_string(), VisitorRef::Direct(simple_visitor));
         schema_fields.insert("const".to_string(), VisitorRef::Direct(simple_visitor));
@@ -706,6 +780,15 @@ impl JsonSchema202012Handler {
             visitor: Some(simple_visitor),
             fixed_fields: Some(schema_fields),
         });
+        
+        // Keyword values holding schemas: `$defs`/`properties` map names to
+        // schemas, composition keywords hold arrays of schemas
+        for name in ["definitions", "properties", "schemaArray"] {
+            self.visitor_specs.insert(name.to_string(), VisitorSpec {
+                visitor: Some(simple_visitor),
+                fixed_fields: None,
+            });
+        }
     }
 
     fn initialize_fold_passes(&mut self) {
@@ -781,8 +864,16 @@ impl SpecificationHandler for JsonSchema202012Handler {
             for member in &obj.content {
                 if let Element::String(key) = member.key.as_ref() {
                     if key.content == "type" {
-                        if let Element::String(type_val) = member.value.as_ref() {
-                            let valid_types = ["null", "boolean", "object", "array", "number", "integer", "string"];
+                        // `type` is a single name or an array of names
+                        let type_values: Vec<&StringElement> = match member.value.as_ref() {
+                            Element::String(type_val) => vec![type_val],
+                            Element::Array(type_vals) => type_vals.content.iter()
+                                .filter_map(|v| if let Element::String(s) = v { Some(s) } else { None })
+                                .collect(),
+                            _ => Vec::new(),
+                        };
+                        let valid_types = ["null", "boolean", "object", "array", "number", "integer", "string"];
+                        for type_val in type_values {
                             if !valid_types.contains(&type_val.content.as_str()) {
                                 errors.push(ValidationError {
                                     message: format!("Invalid type value: {}", type_val.content),
@@ -793,6 +884,17 @@ impl SpecificationHandler for JsonSchema202012Handler {
                             }
                         }
                     }
+                    if matches!(key.content.as_str(), "allOf" | "anyOf" | "oneOf") {
+                        let is_schema_array = matches!(member.value.as_ref(), Element::Array(items) if !items.content.is_empty());
+                        if !is_schema_array {
+                            errors.push(ValidationError {
+                                message: format!("{} must be a non-empty array of schemas", key.content),
+                                path: vec![key.content.clone()],
+                                code: "INVALID_SCHEMA_ARRAY".to_string(),
+                                severity: ErrorSeverity::Error,
+                            });
+                        }
+                    }
                 }
             }
         }
@@ -869,6 +971,10 @@ impl FoldPass for AsyncApiReferenceResolutionPass {
     fn name(&self) -> &str {
         "AsyncApiReferenceResolutionPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::ReferenceResolution
+    }
 }
 
 #[derive(Debug)]
@@ -888,6 +994,10 @@ impl FoldPass for AsyncApiSemanticEnhancementPass {
     fn name(&self) -> &str {
         "AsyncApiSemanticEnhancementPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::SemanticEnhancement
+    }
 }
 
 #[derive(Debug)]
@@ -907,6 +1017,10 @@ impl FoldPass for AsyncApiValidationPass {
     fn name(&self) -> &str {
         "AsyncApiValidationPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::Validation
+    }
 }
 
 // JSON Schema-specific fold passes
@@ -946,6 +1060,10 @@ impl FoldPass for JsonSchemaReferenceResolutionPass {
     fn name(&self) -> &str {
         "JsonSchemaReferenceResolutionPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::ReferenceResolution
+    }
 }
 
 #[derive(Debug)]
@@ -965,6 +1083,10 @@ impl FoldPass for JsonSchemaSemanticEnhancementPass {
     fn name(&self) -> &str {
         "JsonSchemaSemanticEnhancementPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::SemanticEnhancement
+    }
 }
 
 #[derive(Debug)]
@@ -984,6 +1106,10 @@ impl FoldPass for JsonSchemaValidationPass {
     fn name(&self) -> &str {
         "JsonSchemaValidationPass"
     }
+
+    fn stage(&self) -> PassStage {
+        PassStage::Validation
+    }
 }
 
 /// Default configuration
@@ -1000,6 +1126,14 @@ impl Default for FrameworkConfig {
     }
 }
 
+impl FrameworkConfig {
+    /// Register a processing hook under `name`, replacing any hook with that name
+    pub fn with_hook(mut self, name: &str, hook: Arc<dyn ProcessingHook>) -> Self {
+        self.custom_hooks.insert(name.to_string(), hook);
+        self
+    }
+}
+
 /// Default implementation
 impl Default for ExtensibleFramework {
     fn default() -> Self {
@@ -1011,6 +1145,8 @@ impl Default for ExtensibleFramework {
 mod tests {
     use super::*;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
 
     #[test]
     fn test_asyncapi_specification_detection() {
@@ -1025,6 +1161,250 @@ mod tests {
         assert!(handler.can_handle_element(&element));
     }
 
+    fn root_document(key: &str, value: &str) -> Element {
+        let mut doc = ObjectElement::new();
+        doc.set(key, Element::String(StringElement::new(value)));
+        Element::Object(doc)
+    }
+
+    #[test]
+    fn test_detect_specification_type_from_root_keys() {
+        let detect = |doc: &Element| ExtensibleFramework::detect_from_root_keys(doc).unwrap();
+
+        assert_eq!(detect(&root_document("openapi", "3.0.3")), Some(SpecificationType::OpenApi30));
+        assert_eq!(detect(&root_document("openapi", "3.1.0")), Some(SpecificationType::OpenApi31));
+        assert_eq!(
+            detect(&root_document("$schema", "http://json-schema.org/draft-07/schema#")),
+            Some(SpecificationType::JsonSchemaDraft7)
+        );
+        assert_eq!(detect(&root_document("asyncapi", "2.6.0")), Some(SpecificationType::AsyncApi26));
+
+        // Detection settles on a root-key type once a handler is registered for it
+        let mut framework = ExtensibleFramework::new();
+        framework.register_specification(Arc::new(AsyncApi26Handler::new()));
+        assert_eq!(
+            framework.detect_specification_type(&root_document("asyncapi", "2.6.0")).unwrap(),
+            SpecificationType::AsyncApi26
+        );
+    }
+
+    #[test]
+    fn test_detect_specification_type_prefers_registered_handlers() {
+        let handler = Arc::new(StubHandler { accepts_all: true, ..StubHandler::default() });
+        let mut framework = ExtensibleFramework::new();
+        framework.register_specification(handler);
+
+        let openapi_30 = root_document("openapi", "3.0.3");
+        assert_eq!(
+            framework.detect_specification_type(&openapi_30).unwrap(),
+            SpecificationType::Custom("stub".to_string())
+        );
+        assert!(framework.process_element(openapi_30).is_ok());
+
+        // Without any handler the detected type is reported as unsupported
+        let empty = ExtensibleFramework::new();
+        assert!(matches!(
+            empty.detect_specification_type(&root_document("openapi", "3.0.3")),
+            Err(SpecificationError::UnsupportedSpecification(SpecificationType::OpenApi30))
+        ));
+    }
+
+    #[test]
+    fn test_detect_specification_type_rejects_unknown_documents() {
+        let framework = ExtensibleFramework::new();
+
+        let unknown = root_document("swagger", "2.0");
+        assert!(matches!(
+            framework.detect_specification_type(&unknown),
+            Err(SpecificationError::UnknownSpecification(_))
+        ));
+
+        let unsupported = root_document("openapi", "4.0.0");
+        assert!(matches!(
+            framework.detect_specification_type(&unsupported),
+            Err(SpecificationError::UnknownSpecification(_))
+        ));
+    }
+
+    /// Pass that sets `field` once and counts its invocations
+    struct StubPass {
+        name: &'static str,
+        stage: PassStage,
+        field: &'static str,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl FoldPass for StubPass {
+        fn apply(&self, element: &Element) -> Option<Element> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            let Element::Object(obj) = element else { return None };
+            if obj.has_key(self.field) {
+                return None;
+            }
+            let mut obj = obj.clone();
+            obj.set(self.field, Element::Boolean(BooleanElement::new(true)));
+            Some(Element::Object(obj))
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn stage(&self) -> PassStage {
+            self.stage
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct StubHandler {
+        reference_runs: Arc<AtomicUsize>,
+        validation_runs: Arc<AtomicUsize>,
+        accepts_all: bool,
+    }
+
+    impl SpecificationHandler for StubHandler {
+        fn specification_type(&self) -> SpecificationType {
+            SpecificationType::Custom("stub".to_string())
+        }
+
+        fn get_visitor_specs(&self) -> HashMap<String, VisitorSpec> {
+            HashMap::new()
+        }
+
+        fn get_fold_passes(&self) -> Vec<Box<dyn FoldPass>> {
+            vec![
+                Box::new(StubPass { name: "resolve", stage: PassStage::ReferenceResolution, field: "resolved", runs: self.reference_runs.clone() }),
+                Box::new(StubPass { name: "check", stage: PassStage::Validation, field: "validated", runs: self.validation_runs.clone() }),
+            ]
+        }
+
+        fn can_handle_element(&self, _element: &Element) -> bool {
+            self.accepts_all
+        }
+
+        fn get_root_element_name(&self) -> &str {
+            "stub"
+        }
+
+        fn validate_element(&self, _element: &Element) -> Result<ValidationResult, SpecificationError> {
+            Ok(ValidationResult { is_valid: true, errors: Vec::new(), warnings: Vec::new(), metadata: HashMap::new() })
+        }
+
+        fn transform_element(&self, element: Element, _context: &TransformContext) -> Result<Element, SpecificationError> {
+            Ok(element)
+        }
+
+        fn get_metadata(&self) -> HashMap<String, Value> {
+            HashMap::new()
+        }
+    }
+
+    fn stub_framework(config: FrameworkConfig) -> (ExtensibleFramework, Arc<StubHandler>) {
+        let handler = Arc::new(StubHandler::default());
+        let mut framework = ExtensibleFramework::new().with_config(config);
+        framework.register_specification(handler.clone());
+        (framework, handler)
+    }
+
+    #[test]
+    fn test_process_with_specification_runs_enabled_passes() {
+        let (framework, handler) = stub_framework(FrameworkConfig::default());
+
+        let processed = framework
+            .process_with_specification_type(Element::Object(ObjectElement::new()), SpecificationType::Custom("stub".to_string()))
+            .unwrap();
+
+        let Element::Object(obj) = processed else { panic!("expected object") };
+        assert!(obj.has_key("resolved"));
+        assert!(obj.has_key("validated"));
+        // One changing iteration followed by one that reaches the fixed point
+        assert_eq!(handler.reference_runs.load(Ordering::SeqCst), 2);
+        assert_eq!(handler.validation_runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_process_with_specification_skips_disabled_passes() {
+        let config = FrameworkConfig {
+            enable_reference_resolution: false,
+            enable_validation: false,
+            ..FrameworkConfig::default()
+        };
+        let (framework, handler) = stub_framework(config);
+
+        let processed = framework
+            .process_with_specification_type(Element::Object(ObjectElement::new()), SpecificationType::Custom("stub".to_string()))
+            .unwrap();
+
+        let Element::Object(obj) = processed else { panic!("expected object") };
+        assert!(obj.content.is_empty());
+        assert_eq!(handler.reference_runs.load(Ordering::SeqCst), 0);
+        assert_eq!(handler.validation_runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_process_with_specification_respects_max_iterations() {
+        let config = FrameworkConfig { max_iterations: 1, ..FrameworkConfig::default() };
+        let (framework, handler) = stub_framework(config);
+
+        framework
+            .process_with_specification_type(Element::Object(ObjectElement::new()), SpecificationType::Custom("stub".to_string()))
+            .unwrap();
+
+        assert_eq!(handler.reference_runs.load(Ordering::SeqCst), 1);
+        assert_eq!(handler.validation_runs.load(Ordering::SeqCst), 1);
+    }
+
+    struct RecordingHook {
+        passes: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ProcessingHook for RecordingHook {
+        fn before_processing(&self, _element: &Element, _context: &ProcessingContext) -> Result<(), ProcessingError> {
+            Ok(())
+        }
+
+        fn after_pass(&self, _element: &Element, pass_name: &str, _context: &ProcessingContext) -> Result<(), ProcessingError> {
+            self.passes.lock().unwrap().push(pass_name.to_string());
+            Ok(())
+        }
+
+        fn after_processing(&self, _element: &Element, _context: &ProcessingContext) -> Result<(), ProcessingError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_after_pass_hook_fires_once_per_pass_per_iteration() {
+        let passes = Arc::new(Mutex::new(Vec::new()));
+        let config = FrameworkConfig::default().with_hook("recorder", Arc::new(RecordingHook { passes: passes.clone() }));
+        let (framework, _handler) = stub_framework(config);
+
+        framework
+            .process_with_specification_type(Element::Object(ObjectElement::new()), SpecificationType::Custom("stub".to_string()))
+            .unwrap();
+
+        // Two passes over two iterations
+        assert_eq!(
+            *passes.lock().unwrap(),
+            vec![
+                "resolve",
+                "check",
+                "resolve",
+                "check",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_framework_config_clone_keeps_hooks() {
+        let passes = Arc::new(Mutex::new(Vec::new()));
+        let config = FrameworkConfig::default().with_hook("recorder", Arc::new(RecordingHook { passes }));
+
+        let cloned = config.clone();
+        assert_eq!(cloned.custom_hooks.len(), 1);
+        assert!(Arc::ptr_eq(&config.custom_hooks["recorder"], &cloned.custom_hooks["recorder"]));
+    }
+
     #[test]
     fn test_json_schema_specification_detection() {
         let handler = JsonSchema202012Handler::new();
@@ -1053,6 +1433,40 @@ mod tests {
         let supported = framework.supported_specifications();
         assert!(supported.contains(&SpecificationType::AsyncApi26));
         assert!(supported.contains(&SpecificationType::JsonSchema202012));
+        assert!(!supported.contains(&SpecificationType::OpenApi30));
+        
+        assert!(framework.has_handler(&SpecificationType::AsyncApi26));
+        assert!(framework.has_handler(&SpecificationType::JsonSchema202012));
+        assert!(!framework.has_handler(&SpecificationType::OpenApi30));
+    }
+
+    #[test]
+    fn test_json_schema_202012_document_processing() {
+        let mut framework = ExtensibleFramework::new();
+        framework.register_specification(Arc::new(JsonSchema202012Handler::new()));
+
+        let mut properties = ObjectElement::new();
+        let mut name = ObjectElement::new();
+        name.set("type", Element::String(StringElement::new("string")));
+        properties.set("name", Element::Object(name));
+
+        let mut schema = ObjectElement::new();
+        schema.set("$schema", Element::String(StringElement::new("https://json-schema.org/draft/2020-12/schema")));
+        schema.set("$id", Element::String(StringElement::new("https://example.com/pet")));
+        schema.set("type", Element::String(StringElement::new("object")));
+        schema.set("properties", Element::Object(properties));
+        let schema = Element::Object(schema);
+
+        let validation = framework.validate_element(&schema, None).unwrap();
+        assert!(validation.is_valid);
+        assert!(framework.process_element(schema).is_ok());
+
+        let handler = JsonSchema202012Handler::new();
+        let specs = handler.get_visitor_specs();
+        let fixed_fields = specs["schema"].fixed_fields.as_ref().unwrap();
+        for keyword in ["$id", "$ref", "$defs", "properties", "items", "allOf", "anyOf", "oneOf", "type"] {
+            assert!(fixed_fields.contains_key(keyword), "missing visitor for {}", keyword);
+        }
     }
 
     #[test]
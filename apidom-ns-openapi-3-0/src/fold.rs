@@ -2,96 +2,104 @@ use apidom_ast::minim_model::*;
 use crate::builder::*;
 use apidom_ast::fold::{Fold, DefaultFolder};
 use crate::builder::paths_builder::build_and_decorate_paths;
-use crate::builder::schema_builder::{build_openapi_schema, build_and_decorate_schema};
+use crate::builder::schema_builder::{build_openapi_schema, build_and_decorate_schema_with_options, SchemaBuildOptions};
 use crate::builder::components_builder::{build_and_decorate_components};
+use crate::element_type::OpenApiElementType;
 
 /// Fold that transforms a generic Element AST into an OpenAPI 3.0 Element AST.
 #[derive(Debug, Default)]
-pub struct OpenApiBuilderFolder;
+pub struct OpenApiBuilderFolder {
+    schema_options: SchemaBuildOptions,
+}
 
 impl OpenApiBuilderFolder {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Build schemas with the given options
+    pub fn with_schema_options(mut self, options: SchemaBuildOptions) -> Self {
+        self.schema_options = options;
+        self
     }
 }
 
 impl Fold for OpenApiBuilderFolder {
     fn fold_object_element(&mut self, element: ObjectElement) -> Element {
-        let element_type = element.element.as_str();
-
-        match element_type {
-            "openApi3_0" => {
+        match OpenApiElementType::lookup(&element.element) {
+            Some(OpenApiElementType::OpenApi3_0) => {
                 if let Some(built) = build_openapi3_0(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "info" => {
+            Some(OpenApiElementType::Info) => {
                 if let Some(built) = build_info(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "paths" => {
+            Some(OpenApiElementType::Paths) => {
                 if let Some(built) = build_and_decorate_paths(&Element::Object(element.clone()), Some(self)) {
                     return Element::Object(built.object);
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "pathItem" => {
+            Some(OpenApiElementType::PathItem) => {
                 if let Some(built) = build_and_decorate_path_item(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "operation" => {
+            Some(OpenApiElementType::Operation) => {
                 if let Some(built) = build_operation(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "parameter" => {
+            Some(OpenApiElementType::Parameter) => {
                 if let Some(built) = build_and_decorate_parameter(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "requestBody" => {
+            Some(OpenApiElementType::RequestBody) => {
                 if let Some(built) = build_and_decorate_request_body(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "responses" => {
+            Some(OpenApiElementType::Responses) => {
                 if let Some(built) = build_responses(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "response" => {
+            Some(OpenApiElementType::Response) => {
                 if let Some(built) = build_response(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "mediaType" => {
+            Some(OpenApiElementType::MediaType) => {
                 if let Some(built) = build_media_type(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "schema" => {
-                if let Some(built) = build_and_decorate_schema(&Element::Object(element.clone()), Some(self)) {
+            Some(OpenApiElementType::Schema) => {
+                let options = self.schema_options.clone();
+                if let Some(built) = build_and_decorate_schema_with_options(&Element::Object(element.clone()), Some(self), &options) {
                     Element::Object(built.base.object)
                 } else if let Some(built) = build_openapi_schema(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.base.object)
@@ -99,133 +107,133 @@ impl Fold for OpenApiBuilderFolder {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "example" => {
+            Some(OpenApiElementType::Example) => {
                 if let Some(built) = build_example(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "header" => {
+            Some(OpenApiElementType::Header) => {
                 if let Some(built) = build_header(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "components" => {
+            Some(OpenApiElementType::Components) => {
                 if let Some(built) = build_and_decorate_components(Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "securityRequirement" => {
+            Some(OpenApiElementType::SecurityRequirement) => {
                 if let Some(built) = build_security_requirement(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "securityScheme" => {
+            Some(OpenApiElementType::SecurityScheme) => {
                 if let Some(built) = build_security_scheme(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "server" => {
+            Some(OpenApiElementType::Server) => {
                 if let Some(built) = build_server(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "serverVariable" => {
+            Some(OpenApiElementType::ServerVariable) => {
                 if let Some(built) = build_and_decorate_server_variable(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "callback" => {
+            Some(OpenApiElementType::Callback) => {
                 if let Some(built) = build_and_decorate_callback(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "link" => {
+            Some(OpenApiElementType::Link) => {
                 if let Some(built) = build_link(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "xml" => {
+            Some(OpenApiElementType::Xml) => {
                 if let Some(built) = build_and_decorate_xml(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "encoding" => {
+            Some(OpenApiElementType::Encoding) => {
                 if let Some(built) = build_encoding(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "tag" => {
+            Some(OpenApiElementType::Tag) => {
                 if let Some(built) = build_and_decorate_tag(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "discriminator" => {
+            Some(OpenApiElementType::Discriminator) => {
                 if let Some(built) = build_discriminator(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "license" => {
+            Some(OpenApiElementType::License) => {
                 if let Some(built) = build_and_decorate_license(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "contact" => {
+            Some(OpenApiElementType::Contact) => {
                 if let Some(built) = build_contact(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "oAuthFlow" => {
+            Some(OpenApiElementType::OAuthFlow) => {
                 if let Some(built) = build_and_decorate_oauth_flow(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "oAuthFlows" => {
+            Some(OpenApiElementType::OAuthFlows) => {
                 if let Some(built) = build_and_decorate_oauth_flows(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "reference" => {
+            Some(OpenApiElementType::Reference) => {
                 if let Some(built) = build_reference(&Element::Object(element.clone())) {
                     DefaultFolder.fold_object_element(built.object)
                 } else {
                     DefaultFolder.fold_object_element(element)
                 }
             }
-            "externalDocumentation" => {
+            Some(OpenApiElementType::ExternalDocumentation) => {
                 if let Some(built) = build_and_decorate_external_docs(&Element::Object(element.clone()), Some(self)) {
                     Element::Object(built.object)
                 } else {
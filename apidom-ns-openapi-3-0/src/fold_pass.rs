@@ -1,20 +1,107 @@
 use crate::specification::{OpenApiSpecification, apply_fixed_fields_visitor};
-use apidom_ast::minim_model::Element;
-use std::collections::HashMap;
+use apidom_ast::minim_model::{Element, ObjectElement};
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::extensible_framework::{ErrorSeverity, ValidationError};
+use crate::passes::support::{element_at, element_at_mut, pointer_segments, walk_objects_mut};
+use crate::rules::{RuleSet, RULE_OPENAPI_VERSION};
 
 /// FoldPass represents a single transformation pass over the document
 pub trait FoldPass: Send + Sync {
     /// Apply the pass to an element
     fn apply(&self, element: &Element) -> Option<Element>;
     
-    /// Get the name of this pass for debugging
-    fn name(&self) -> &str;
+    /// Get the name of this pass for debugging; defaults to the type name
+    /// without its module path
+    fn name(&self) -> &str {
+        let type_name = std::any::type_name::<Self>();
+        let base = type_name.split('<').next().unwrap_or(type_name);
+        base.rsplit("::").next().unwrap_or(base)
+    }
     
     /// Check if this pass should continue running
     fn should_continue(&self, previous: &Element, current: &Element) -> bool {
         // Default: continue if elements are different
         !elements_equal(previous, current)
     }
+    
+    /// Processing stage this pass belongs to, used to switch stages on and off
+    fn stage(&self) -> PassStage {
+        PassStage::Transform
+    }
+}
+
+/// Processing stage of a fold pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PassStage {
+    /// General transformation that always runs
+    Transform,
+    /// Reference resolution
+    ReferenceResolution,
+    /// Patterned field processing
+    PatternProcessing,
+    /// Semantic enhancement
+    SemanticEnhancement,
+    /// Validation
+    Validation,
+}
+
+/// Document state captured before a pass changed it
+#[derive(Debug, Clone)]
+pub struct PassSnapshot {
+    /// Name of the pass that changed the document
+    pub pass_name: String,
+    /// Document as it was before the pass ran
+    pub before: Arc<Element>,
+}
+
+/// Cost of one pass accumulated over a pipeline run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PassProfile {
+    /// Name of the pass
+    pub pass_name: String,
+    /// Time spent in the pass over all iterations
+    pub total_duration: Duration,
+    /// Number of times the pass ran
+    pub invocations: usize,
+    /// Number of runs in which the pass changed the document
+    pub changes: usize,
+}
+
+/// Per-pass cost of the last pipeline run, in pipeline order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PipelineProfile {
+    passes: Vec<PassProfile>,
+}
+
+impl PipelineProfile {
+    pub fn passes(&self) -> &[PassProfile] {
+        &self.passes
+    }
+
+    /// Profile of the pass named `pass_name`
+    pub fn get(&self, pass_name: &str) -> Option<&PassProfile> {
+        self.passes.iter().find(|p| p.pass_name == pass_name)
+    }
+
+    /// Time spent in all passes together
+    pub fn total_duration(&self) -> Duration {
+        self.passes.iter().map(|p| p.total_duration).sum()
+    }
+
+    fn record(&mut self, index: usize, pass_name: &str, duration: Duration, changed: bool) {
+        if self.passes.len() <= index {
+            self.passes.resize_with(index + 1, PassProfile::default);
+        }
+        let entry = &mut self.passes[index];
+        entry.pass_name = pass_name.to_string();
+        entry.total_duration += duration;
+        entry.invocations += 1;
+        entry.changes += usize::from(changed);
+    }
 }
 
 /// Pipeline for running multiple fold passes
@@ -22,6 +109,10 @@ pub struct FoldPipeline {
     passes: Vec<Box<dyn FoldPass>>,
     max_iterations: usize,
     debug: bool,
+    snapshots_enabled: bool,
+    snapshots: Mutex<Vec<PassSnapshot>>,
+    profiling_enabled: bool,
+    profile: Mutex<PipelineProfile>,
 }
 
 impl FoldPipeline {
@@ -31,6 +122,10 @@ impl FoldPipeline {
             passes: Vec::new(),
             max_iterations: 10,
             debug: false,
+            snapshots_enabled: false,
+            snapshots: Mutex::new(Vec::new()),
+            profiling_enabled: false,
+            profile: Mutex::new(PipelineProfile::default()),
         }
     }
     
@@ -40,6 +135,36 @@ impl FoldPipeline {
         self
     }
     
+    /// Insert a pass at `index`, shifting later passes back
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of passes.
+    pub fn insert_pass(&mut self, index: usize, pass: Box<dyn FoldPass>) {
+        assert!(
+            index <= self.passes.len(),
+            "cannot insert pass '{}' at index {}: pipeline has {} passes",
+            pass.name(),
+            index,
+            self.passes.len()
+        );
+        self.passes.insert(index, pass);
+    }
+    
+    /// Remove and return the pass at `index`, or `None` if out of range
+    pub fn remove_pass(&mut self, index: usize) -> Option<Box<dyn FoldPass>> {
+        (index < self.passes.len()).then(|| self.passes.remove(index))
+    }
+    
+    /// Swap the passes at `a` and `b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of range.
+    pub fn swap_passes(&mut self, a: usize, b: usize) {
+        self.passes.swap(a, b);
+    }
+    
     /// Set maximum iterations for run_until_fixed
     pub fn max_iterations(mut self, max: usize) -> Self {
         self.max_iterations = max;
@@ -52,32 +177,131 @@ impl FoldPipeline {
         self
     }
     
+    /// Record the document before every pass that changes it, so changes
+    /// can be reverted one pass at a time with [`FoldPipeline::undo`].
+    /// Each snapshot holds a full copy of the document.
+    pub fn with_snapshots(mut self, enabled: bool) -> Self {
+        self.snapshots_enabled = enabled;
+        self
+    }
+    
+    /// Measure the time, invocation count and change count of every pass;
+    /// read the result with [`FoldPipeline::profile`] after a run
+    pub fn with_profiling(mut self, enabled: bool) -> Self {
+        self.profiling_enabled = enabled;
+        self
+    }
+    
     /// Get the number of passes in this pipeline
     pub fn pass_count(&self) -> usize {
         self.passes.len()
     }
     
+    /// Snapshots recorded by the last run, oldest first
+    pub fn snapshots(&self) -> Vec<PassSnapshot> {
+        self.snapshots.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+    
+    /// Pop the most recent snapshot; its `before` is the document as it was
+    /// before the last recorded pass ran
+    pub fn undo(&self) -> Option<PassSnapshot> {
+        self.snapshots.lock().ok().and_then(|mut s| s.pop())
+    }
+    
+    /// Profile of the last run; empty unless profiling is enabled
+    pub fn profile(&self) -> PipelineProfile {
+        self.profile.lock().map(|p| p.clone()).unwrap_or_default()
+    }
+    
+    /// Wall-clock time of every pass during the last run, in pipeline order;
+    /// empty unless profiling is enabled
+    pub fn last_profile(&self) -> Vec<(String, Duration)> {
+        self.profile()
+            .passes()
+            .iter()
+            .map(|p| (p.pass_name.clone(), p.total_duration))
+            .collect()
+    }
+    
+    fn reset_run_state(&self) {
+        if let Ok(mut snapshots) = self.snapshots.lock() {
+            snapshots.clear();
+        }
+        if let Ok(mut profile) = self.profile.lock() {
+            *profile = PipelineProfile::default();
+        }
+    }
+    
+    /// Run a single pass, recording its cost when profiling is enabled
+    fn apply_pass(&self, index: usize, pass: &dyn FoldPass, element: &Element) -> Option<Element> {
+        if !self.profiling_enabled {
+            return pass.apply(element);
+        }
+        let started = Instant::now();
+        let result = pass.apply(element);
+        let elapsed = started.elapsed();
+        if let Ok(mut profile) = self.profile.lock() {
+            let changed = result
+                .as_ref()
+                .is_some_and(|transformed| pass.should_continue(element, transformed));
+            profile.record(index, pass.name(), elapsed, changed);
+        }
+        result
+    }
+    
+    /// Make `transformed` the current document, recording the previous one
+    /// as a snapshot when snapshots are enabled. The snapshot shares the
+    /// previous document's allocation rather than copying it.
+    fn advance(&self, pass: &dyn FoldPass, current: &mut Arc<Element>, transformed: Element) {
+        let before = std::mem::replace(current, Arc::new(transformed));
+        if !self.snapshots_enabled {
+            return;
+        }
+        if let Ok(mut snapshots) = self.snapshots.lock() {
+            snapshots.push(PassSnapshot {
+                pass_name: pass.name().to_string(),
+                before,
+            });
+        }
+    }
+    
     /// Run all passes once
     pub fn run_once(&self, element: &Element) -> Option<Element> {
-        let mut current = element.clone();
+        let mut current = Arc::new(element.clone());
+        self.reset_run_state();
         
-        for pass in &self.passes {
+        for (index, pass) in self.passes.iter().enumerate() {
             if self.debug {
                 println!("Running pass: {}", pass.name());
             }
             
-            if let Some(transformed) = pass.apply(&current) {
-                current = transformed;
+            if let Some(transformed) = self.apply_pass(index, pass.as_ref(), &current)
+                && pass.should_continue(&current, &transformed)
+            {
+                self.advance(pass.as_ref(), &mut current, transformed);
             }
         }
         
-        Some(current)
+        Some(Arc::unwrap_or_clone(current))
     }
     
     /// Run passes until no more changes occur (fixed point)
     pub fn run_until_fixed(&self, element: &Element) -> Option<Element> {
-        let mut current = element.clone();
+        self.run_until_fixed_with(element, |_, _| Ok::<(), Infallible>(()))
+            .unwrap_or_else(|never| match never {})
+    }
+    
+    /// Run passes until no more changes occur, calling `after_pass` with the
+    /// pass name and the current document after every pass of every
+    /// iteration. The run stops at the first error returned by `after_pass`.
+    pub fn run_until_fixed_with<E>(
+        &self,
+        element: &Element,
+        mut after_pass: impl FnMut(&str, &Element) -> Result<(), E>,
+    ) -> Result<Option<Element>, E> {
+        let mut current = Arc::new(element.clone());
         let mut iteration = 0;
+        self.reset_run_state();
         
         loop {
             if iteration >= self.max_iterations {
@@ -87,20 +311,21 @@ impl FoldPipeline {
                 break;
             }
             
-            let _previous = current.clone();
             let mut changed = false;
             
-            for pass in &self.passes {
+            for (index, pass) in self.passes.iter().enumerate() {
                 if self.debug {
                     println!("Running pass: {} (iteration {})", pass.name(), iteration);
                 }
                 
-                if let Some(transformed) = pass.apply(&current) {
+                if let Some(transformed) = self.apply_pass(index, pass.as_ref(), &current) {
                     if pass.should_continue(&current, &transformed) {
-                        current = transformed;
+                        self.advance(pass.as_ref(), &mut current, transformed);
                         changed = true;
                     }
                 }
+                
+                after_pass(pass.name(), &current)?;
             }
             
             if !changed {
@@ -113,7 +338,27 @@ impl FoldPipeline {
             iteration += 1;
         }
         
-        Some(current)
+        Ok(Some(Arc::unwrap_or_clone(current)))
+    }
+    
+    /// Run all passes exactly `n` times, ignoring change detection.
+    /// Returns `None` as soon as any pass returns `None`.
+    pub fn run_n_times(&self, element: &Element, n: usize) -> Option<Element> {
+        let mut current = Arc::new(element.clone());
+        self.reset_run_state();
+        
+        for iteration in 0..n {
+            for (index, pass) in self.passes.iter().enumerate() {
+                if self.debug {
+                    println!("Running pass: {} (iteration {})", pass.name(), iteration);
+                }
+                
+                let transformed = self.apply_pass(index, pass.as_ref(), &current)?;
+                self.advance(pass.as_ref(), &mut current, transformed);
+            }
+        }
+        
+        Some(Arc::unwrap_or_clone(current))
     }
 }
 
@@ -123,6 +368,49 @@ impl Default for FoldPipeline {
     }
 }
 
+/// Pass that runs an inner pass only on elements accepted by a predicate
+///
+/// Rejected elements are returned unchanged, which the pipeline treats as no
+/// change.
+pub struct ConditionalPass {
+    inner: Box<dyn FoldPass>,
+    predicate: Box<dyn Fn(&Element) -> bool + Send + Sync>,
+}
+
+impl ConditionalPass {
+    pub fn new<P>(inner: Box<dyn FoldPass>, predicate: P) -> Self
+    where
+        P: Fn(&Element) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            predicate: Box::new(predicate),
+        }
+    }
+}
+
+impl FoldPass for ConditionalPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        if (self.predicate)(element) {
+            self.inner.apply(element)
+        } else {
+            Some(element.clone())
+        }
+    }
+    
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    
+    fn should_continue(&self, previous: &Element, current: &Element) -> bool {
+        self.inner.should_continue(previous, current)
+    }
+    
+    fn stage(&self) -> PassStage {
+        self.inner.stage()
+    }
+}
+
 /// OpenAPI specification-aware fold pass
 pub struct OpenApiSpecPass {
     spec: OpenApiSpecification,
@@ -139,7 +427,7 @@ impl FoldPass for OpenApiSpecPass {
     fn apply(&self, element: &Element) -> Option<Element> {
         // Determine element type and apply appropriate visitor
         let element_type = determine_element_type(element);
-        apply_fixed_fields_visitor(&self.spec, element, &element_type)
+        apply_fixed_fields_visitor(&self.spec, element, &element_type, None)
     }
     
     fn name(&self) -> &str {
@@ -147,10 +435,27 @@ impl FoldPass for OpenApiSpecPass {
     }
 }
 
+/// Metadata key set to `true` on reference objects whose target leads back
+/// to the reference itself
+pub const CIRCULAR_REFERENCE_KEY: &str = "circular-reference";
+
+/// Metadata key set to `true` on reference objects whose local target does
+/// not exist
+pub const RESOLUTION_FAILED_KEY: &str = "resolution-failed";
+
 /// Reference resolution pass
+///
+/// Follows every local `$ref` and marks the references that close a cycle
+/// (A → B → A) with [`CIRCULAR_REFERENCE_KEY`]. Cycles are detected by
+/// tracking the pointers being resolved instead of relying on the
+/// pipeline's iteration limit, so one application marks every cycle.
+/// References whose target does not exist are marked with
+/// [`RESOLUTION_FAILED_KEY`] and listed by
+/// [`ReferenceResolutionPass::unresolved_references`].
 pub struct ReferenceResolutionPass {
     name: String,
     reference_cache: HashMap<String, Element>,
+    unresolved: Mutex<Vec<String>>,
 }
 
 impl ReferenceResolutionPass {
@@ -158,6 +463,7 @@ impl ReferenceResolutionPass {
         Self {
             name: "ReferenceResolution".to_string(),
             reference_cache: HashMap::new(),
+            unresolved: Mutex::new(Vec::new()),
         }
     }
     
@@ -165,64 +471,171 @@ impl ReferenceResolutionPass {
         self.reference_cache = cache;
         self
     }
+
+    /// `$ref` values whose target could not be found during the last run,
+    /// in document order
+    pub fn unresolved_references(&self) -> Vec<String> {
+        self.unresolved.lock().map(|u| u.clone()).unwrap_or_default()
+    }
 }
 
 impl FoldPass for ReferenceResolutionPass {
     fn apply(&self, element: &Element) -> Option<Element> {
-        resolve_references_in_element(element, &self.reference_cache)
+        let mut unresolved = Vec::new();
+        let result = resolve_references_in_element(element, &self.reference_cache, &mut unresolved);
+        if let Ok(mut last) = self.unresolved.lock() {
+            *last = unresolved;
+        }
+        result
     }
     
     fn name(&self) -> &str {
         &self.name
     }
+    
+    fn stage(&self) -> PassStage {
+        PassStage::ReferenceResolution
+    }
 }
 
+/// Metadata key marking an object as enhanced; holds the element type the
+/// semantic classes were derived from
+pub const SEMANTIC_ENHANCED_KEY: &str = "semantic-enhanced";
+
 /// Semantic enhancement pass
+///
+/// Adds semantic classes to every object whose type it recognizes and marks
+/// it with [`SEMANTIC_ENHANCED_KEY`]. Marked objects whose type has not
+/// changed are skipped on later runs, so re-running the pass is cheap and
+/// leaves an already enhanced document untouched.
 pub struct SemanticEnhancementPass {
     name: String,
+    touched: AtomicUsize,
+    applied: Mutex<Vec<(String, String)>>,
 }
 
 impl SemanticEnhancementPass {
     pub fn new() -> Self {
         Self {
             name: "SemanticEnhancement".to_string(),
+            touched: AtomicUsize::new(0),
+            applied: Mutex::new(Vec::new()),
         }
     }
+
+    /// Number of objects enhanced by the last run of the pass
+    pub fn last_touched(&self) -> usize {
+        self.touched.load(Ordering::Relaxed)
+    }
+
+    /// `(element_type, class_name)` pairs for every class added by the last
+    /// run of the pass, in document order
+    pub fn applied_classes(&self) -> Vec<(String, String)> {
+        self.applied.lock().map(|a| a.clone()).unwrap_or_default()
+    }
 }
 
 impl FoldPass for SemanticEnhancementPass {
     fn apply(&self, element: &Element) -> Option<Element> {
-        enhance_element_semantics(element)
+        let mut result = element.clone();
+        let mut applied = Vec::new();
+        let touched = enhance_element_semantics(&mut result, &mut applied);
+        self.touched.store(touched, Ordering::Relaxed);
+        if let Ok(mut classes) = self.applied.lock() {
+            *classes = applied;
+        }
+        (touched > 0).then_some(result)
     }
     
     fn name(&self) -> &str {
         &self.name
     }
+    
+    fn stage(&self) -> PassStage {
+        PassStage::SemanticEnhancement
+    }
 }
 
 /// Validation pass
 pub struct ValidationPass {
     name: String,
     strict: bool,
+    rules: RuleSet,
+    errors: Mutex<Vec<ValidationError>>,
 }
 
 impl ValidationPass {
+    /// In strict mode an unsupported `openapi` version is reported as an
+    /// error instead of a warning
     pub fn new(strict: bool) -> Self {
         Self {
             name: "Validation".to_string(),
             strict,
+            rules: Self::apply_strictness(RuleSet::default(), strict),
+            errors: Mutex::new(Vec::new()),
         }
     }
+
+    /// Use a custom rule set instead of the built-in OpenAPI 3.0 rules;
+    /// strict mode still raises the version rule to an error
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = Self::apply_strictness(rules, self.strict);
+        self
+    }
+
+    fn apply_strictness(mut rules: RuleSet, strict: bool) -> RuleSet {
+        if strict {
+            rules.set_severity(RULE_OPENAPI_VERSION, ErrorSeverity::Error);
+        }
+        rules
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    pub fn rules_mut(&mut self) -> &mut RuleSet {
+        &mut self.rules
+    }
+
+    /// Run the enabled rules against a document
+    pub fn check(&self, element: &Element) -> Vec<ValidationError> {
+        self.rules.check(element)
+    }
+
+    /// Errors found by the last run of the pass, with the path and rule code
+    /// of each issue
+    pub fn errors(&self) -> Vec<ValidationError> {
+        self.errors.lock().map(|e| e.clone()).unwrap_or_default()
+    }
 }
 
 impl FoldPass for ValidationPass {
     fn apply(&self, element: &Element) -> Option<Element> {
-        validate_element(element, self.strict)
+        let errors = self.check(element);
+        if let Ok(mut found) = self.errors.lock() {
+            *found = errors.clone();
+        }
+
+        let mut result = validate_element(element, self.strict)?;
+        for error in errors {
+            if let Some(Element::Object(obj)) = element_at_mut(&mut result, &error.path) {
+                obj.meta.properties.insert(
+                    format!("validationError_{}", error.code),
+                    serde_json::Value::String(error.message),
+                );
+            }
+        }
+        Some(result)
     }
     
     fn name(&self) -> &str {
         &self.name
     }
+    
+    fn stage(&self) -> PassStage {
+        PassStage::Validation
+    }
 }
 
 /// Create a standard OpenAPI 3.0 processing pipeline
@@ -251,40 +664,7 @@ pub fn create_strict_openapi_pipeline(spec: OpenApiSpecification) -> FoldPipelin
 /// Determine the type of an OpenAPI element
 fn determine_element_type(element: &Element) -> String {
     match element {
-        Element::Object(obj) => {
-            // Check for specific OpenAPI patterns
-            let keys: Vec<String> = obj.content.iter()
-                .filter_map(|member| {
-                    if let Element::String(key) = &*member.key {
-                        Some(key.content.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            
-            // Determine type based on field patterns
-            if keys.contains(&"openapi".to_string()) {
-                "openApi3_0".to_string()
-            } else if keys.contains(&"title".to_string()) && keys.contains(&"version".to_string()) {
-                "info".to_string()
-            } else if keys.contains(&"$ref".to_string()) {
-                "reference".to_string()
-            } else if keys.contains(&"type".to_string()) || keys.contains(&"properties".to_string()) {
-                "schema".to_string()
-            } else if keys.contains(&"description".to_string()) && keys.contains(&"content".to_string()) {
-                "response".to_string()
-            } else if keys.contains(&"name".to_string()) && keys.contains(&"in".to_string()) {
-                "parameter".to_string()
-            } else if keys.contains(&"url".to_string()) && keys.contains(&"variables".to_string()) {
-                "server".to_string()
-            } else if keys.contains(&"get".to_string()) || keys.contains(&"post".to_string()) || 
-                     keys.contains(&"put".to_string()) || keys.contains(&"delete".to_string()) {
-                "pathItem".to_string()
-            } else {
-                "object".to_string()
-            }
-        }
+        Element::Object(obj) => determine_object_type(obj),
         Element::Array(_) => "array".to_string(),
         Element::String(_) => "string".to_string(),
         Element::Number(_) => "number".to_string(),
@@ -294,6 +674,42 @@ fn determine_element_type(element: &Element) -> String {
     }
 }
 
+/// Determine the type of an OpenAPI object from its fields
+fn determine_object_type(obj: &ObjectElement) -> String {
+    // Check for specific OpenAPI patterns
+    let keys: Vec<String> = obj.content.iter()
+        .filter_map(|member| {
+            if let Element::String(key) = &*member.key {
+                Some(key.content.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+    
+    // Determine type based on field patterns
+    if keys.contains(&"openapi".to_string()) {
+        "openApi3_0".to_string()
+    } else if keys.contains(&"title".to_string()) && keys.contains(&"version".to_string()) {
+        "info".to_string()
+    } else if keys.contains(&"$ref".to_string()) {
+        "reference".to_string()
+    } else if keys.contains(&"type".to_string()) || keys.contains(&"properties".to_string()) {
+        "schema".to_string()
+    } else if keys.contains(&"description".to_string()) && keys.contains(&"content".to_string()) {
+        "response".to_string()
+    } else if keys.contains(&"name".to_string()) && keys.contains(&"in".to_string()) {
+        "parameter".to_string()
+    } else if keys.contains(&"url".to_string()) && keys.contains(&"variables".to_string()) {
+        "server".to_string()
+    } else if keys.contains(&"get".to_string()) || keys.contains(&"post".to_string()) || 
+             keys.contains(&"put".to_string()) || keys.contains(&"delete".to_string()) {
+        "pathItem".to_string()
+    } else {
+        "object".to_string()
+    }
+}
+
 /// Check if two elements are equal
 fn elements_equal(a: &Element, b: &Element) -> bool {
     // This is a simplified equality check
@@ -302,71 +718,128 @@ fn elements_equal(a: &Element, b: &Element) -> bool {
 }
 
 /// Resolve references in an element
-fn resolve_references_in_element(element: &Element, _cache: &HashMap<String, Element>) -> Option<Element> {
-    // Simplified reference resolution
-    // In a full implementation, you would:
-    // 1. Find all $ref fields
-    // 2. Resolve them using the cache or by loading from external sources
-    // 3. Replace the reference with the resolved content
-    
-    if let Element::Object(obj) = element {
-        let has_ref = obj.content.iter().any(|member| {
-            if let Element::String(key) = &*member.key {
-                key.content == "$ref"
-            } else {
-                false
+fn resolve_references_in_element(
+    element: &Element,
+    cache: &HashMap<String, Element>,
+    unresolved: &mut Vec<String>,
+) -> Option<Element> {
+    let mut result = element.clone();
+    let mut changed = false;
+
+    walk_objects_mut(&mut result, &mut |path, obj| {
+        let Some(reference) = obj.get("$ref").and_then(Element::as_string) else {
+            return;
+        };
+        if !reference.content.starts_with('#') {
+            return;
+        }
+        let reference = reference.content.clone();
+
+        let found = cache.contains_key(&reference)
+            || element_at(element, &pointer_segments(&reference)).is_some();
+        if !found {
+            unresolved.push(reference);
+            if !obj.meta.properties.contains_key(RESOLUTION_FAILED_KEY) {
+                obj.meta
+                    .properties
+                    .insert(RESOLUTION_FAILED_KEY.to_string(), serde_json::Value::Bool(true));
+                changed = true;
             }
-        });
-        
-        if has_ref {
-            // For now, just add metadata indicating this is a reference
-            let new_obj = obj.clone();
-            // Add reference metadata here
-            return Some(Element::Object(new_obj));
+            return;
+        }
+
+        if !obj.meta.properties.contains_key(CIRCULAR_REFERENCE_KEY)
+            && reference_leads_back(element, &reference, path)
+        {
+            obj.meta
+                .properties
+                .insert(CIRCULAR_REFERENCE_KEY.to_string(), serde_json::Value::Bool(true));
+            changed = true;
+        }
+    });
+
+    changed.then_some(result)
+}
+
+/// Whether resolving `target`, and the references inside it, re-enters a
+/// pointer that contains the reference found at `location`
+fn reference_leads_back(root: &Element, target: &str, location: &[String]) -> bool {
+    let mut resolving = HashSet::new();
+    let mut pending = vec![target.to_string()];
+    while let Some(pointer) = pending.pop() {
+        if !resolving.insert(pointer.clone()) {
+            continue;
+        }
+        let segments = pointer_segments(&pointer);
+        if location.starts_with(&segments) {
+            return true;
+        }
+        if let Some(resolved) = element_at(root, &segments) {
+            collect_local_references(resolved, &mut pending);
         }
     }
-    
-    Some(element.clone())
+    false
 }
 
-/// Enhance element with semantic information
-fn enhance_element_semantics(element: &Element) -> Option<Element> {
-    // Add semantic classes, metadata, etc.
-    // This is where we would add the semantic tree enhancements
-    
+/// Push every local `$ref` value found in `element` onto `references`
+fn collect_local_references(element: &Element, references: &mut Vec<String>) {
     match element {
         Element::Object(obj) => {
-            let mut enhanced_obj = obj.clone();
-            
-            // Add semantic classes based on element type
-            let element_type = determine_element_type(element);
-            match element_type.as_str() {
-                "openApi3_0" => {
-                    // Add OpenAPI root classes
-                    enhanced_obj.add_class("openapi");
-                    enhanced_obj.add_class("openapi-3-0");
-                }
-                "info" => {
-                    enhanced_obj.add_class("info");
-                    enhanced_obj.add_class("openapi-info");
-                }
-                "schema" => {
-                    enhanced_obj.add_class("schema");
-                    enhanced_obj.add_class("json-schema");
-                }
-                "reference" => {
-                    enhanced_obj.add_class("reference");
-                    enhanced_obj.add_class("json-reference");
+            for member in &obj.content {
+                match (member.key.as_ref(), member.value.as_ref()) {
+                    (Element::String(key), Element::String(value))
+                        if key.content == "$ref" && value.content.starts_with('#') =>
+                    {
+                        references.push(value.content.clone());
+                    }
+                    (_, value) => collect_local_references(value, references),
                 }
-                _ => {}
             }
-            
-            Some(Element::Object(enhanced_obj))
         }
-        _ => Some(element.clone()),
+        Element::Array(arr) => {
+            for item in &arr.content {
+                collect_local_references(item, references);
+            }
+        }
+        _ => {}
     }
 }
 
+/// Enhance element with semantic information, returning how many objects
+/// were (re-)enhanced
+fn enhance_element_semantics(element: &mut Element, applied: &mut Vec<(String, String)>) -> usize {
+    let mut touched = 0;
+    walk_objects_mut(element, &mut |_, obj| {
+        let element_type = determine_object_type(obj);
+        let classes: &[&str] = match element_type.as_str() {
+            "openApi3_0" => &["openapi", "openapi-3-0"],
+            "info" => &["info", "openapi-info"],
+            "schema" => &["schema", "json-schema"],
+            "reference" => &["reference", "json-reference"],
+            _ => return,
+        };
+        let marker = serde_json::Value::String(element_type.clone());
+        if obj.meta.properties.get(SEMANTIC_ENHANCED_KEY) == Some(&marker) {
+            return;
+        }
+
+        for class in classes {
+            let present = obj
+                .classes
+                .content
+                .iter()
+                .any(|c| matches!(c, Element::String(s) if s.content == *class));
+            if !present {
+                obj.add_class(class);
+                applied.push((element_type.clone(), class.to_string()));
+            }
+        }
+        obj.meta.properties.insert(SEMANTIC_ENHANCED_KEY.to_string(), marker);
+        touched += 1;
+    });
+    touched
+}
+
 /// Validate an element
 fn validate_element(element: &Element, _strict: bool) -> Option<Element> {
     // Perform validation and potentially add validation metadata
@@ -461,4 +934,467 @@ mod tests {
         let element_type = determine_element_type(&element);
         assert_eq!(element_type, "object");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_validation_pass_consults_rule_set() {
+        use crate::rules::{RULE_INFO_TITLE, RULE_INFO_VERSION};
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3", "info": {}, "paths": {} }"#);
+
+        let mut pass = ValidationPass::new(false);
+        pass.rules_mut().disable(RULE_INFO_VERSION);
+        let codes: Vec<String> = pass.check(&doc).into_iter().map(|e| e.code).collect();
+        assert_eq!(codes, vec![RULE_INFO_TITLE.to_string()]);
+
+        let result = pass.apply(&doc).unwrap();
+        let info = result.as_object().and_then(|o| o.get("info")).and_then(Element::as_object).unwrap();
+        assert!(info.meta.properties.contains_key(&format!("validationError_{}", RULE_INFO_TITLE)));
+        assert!(!info.meta.properties.contains_key(&format!("validationError_{}", RULE_INFO_VERSION)));
+    }
+
+    #[test]
+    fn test_validation_pass_reports_errors() {
+        use crate::rules::{RULE_INFO_TITLE, RULE_INFO_VERSION, RULE_PATH_LEADING_SLASH};
+        use apidom_ast::fold::json_source_to_ast;
+
+        let pass = ValidationPass::new(false);
+        assert!(pass.errors().is_empty());
+
+        pass.apply(&json_source_to_ast(r#"{ "openapi": "3.0.3", "info": {}, "paths": {} }"#));
+        let errors = pass.errors();
+        let codes: Vec<&str> = errors.iter().map(|e| e.code.as_str()).collect();
+        assert!(codes.contains(&RULE_INFO_TITLE));
+        assert!(codes.contains(&RULE_INFO_VERSION));
+        assert!(errors.iter().all(|e| e.path == vec!["info"]));
+
+        pass.apply(&json_source_to_ast(
+            r#"{ "openapi": "3.0.3", "info": { "title": "Pets", "version": "1.0" }, "paths": { "users": {} } }"#,
+        ));
+        let errors = pass.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, RULE_PATH_LEADING_SLASH);
+        assert_eq!(errors[0].path, vec!["paths", "users"]);
+    }
+
+    #[test]
+    fn test_validation_pass_openapi_version_severity() {
+        use apidom_ast::fold::json_source_to_ast;
+        use crate::rules::RULE_OPENAPI_FIELD;
+
+        let version_errors = |pass: &ValidationPass, source: &str| -> Vec<ValidationError> {
+            pass.apply(&json_source_to_ast(source));
+            pass.errors()
+                .into_iter()
+                .filter(|e| e.code == RULE_OPENAPI_VERSION)
+                .collect()
+        };
+        let lenient = ValidationPass::new(false);
+        let strict = ValidationPass::new(true);
+
+        assert!(version_errors(&strict, r#"{ "openapi": "3.0.3", "paths": {} }"#).is_empty());
+
+        let swagger = r#"{ "openapi": "2.0", "paths": {} }"#;
+        assert_eq!(version_errors(&lenient, swagger)[0].severity, ErrorSeverity::Warning);
+        assert_eq!(version_errors(&strict, swagger)[0].severity, ErrorSeverity::Error);
+
+        let missing_version = version_errors(&strict, r#"{ "paths": {} }"#);
+        assert_eq!(missing_version.len(), 1);
+        assert_eq!(missing_version[0].severity, ErrorSeverity::Error);
+        let missing: Vec<_> = strict.errors().into_iter().filter(|e| e.code == RULE_OPENAPI_FIELD).collect();
+        assert_eq!(missing.len(), 1);
+
+        let custom = ValidationPass::new(true).with_rules(RuleSet::default());
+        assert_eq!(version_errors(&custom, swagger)[0].severity, ErrorSeverity::Error);
+    }
+
+    struct SetFieldPass(&'static str);
+
+    impl FoldPass for SetFieldPass {
+        fn apply(&self, element: &Element) -> Option<Element> {
+            use apidom_ast::minim_model::BooleanElement;
+
+            let mut obj = element.as_object()?.clone();
+            if obj.has_key(self.0) {
+                return None;
+            }
+            obj.set(self.0, Element::Boolean(BooleanElement::new(true)));
+            Some(Element::Object(obj))
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    struct CountingPass(&'static str, Arc<AtomicUsize>);
+
+    impl FoldPass for CountingPass {
+        fn apply(&self, element: &Element) -> Option<Element> {
+            self.1.fetch_add(1, Ordering::SeqCst);
+            Some(element.clone())
+        }
+
+        fn name(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_run_n_times_applies_each_pass_n_times() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3" }"#);
+        let first = Arc::new(AtomicUsize::new(0));
+        let second = Arc::new(AtomicUsize::new(0));
+        let pipeline = FoldPipeline::new()
+            .add_pass(Box::new(CountingPass("first", first.clone())))
+            .add_pass(Box::new(CountingPass("second", second.clone())));
+
+        let result = pipeline.run_n_times(&doc, 2).unwrap();
+        assert_eq!(result.to_value(), doc.to_value());
+        assert_eq!(first.load(Ordering::SeqCst), 2);
+        assert_eq!(second.load(Ordering::SeqCst), 2);
+
+        let stopping = FoldPipeline::new()
+            .add_pass(Box::new(SetFieldPass("once")))
+            .add_pass(Box::new(CountingPass("after", first.clone())));
+        assert!(stopping.run_n_times(&doc, 2).is_none());
+        assert_eq!(first.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_edit_pipeline_passes() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3", "info": {}, "paths": {} }"#);
+        let mut pipeline = FoldPipeline::new()
+            .add_pass(Box::new(SetFieldPass("first")))
+            .add_pass(Box::new(ValidationPass::new(false)));
+        pipeline.insert_pass(0, Box::new(SetFieldPass("zeroth")));
+        pipeline.swap_passes(0, 1);
+        let names: Vec<&str> = pipeline.passes.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["first", "zeroth", "Validation"]);
+
+        let validated = |result: &Element| {
+            result
+                .as_object()
+                .and_then(|o| o.get("info"))
+                .and_then(Element::as_object)
+                .is_some_and(|info| info.meta.properties.keys().any(|k| k.starts_with("validationError_")))
+        };
+        assert!(validated(&pipeline.run_once(&doc).unwrap()));
+
+        let removed = pipeline.remove_pass(2).unwrap();
+        assert_eq!(removed.name(), "Validation");
+        assert_eq!(pipeline.pass_count(), 2);
+        assert!(pipeline.remove_pass(2).is_none());
+
+        let result = pipeline.run_once(&doc).unwrap();
+        assert!(result.as_object().unwrap().has_key("zeroth"));
+        assert!(!validated(&result));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot insert pass 'late' at index 3: pipeline has 1 passes")]
+    fn test_insert_pass_out_of_range_panics() {
+        let mut pipeline = FoldPipeline::new().add_pass(Box::new(SetFieldPass("first")));
+        pipeline.insert_pass(3, Box::new(SetFieldPass("late")));
+    }
+
+    #[test]
+    fn test_snapshots_revert_one_pass_at_a_time() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3" }"#);
+        let pipeline = FoldPipeline::new()
+            .add_pass(Box::new(SetFieldPass("first")))
+            .add_pass(Box::new(SetFieldPass("second")))
+            .with_snapshots(true);
+
+        let result = pipeline.run_once(&doc).unwrap();
+        assert!(result.as_object().unwrap().has_key("second"));
+        assert_eq!(pipeline.snapshots().len(), 2);
+
+        let snapshot = pipeline.undo().unwrap();
+        assert_eq!(snapshot.pass_name, "second");
+        assert_eq!(
+            snapshot.before.to_value(),
+            serde_json::json!({ "openapi": "3.0.3", "first": true })
+        );
+
+        let snapshot = pipeline.undo().unwrap();
+        assert_eq!(snapshot.before.to_value(), doc.to_value());
+        assert!(pipeline.undo().is_none());
+
+        let without = FoldPipeline::new().add_pass(Box::new(SetFieldPass("first")));
+        without.run_once(&doc);
+        assert!(without.snapshots().is_empty());
+    }
+
+    #[test]
+    fn test_run_once_skips_snapshots_for_unchanged_documents() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3" }"#);
+        let pipeline = FoldPipeline::new()
+            .add_pass(Box::new(ConditionalPass::new(Box::new(SetFieldPass("skipped")), |_| false)))
+            .add_pass(Box::new(SetFieldPass("first")))
+            .with_snapshots(true);
+
+        pipeline.run_once(&doc).unwrap();
+        let snapshots = pipeline.snapshots();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].pass_name, "first");
+    }
+
+    #[test]
+    fn test_profile_records_each_pass() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3", "info": { "title": "Pets" }, "paths": {} }"#);
+        let pipeline = FoldPipeline::new()
+            .add_pass(Box::new(SetFieldPass("first")))
+            .add_pass(Box::new(ValidationPass::new(false)))
+            .with_profiling(true);
+
+        pipeline.run_until_fixed(&doc).unwrap();
+        let profile = pipeline.profile();
+        assert_eq!(profile.passes().len(), 2);
+
+        let first = profile.get("first").unwrap();
+        assert_eq!(first.changes, 1);
+        assert!(first.invocations >= 2);
+
+        let validation = profile.get("Validation").unwrap();
+        assert_eq!(validation.invocations, first.invocations);
+        assert!(validation.total_duration > Duration::ZERO);
+        assert!(profile.total_duration() >= validation.total_duration);
+
+        let unprofiled = FoldPipeline::new().add_pass(Box::new(SetFieldPass("first")));
+        unprofiled.run_once(&doc);
+        assert!(unprofiled.profile().passes().is_empty());
+    }
+
+    #[test]
+    fn test_conditional_pass_requires_openapi_root() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let is_openapi = |element: &Element| element.as_object().is_some_and(|o| o.has_key("openapi"));
+        let pipeline = FoldPipeline::new().add_pass(Box::new(ConditionalPass::new(
+            Box::new(SemanticEnhancementPass::new()),
+            is_openapi,
+        )));
+        assert_eq!(pipeline.passes[0].name(), "SemanticEnhancement");
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3", "info": { "title": "Pets", "version": "1.0" } }"#);
+        let enhanced = pipeline.run_until_fixed(&doc).unwrap();
+        assert!(!enhanced.as_object().unwrap().classes.content.is_empty());
+
+        let other = json_source_to_ast(r#"{ "swagger": "2.0", "info": { "title": "Pets", "version": "1.0" } }"#);
+        let pass = ConditionalPass::new(Box::new(SemanticEnhancementPass::new()), is_openapi);
+        let skipped = pass.apply(&other).unwrap();
+        assert!(!pass.should_continue(&other, &skipped));
+        let untouched = pipeline.run_until_fixed(&other).unwrap();
+        assert!(untouched.as_object().unwrap().classes.content.is_empty());
+    }
+
+    struct UnnamedPass;
+
+    impl FoldPass for UnnamedPass {
+        fn apply(&self, element: &Element) -> Option<Element> {
+            Some(element.clone())
+        }
+    }
+
+    #[test]
+    fn test_last_profile_has_one_entry_per_pass() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(r#"{ "openapi": "3.0.3", "info": { "title": "Pets" }, "paths": {} }"#);
+        let pipeline = FoldPipeline::new()
+            .add_pass(Box::new(UnnamedPass))
+            .add_pass(Box::new(ValidationPass::new(false)))
+            .with_profiling(true);
+        assert!(pipeline.last_profile().is_empty());
+
+        pipeline.run_once(&doc).unwrap();
+        let profile = pipeline.last_profile();
+        let names: Vec<&str> = profile.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["UnnamedPass", "Validation"]);
+        assert!(profile.iter().all(|(_, duration)| *duration > Duration::ZERO));
+        let durations: Vec<Duration> = pipeline.profile().passes().iter().map(|p| p.total_duration).collect();
+        assert_eq!(profile.iter().map(|(_, duration)| *duration).collect::<Vec<_>>(), durations);
+    }
+
+    #[test]
+    fn test_semantic_enhancement_skips_enhanced_nodes() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let paths: Vec<String> = (0..100)
+            .map(|i| {
+                format!(
+                    r#""/items/{i}": {{ "get": {{ "responses": {{ "200": {{ "description": "ok",
+                        "content": {{ "application/json": {{ "schema": {{ "type": "object",
+                        "properties": {{ "id": {{ "type": "integer" }} }} }} }} }} }} }} }} }}"#
+                )
+            })
+            .collect();
+        let doc = json_source_to_ast(&format!(
+            r#"{{ "openapi": "3.0.3", "info": {{ "title": "Large", "version": "1.0" }}, "paths": {{ {} }} }}"#,
+            paths.join(",")
+        ));
+
+        let pass = SemanticEnhancementPass::new();
+        let enhanced = pass.apply(&doc).expect("first run enhances the document");
+        let first = pass.last_touched();
+        assert!(first > 200);
+
+        assert!(pass.apply(&enhanced).is_none());
+        assert_eq!(pass.last_touched(), 0);
+
+        let mut edited = enhanced.clone();
+        if let Element::Object(root) = &mut edited {
+            root.set("x-extra", json_source_to_ast(r#"{ "type": "string" }"#));
+        }
+        let reenhanced = pass.apply(&edited).unwrap();
+        assert_eq!(pass.last_touched(), 1);
+
+        let schema = reenhanced
+            .as_object()
+            .and_then(|o| o.get("x-extra"))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert_eq!(schema.classes.content.len(), 2);
+        let root = reenhanced.as_object().unwrap();
+        assert_eq!(root.classes.content.len(), 2);
+    }
+
+    #[test]
+    fn test_semantic_enhancement_records_applied_classes() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "info": { "title": "Petstore", "version": "1.0.0" },
+                "paths": {},
+                "components": { "schemas": { "Pet": { "type": "object" } } }
+            }"#,
+        );
+
+        let pass = SemanticEnhancementPass::new();
+        assert!(pass.applied_classes().is_empty());
+        let enhanced = pass.apply(&doc).unwrap();
+
+        let applied = pass.applied_classes();
+        assert!(applied.contains(&("info".to_string(), "openapi-info".to_string())));
+        assert!(applied.iter().any(|(element_type, _)| element_type == "schema"));
+
+        assert!(pass.apply(&enhanced).is_none());
+        assert!(pass.applied_classes().is_empty());
+    }
+
+    #[test]
+    fn test_semantic_enhancement_does_not_duplicate_classes() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        fn all_classes(element: &Element) -> Vec<Vec<String>> {
+            let mut element = element.clone();
+            let mut classes = Vec::new();
+            walk_objects_mut(&mut element, &mut |_, obj| {
+                classes.push(
+                    obj.classes
+                        .content
+                        .iter()
+                        .filter_map(Element::as_string)
+                        .map(|c| c.content.clone())
+                        .collect(),
+                );
+            });
+            classes
+        }
+
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.3", "info": { "title": "Pets", "version": "1.0" },
+                "components": { "schemas": { "Pet": { "type": "object" } } } }"#,
+        );
+        let pass = SemanticEnhancementPass::new();
+        let first = pass.apply(&doc).unwrap();
+
+        // Without the markers every object is visited again
+        let mut unmarked = first.clone();
+        walk_objects_mut(&mut unmarked, &mut |_, obj| {
+            obj.meta.properties.remove(SEMANTIC_ENHANCED_KEY);
+        });
+        let second = pass.apply(&unmarked).unwrap();
+        assert!(pass.applied_classes().is_empty());
+        assert_eq!(all_classes(&second), all_classes(&first));
+    }
+
+    #[test]
+    fn test_reference_resolution_marks_mutual_references() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(
+            r##"{
+                "openapi": "3.0.3",
+                "paths": { "/a": { "get": { "responses": { "200": { "description": "ok",
+                    "content": { "application/json": { "schema": { "$ref": "#/components/schemas/A" } } } } } } } },
+                "components": { "schemas": {
+                    "A": { "type": "object", "properties": { "b_ref": { "$ref": "#/components/schemas/B" } } },
+                    "B": { "type": "object", "properties": { "a_ref": { "$ref": "#/components/schemas/A" } } }
+                } }
+            }"##,
+        );
+
+        let pass = ReferenceResolutionPass::new();
+        let resolved = pass.apply(&doc).expect("cycle should be marked");
+
+        let circular = |pointer: &str| {
+            element_at(&resolved, &pointer_segments(pointer))
+                .and_then(Element::as_object)
+                .is_some_and(|obj| obj.meta.properties.contains_key(CIRCULAR_REFERENCE_KEY))
+        };
+        assert!(circular("/components/schemas/A/properties/b_ref"));
+        assert!(circular("/components/schemas/B/properties/a_ref"));
+        assert!(!circular("/paths/~1a/get/responses/200/content/application~1json/schema"));
+
+        // Everything was handled in one application
+        assert!(pass.apply(&resolved).is_none());
+    }
+
+    #[test]
+    fn test_reference_resolution_reports_unresolved_references() {
+        use apidom_ast::fold::json_source_to_ast;
+
+        let doc = json_source_to_ast(
+            r##"{
+                "openapi": "3.0.3",
+                "paths": { "/test": { "get": { "responses": {
+                    "200": { "$ref": "#/components/responses/NonExistent" },
+                    "404": { "$ref": "#/components/responses/NotFound" }
+                } } } },
+                "components": { "responses": { "NotFound": { "description": "not found" } } }
+            }"##,
+        );
+
+        let pass = ReferenceResolutionPass::new();
+        let resolved = pass.apply(&doc).expect("broken reference should be marked");
+        assert_eq!(pass.unresolved_references(), vec!["#/components/responses/NonExistent"]);
+
+        let responses = pointer_segments("/paths/~1test/get/responses");
+        let flagged = |status: &str| {
+            let mut path = responses.clone();
+            path.push(status.to_string());
+            element_at(&resolved, &path)
+                .and_then(Element::as_object)
+                .is_some_and(|obj| obj.meta.properties.contains_key(RESOLUTION_FAILED_KEY))
+        };
+        assert!(flagged("200"));
+        assert!(!flagged("404"));
+
+        assert!(pass.apply(&resolved).is_none());
+        assert_eq!(pass.unresolved_references().len(), 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+//! Building standalone fragments of an OpenAPI 3.0 document.
+//!
+//! A fragment is a single object (for example a schema or an operation)
+//! without the surrounding document. Its root is tagged with the requested
+//! element type and folded on its own. References that cannot be resolved
+//! inside the fragment are flagged with `unresolvable-reference` metadata and
+//! listed in [`Fragment::unresolved_references`] instead of failing the build.
+
+use apidom_ast::fold::{json_source_to_ast, Fold};
+use apidom_ast::minim_model::Element;
+use serde_json::Value;
+
+use crate::element_type::OpenApiElementType;
+use crate::fold::OpenApiBuilderFolder;
+use crate::passes::support::{element_at, pointer_segments, walk_objects_mut};
+
+/// Metadata key set on `$ref` objects that point outside the fragment
+pub const UNRESOLVABLE_REFERENCE_KEY: &str = "unresolvable-reference";
+
+/// Errors raised while building a fragment
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FragmentError {
+    #[error("Fragment root must be an object, found {0}")]
+    NotAnObject(String),
+}
+
+/// A built fragment
+#[derive(Debug, Clone)]
+pub struct Fragment {
+    /// Folded fragment root
+    pub element: Element,
+    /// `$ref` values that do not resolve inside the fragment, in document order
+    pub unresolved_references: Vec<String>,
+}
+
+/// Build a schema fragment from JSON source
+pub fn build_schema_fragment(source: &str) -> Result<Fragment, FragmentError> {
+    build_fragment(source, OpenApiElementType::Schema)
+}
+
+/// Build an operation fragment from JSON source
+pub fn build_operation_fragment(source: &str) -> Result<Fragment, FragmentError> {
+    build_fragment(source, OpenApiElementType::Operation)
+}
+
+/// Build a fragment whose root is an element of `element_type`
+pub fn build_fragment(source: &str, element_type: OpenApiElementType) -> Result<Fragment, FragmentError> {
+    let mut root = match json_source_to_ast(source) {
+        Element::Object(obj) => obj,
+        other => return Err(FragmentError::NotAnObject(element_kind(&other).to_string())),
+    };
+    root.set_element_type(element_type.as_str());
+
+    let mut element = OpenApiBuilderFolder::new().fold_element(Element::Object(root));
+
+    let snapshot = element.clone();
+    let mut unresolved_references = Vec::new();
+    walk_objects_mut(&mut element, &mut |_, obj| {
+        let Some(reference) = obj
+            .get("$ref")
+            .and_then(Element::as_string)
+            .map(|r| r.content.clone())
+        else {
+            return;
+        };
+        let resolvable = reference.starts_with('#')
+            && element_at(&snapshot, &pointer_segments(&reference)).is_some();
+        if !resolvable {
+            obj.meta
+                .properties
+                .insert(UNRESOLVABLE_REFERENCE_KEY.to_string(), Value::String(reference.clone()));
+            unresolved_references.push(reference);
+        }
+    });
+
+    Ok(Fragment {
+        element,
+        unresolved_references,
+    })
+}
+
+fn element_kind(element: &Element) -> &'static str {
+    match element {
+        Element::Null(_) => "null",
+        Element::Boolean(_) => "boolean",
+        Element::Number(_) => "number",
+        Element::String(_) => "string",
+        Element::Array(_) => "array",
+        Element::Object(_) => "object",
+        Element::Member(_) => "member",
+        Element::Ref(_) => "ref",
+        Element::Link(_) => "link",
+        Element::Custom(..) => "custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_standalone_schema() {
+        let fragment = build_schema_fragment(
+            r##"{
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": { "type": "integer" },
+                    "parent": { "$ref": "#" },
+                    "owner": { "$ref": "#/components/schemas/Owner" }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let root = fragment.element.as_object().unwrap();
+        assert_eq!(root.element, "schema");
+        assert_eq!(fragment.unresolved_references, vec!["#/components/schemas/Owner"]);
+
+        let owner = element_at(&fragment.element, &pointer_segments("#/properties/owner"))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert!(owner.meta.properties.contains_key(UNRESOLVABLE_REFERENCE_KEY));
+    }
+
+    #[test]
+    fn test_build_standalone_operation() {
+        let fragment = build_operation_fragment(
+            r##"{
+                "operationId": "listPets",
+                "parameters": [ { "$ref": "#/components/parameters/Limit" } ],
+                "responses": { "200": { "description": "ok" } }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(fragment.element.as_object().unwrap().element, "operation");
+        assert_eq!(fragment.unresolved_references, vec!["#/components/parameters/Limit"]);
+        assert_eq!(
+            fragment.element.to_value()["operationId"],
+            serde_json::json!("listPets")
+        );
+    }
+
+    #[test]
+    fn test_non_object_fragment_rejected() {
+        assert_eq!(
+            build_schema_fragment("[1, 2]").unwrap_err(),
+            FragmentError::NotAnObject("array".to_string())
+        );
+    }
+}
@@ -6,5 +6,19 @@ pub mod url_builder;
 pub mod specification;
 pub mod fold_pass;
 pub mod patterned_fields;
+pub mod provenance;
 pub mod reference_resolver;
-pub mod extensible_framework;
\ No newline at end of file
+pub mod extensible_framework;
+pub mod fragment;
+pub mod element_classifier;
+pub mod element_type;
+pub mod equivalence;
+pub mod passes;
+pub mod ref_graph;
+pub mod refactor;
+pub mod rules;
+pub mod schema_analysis;
+pub mod security_schemes;
+pub mod serializer;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
\ No newline at end of file
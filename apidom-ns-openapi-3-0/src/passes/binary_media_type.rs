@@ -0,0 +1,182 @@
+/*!
+ * Binary schema media type lint
+ *
+ * A schema with `type: string, format: binary` describes raw file content,
+ * which belongs under `application/octet-stream`, a multipart type or another
+ * binary media type. Declaring it under a textual media type such as
+ * `application/json`, `text/plain` or a form-urlencoded body cannot carry the
+ * bytes as-is, so this pass warns on that combination. Array schemas whose
+ * items are binary and local `$ref`s to binary schemas are checked too.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+use crate::schema_analysis::local_ref_resolver;
+
+/// Warning code reported for a binary schema under a textual media type
+pub const BINARY_MEDIA_TYPE_MISMATCH_CODE: &str = "BINARY_MEDIA_TYPE_MISMATCH";
+
+/// Pass that flags binary-format schemas declared under textual media types
+pub struct BinaryMediaTypePass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl BinaryMediaTypePass {
+    pub fn new() -> Self {
+        Self {
+            name: "BinaryMediaType".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for BinaryMediaTypePass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for BinaryMediaTypePass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let resolver = local_ref_resolver(element);
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, media_type| {
+            let Some(name) = path.last() else {
+                return;
+            };
+            if element_type != "mediaType" || !is_textual_media_type(name) {
+                return;
+            }
+            let Some(schema) = media_type.get("schema") else {
+                return;
+            };
+            if !describes_binary(schema, &resolver) {
+                return;
+            }
+
+            let message = format!(
+                "Schema at '{}' has format 'binary' but media type '{}' is textual; \
+                 use application/octet-stream or multipart/*",
+                json_pointer(path),
+                name
+            );
+            annotate_warning(media_type, "schema", &message);
+            found.push(warning(BINARY_MEDIA_TYPE_MISMATCH_CODE, message, path));
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Whether a media type carries text rather than raw bytes
+fn is_textual_media_type(media_type: &str) -> bool {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence.starts_with("text/")
+        || essence == "application/json"
+        || essence.ends_with("+json")
+        || essence == "application/xml"
+        || essence.ends_with("+xml")
+        || essence == "application/x-www-form-urlencoded"
+}
+
+/// Whether a schema is a binary string, directly, through a local `$ref`
+/// or as the items of an array
+fn describes_binary<R>(schema: &Element, resolver: &R) -> bool
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let Some(obj) = schema.as_object() else {
+        return false;
+    };
+    if let Some(reference) = obj.get("$ref").and_then(Element::as_string) {
+        return resolver(&reference.content)
+            .and_then(|target| target.as_object().map(is_binary_string))
+            .unwrap_or(false);
+    }
+    is_binary_string(obj)
+        || obj
+            .get("items")
+            .and_then(Element::as_object)
+            .is_some_and(is_binary_string)
+}
+
+fn is_binary_string(schema: &ObjectElement) -> bool {
+    let field = |key: &str| {
+        schema
+            .get(key)
+            .and_then(Element::as_string)
+            .map(|s| s.content.as_str())
+    };
+    field("format") == Some("binary") && field("type").is_none_or(|ty| ty == "string")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(media_type: &str) -> Element {
+        json_source_to_ast(&format!(
+            r#"{{
+                "openapi": "3.0.3",
+                "paths": {{ "/files": {{ "post": {{
+                    "requestBody": {{ "content": {{
+                        "{}": {{ "schema": {{ "type": "string", "format": "binary" }} }}
+                    }} }},
+                    "responses": {{ "204": {{ "description": "stored" }} }}
+                }} }} }}
+            }}"#,
+            media_type
+        ))
+    }
+
+    #[test]
+    fn test_binary_schema_under_json_warns() {
+        let pass = BinaryMediaTypePass::new();
+        pass.apply(&document("application/json"))
+            .expect("warning should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, BINARY_MEDIA_TYPE_MISMATCH_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["paths", "/files", "post", "requestBody", "content", "application/json"]
+        );
+    }
+
+    #[test]
+    fn test_binary_schema_under_octet_stream_is_valid() {
+        let pass = BinaryMediaTypePass::new();
+        assert!(pass.apply(&document("application/octet-stream")).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+/*!
+ * Co-requirement validation pass
+ *
+ * Some OpenAPI fields are only meaningful together: an apiKey security
+ * scheme needs both `name` and `in`, an authorization code flow needs both
+ * `authorizationUrl` and `tokenUrl`. This pass is driven by a table of
+ * `(element type, field group)` rules; when any field of a group is present
+ * on an element, every other field of the group must be present too. A rule
+ * can be limited to elements whose discriminating field has a given value,
+ * such as security schemes of `type: apiKey`.
+ *
+ * Findings are collected as `ValidationWarning`s and recorded on the
+ * offending object as `validationWarning_<missing field>` metadata.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for a broken field group
+pub const CO_REQUIREMENT_CODE: &str = "CO_REQUIREMENT";
+
+/// A group of fields that must appear together on one element type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoRequirementRule {
+    /// Element type the rule applies to (e.g. `securityScheme`)
+    pub element_type: String,
+    /// Only apply the rule to elements stored under this member key
+    pub key: Option<String>,
+    /// Fields that must be present together
+    pub fields: Vec<String>,
+    /// Only apply the rule when this `(field, value)` pair is present
+    pub condition: Option<(String, String)>,
+}
+
+impl CoRequirementRule {
+    pub fn new(element_type: &str, fields: &[&str]) -> Self {
+        Self {
+            element_type: element_type.to_string(),
+            key: None,
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            condition: None,
+        }
+    }
+
+    /// Only apply the rule to elements whose `field` is the string `value`
+    /// (e.g. security schemes with `type: apiKey`)
+    pub fn when(mut self, field: &str, value: &str) -> Self {
+        self.condition = Some((field.to_string(), value.to_string()));
+        self
+    }
+
+    /// Restrict the rule to elements stored under `key`
+    /// (e.g. the `authorizationCode` entry of an OAuth flows object)
+    pub fn at_key(mut self, key: &str) -> Self {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    fn applies_to(&self, element_type: &str, path: &[String], obj: &ObjectElement) -> bool {
+        self.element_type == element_type
+            && self
+                .key
+                .as_ref()
+                .is_none_or(|key| path.last() == Some(key))
+            && self.condition.as_ref().is_none_or(|(field, value)| {
+                obj.get(field)
+                    .and_then(Element::as_string)
+                    .is_some_and(|actual| actual.content == *value)
+            })
+    }
+}
+
+/// Default co-requirement table for OpenAPI 3.0
+pub fn default_co_requirement_rules() -> Vec<CoRequirementRule> {
+    vec![
+        CoRequirementRule::new("securityScheme", &["name", "in"]).when("type", "apiKey"),
+        CoRequirementRule::new("oAuthFlow", &["authorizationUrl", "tokenUrl"])
+            .at_key("authorizationCode"),
+        CoRequirementRule::new("parameter", &["name", "in"]),
+    ]
+}
+
+/// Pass that reports fields present without their required companions
+pub struct CoRequirementPass {
+    name: String,
+    rules: Vec<CoRequirementRule>,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl CoRequirementPass {
+    pub fn new() -> Self {
+        Self::with_rules(default_co_requirement_rules())
+    }
+
+    pub fn with_rules(rules: Vec<CoRequirementRule>) -> Self {
+        Self {
+            name: "CoRequirement".to_string(),
+            rules,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a rule to the table
+    pub fn add_rule(mut self, rule: CoRequirementRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[CoRequirementRule] {
+        &self.rules
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for CoRequirementPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for CoRequirementPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, obj| {
+            let rules: Vec<&CoRequirementRule> = self
+                .rules
+                .iter()
+                .filter(|r| r.applies_to(element_type, path, obj))
+                .collect();
+            for rule in rules {
+                let (present, missing): (Vec<&String>, Vec<&String>) =
+                    rule.fields.iter().partition(|f| obj.has_key(f));
+                if present.is_empty() || missing.is_empty() {
+                    continue;
+                }
+
+                for missing in missing {
+                    let message = format!(
+                        "{} at '{}' has '{}' but is missing '{}'",
+                        element_type,
+                        json_pointer(path),
+                        present[0],
+                        missing
+                    );
+                    annotate_warning(obj, missing, &message);
+                    found.push(warning(CO_REQUIREMENT_CODE, message, path));
+                }
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(security_scheme: &str) -> Element {
+        json_source_to_ast(&format!(
+            r#"{{
+                "openapi": "3.0.3",
+                "info": {{ "title": "t", "version": "1" }},
+                "paths": {{}},
+                "components": {{ "securitySchemes": {{ "api_key": {} }} }}
+            }}"#,
+            security_scheme
+        ))
+    }
+
+    #[test]
+    fn test_api_key_without_in() {
+        let pass = CoRequirementPass::new();
+        let doc = document(r#"{ "type": "apiKey", "name": "X-API-Key" }"#);
+
+        let result = pass.apply(&doc).expect("pass should annotate the document");
+        let warnings = pass.warnings();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, CO_REQUIREMENT_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["components", "securitySchemes", "api_key"]
+        );
+        assert!(warnings[0].message.contains("'in'"));
+
+        let scheme = result
+            .as_object()
+            .and_then(|o| o.get("components"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("securitySchemes"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("api_key"))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert!(scheme.meta.properties.contains_key("validationWarning_in"));
+    }
+
+    #[test]
+    fn test_complete_group_passes() {
+        let pass = CoRequirementPass::new();
+        let doc = document(r#"{ "type": "apiKey", "name": "X-API-Key", "in": "header" }"#);
+
+        assert!(pass.apply(&doc).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_security_scheme_rule_only_applies_to_api_keys() {
+        let pass = CoRequirementPass::new();
+        let doc = document(r#"{ "type": "http", "scheme": "bearer", "name": "token" }"#);
+
+        assert!(pass.apply(&doc).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_keyed_rule_only_applies_to_its_flow() {
+        let pass = CoRequirementPass::new();
+        let doc = document(
+            r#"{ "type": "oauth2", "flows": {
+                "implicit": { "authorizationUrl": "https://a", "scopes": {} },
+                "authorizationCode": { "authorizationUrl": "https://a", "scopes": {} }
+            } }"#,
+        );
+
+        pass.apply(&doc);
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path.last().unwrap(), "authorizationCode");
+    }
+}
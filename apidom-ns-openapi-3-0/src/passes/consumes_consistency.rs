@@ -0,0 +1,171 @@
+/*!
+ * Consumes consistency pass (Swagger 2 interop)
+ *
+ * The request-side counterpart of the produces consistency pass: documents
+ * upgraded from Swagger 2 keep their `consumes` lists (document level and
+ * operation level) next to the migrated `requestBody.content`. This pass
+ * warns when a request body declares a media type that is not covered by the
+ * operation's effective `consumes` list.
+ *
+ * Operations without any effective `consumes` list, and request bodies given
+ * as `$ref`s, are not checked.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::produces_consistency::{media_type_list, media_type_listed};
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for an undeclared request body media type
+pub const CONSUMES_MISMATCH_CODE: &str = "CONSUMES_MISMATCH";
+
+/// Pass that checks request body media types against the effective `consumes`
+pub struct ConsumesConsistencyPass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl ConsumesConsistencyPass {
+    pub fn new() -> Self {
+        Self {
+            name: "ConsumesConsistency".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for ConsumesConsistencyPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for ConsumesConsistencyPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let document_consumes = element
+            .as_object()
+            .and_then(|root| media_type_list(root, "consumes"));
+
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, operation| {
+            if element_type != "operation" {
+                return;
+            }
+            let Some(consumes) =
+                media_type_list(operation, "consumes").or_else(|| document_consumes.clone())
+            else {
+                return;
+            };
+            let Some(content) = operation
+                .get("requestBody")
+                .and_then(Element::as_object)
+                .and_then(|body| body.get("content"))
+                .and_then(Element::as_object)
+            else {
+                return;
+            };
+
+            let undeclared: Vec<String> = content
+                .content
+                .iter()
+                .filter_map(|m| m.key.as_string())
+                .map(|k| k.content.clone())
+                .filter(|media_type| !media_type_listed(media_type, &consumes))
+                .collect();
+
+            for media_type in undeclared {
+                let mut location = path.to_vec();
+                location.extend([
+                    "requestBody".to_string(),
+                    "content".to_string(),
+                    media_type.clone(),
+                ]);
+                let message = format!(
+                    "Request body content type '{}' at '{}' is not listed in the operation's consumes [{}]",
+                    media_type,
+                    json_pointer(&location),
+                    consumes.join(", ")
+                );
+                annotate_warning(operation, &format!("requestBody_{}", media_type), &message);
+                found.push(warning(CONSUMES_MISMATCH_CODE, message, &location));
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const UPGRADED: &str = r#"{
+        "openapi": "3.0.0",
+        "info": { "title": "Upgraded from Swagger 2", "version": "1.0" },
+        "consumes": ["application/json"],
+        "paths": {
+            "/pets": {
+                "post": {
+                    "requestBody": { "content": {
+                        "application/json": {},
+                        "application/x-www-form-urlencoded": {}
+                    } },
+                    "responses": { "201": { "description": "created" } }
+                },
+                "put": {
+                    "consumes": ["application/*"],
+                    "requestBody": { "content": { "application/xml": {} } },
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_undeclared_request_body_content_type() {
+        let pass = ConsumesConsistencyPass::new();
+        pass.apply(&json_source_to_ast(UPGRADED))
+            .expect("mismatch should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, CONSUMES_MISMATCH_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["paths", "/pets", "post", "requestBody", "content", "application/x-www-form-urlencoded"]
+        );
+    }
+
+    #[test]
+    fn test_operation_without_consumes_is_not_checked() {
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.0", "paths": { "/pets": { "post": {
+                "requestBody": { "content": { "application/xml": {} } },
+                "responses": {}
+            } } } }"#,
+        );
+        assert!(ConsumesConsistencyPass::new().apply(&doc).is_none());
+    }
+}
@@ -0,0 +1,235 @@
+/*!
+ * Duplicate schema detection pass
+ *
+ * Groups `components.schemas` definitions that are structurally identical
+ * (same JSON content, ignoring metadata and member order) and reports every
+ * definition after the first of a group as a duplicate of it. In merge mode
+ * the duplicates are removed and references to them are redirected to the
+ * first definition, which records the merged names in `merged-duplicates`
+ * metadata.
+ */
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, element_at_mut, json_pointer, warning};
+use crate::refactor::merge_component;
+
+/// Warning code reported for a schema identical to an earlier one
+pub const DUPLICATE_SCHEMA_CODE: &str = "DUPLICATE_SCHEMA";
+
+/// A set of identical schema definitions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateSchemaGroup {
+    /// First definition in document order
+    pub canonical: String,
+    /// Later definitions identical to `canonical`
+    pub duplicates: Vec<String>,
+}
+
+/// Pass that reports (and optionally merges) identical component schemas
+pub struct DuplicateSchemaPass {
+    name: String,
+    merge: bool,
+    groups: Mutex<Vec<DuplicateSchemaGroup>>,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl DuplicateSchemaPass {
+    pub fn new() -> Self {
+        Self {
+            name: "DuplicateSchema".to_string(),
+            merge: false,
+            groups: Mutex::new(Vec::new()),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Remove duplicates and redirect their references instead of only
+    /// reporting them
+    pub fn with_merge(mut self, merge: bool) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Duplicate groups found by the last run of the pass
+    pub fn groups(&self) -> Vec<DuplicateSchemaGroup> {
+        self.groups.lock().map(|g| g.clone()).unwrap_or_default()
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for DuplicateSchemaPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for DuplicateSchemaPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let groups = find_duplicate_schemas(element);
+        let mut found = Vec::new();
+        let mut result = element.clone();
+
+        for group in &groups {
+            for duplicate in &group.duplicates {
+                let path: Vec<String> = ["components", "schemas", duplicate]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect();
+                let message = format!(
+                    "Schema at '{}' is identical to '{}'",
+                    json_pointer(&path),
+                    group.canonical
+                );
+                if self.merge {
+                    // Both definitions were found above, so merging cannot fail
+                    let _ = merge_component(&mut result, "schemas", duplicate, &group.canonical);
+                } else if let Some(Element::Object(schema)) = element_at_mut(&mut result, &path) {
+                    annotate_warning(schema, "definition", &message);
+                }
+                found.push(warning(DUPLICATE_SCHEMA_CODE, message, &path));
+            }
+
+            if self.merge {
+                let path: Vec<String> = ["components", "schemas", &group.canonical]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect();
+                if let Some(Element::Object(schema)) = element_at_mut(&mut result, &path) {
+                    schema.meta.properties.insert(
+                        "merged-duplicates".to_string(),
+                        Value::Array(group.duplicates.iter().cloned().map(Value::String).collect()),
+                    );
+                }
+            }
+        }
+
+        let changed = !groups.is_empty();
+        if let Ok(mut stored) = self.groups.lock() {
+            *stored = groups;
+        }
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Group the structurally identical schemas of `components.schemas`
+pub fn find_duplicate_schemas(document: &Element) -> Vec<DuplicateSchemaGroup> {
+    let Some(schemas) = document
+        .as_object()
+        .and_then(|root| root.get("components"))
+        .and_then(Element::as_object)
+        .and_then(|components| components.get("schemas"))
+        .and_then(Element::as_object)
+    else {
+        return Vec::new();
+    };
+
+    let mut groups: Vec<DuplicateSchemaGroup> = Vec::new();
+    let mut by_content: HashMap<String, usize> = HashMap::new();
+    for member in &schemas.content {
+        let Some(name) = member.key.as_string().map(|k| k.content.clone()) else {
+            continue;
+        };
+        // Object keys serialize in sorted order, so equal content gives equal keys
+        let content = member.value.to_value().to_string();
+        match by_content.get(&content) {
+            Some(&index) => groups[index].duplicates.push(name),
+            None => {
+                by_content.insert(content, groups.len());
+                groups.push(DuplicateSchemaGroup {
+                    canonical: name,
+                    duplicates: Vec::new(),
+                });
+            }
+        }
+    }
+
+    groups.retain(|group| !group.duplicates.is_empty());
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r##"{
+        "openapi": "3.0.3",
+        "paths": { "/pets": { "get": { "responses": { "200": {
+            "description": "ok",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Animal" } } }
+        } } } } },
+        "components": { "schemas": {
+            "Pet": { "type": "object", "properties": { "name": { "type": "string" } } },
+            "Animal": { "properties": { "name": { "type": "string" } }, "type": "object" },
+            "Owner": { "type": "object", "properties": { "pet": { "$ref": "#/components/schemas/Animal" } } }
+        } }
+    }"##;
+
+    #[test]
+    fn test_identical_schemas_reported() {
+        let pass = DuplicateSchemaPass::new();
+        pass.apply(&json_source_to_ast(DOCUMENT))
+            .expect("duplicate should be annotated");
+
+        assert_eq!(
+            pass.groups(),
+            vec![DuplicateSchemaGroup {
+                canonical: "Pet".to_string(),
+                duplicates: vec!["Animal".to_string()],
+            }]
+        );
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, DUPLICATE_SCHEMA_CODE);
+        assert_eq!(warnings[0].path, vec!["components", "schemas", "Animal"]);
+    }
+
+    #[test]
+    fn test_merge_mode_redirects_references() {
+        let pass = DuplicateSchemaPass::new().with_merge(true);
+        let result = pass.apply(&json_source_to_ast(DOCUMENT)).unwrap();
+
+        let value = result.to_value();
+        let schemas = value["components"]["schemas"].as_object().unwrap();
+        assert!(!schemas.contains_key("Animal"));
+        assert_eq!(
+            schemas["Owner"]["properties"]["pet"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+        let response = &value["paths"]["/pets"]["get"]["responses"]["200"];
+        assert_eq!(
+            response["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+    }
+
+    #[test]
+    fn test_distinct_schemas_untouched() {
+        let doc = json_source_to_ast(
+            r#"{ "components": { "schemas": {
+                "A": { "type": "string" },
+                "B": { "type": "integer" }
+            } } }"#,
+        );
+        assert!(DuplicateSchemaPass::new().apply(&doc).is_none());
+    }
+}
@@ -0,0 +1,181 @@
+/*!
+ * Example / default conformance pass
+ *
+ * A schema's `default` and `example` values are meant to be valid against
+ * the schema itself, yet nothing checks them. For string values this pass
+ * verifies `minLength`, `maxLength` (counted in characters, as JSON Schema
+ * does) and `pattern`, and warns about each violation. A `pattern` that is
+ * not a valid regular expression is reported instead of being applied.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use regex::Regex;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for a `default`/`example` violating its schema
+pub const EXAMPLE_NONCONFORMANT_CODE: &str = "EXAMPLE_NONCONFORMANT";
+
+/// Warning code reported for a `pattern` that does not compile
+pub const INVALID_PATTERN_CODE: &str = "INVALID_PATTERN";
+
+/// Schema fields whose values are checked
+const CHECKED_FIELDS: [&str; 2] = ["default", "example"];
+
+/// Pass that checks schema examples and defaults against the schema
+pub struct ExampleConformancePass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl ExampleConformancePass {
+    pub fn new() -> Self {
+        Self {
+            name: "ExampleConformance".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for ExampleConformancePass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for ExampleConformancePass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, schema| {
+            if element_type != "schema" {
+                return;
+            }
+
+            let pattern = match schema.get("pattern").and_then(Element::as_string) {
+                Some(pattern) => match Regex::new(&pattern.content) {
+                    Ok(regex) => Some(regex),
+                    Err(err) => {
+                        let message = format!(
+                            "Schema at '{}' has an invalid pattern '{}': {}",
+                            json_pointer(path),
+                            pattern.content,
+                            err
+                        );
+                        annotate_warning(schema, "pattern", &message);
+                        found.push(warning(INVALID_PATTERN_CODE, message, path));
+                        None
+                    }
+                },
+                None => None,
+            };
+            let min_length = length_constraint(schema, "minLength");
+            let max_length = length_constraint(schema, "maxLength");
+
+            for field in CHECKED_FIELDS {
+                let Some(value) = schema.get(field).and_then(Element::as_string) else {
+                    continue;
+                };
+                let value = value.content.clone();
+                let length = value.chars().count();
+
+                let mut violations = Vec::new();
+                if let Some(min) = min_length.filter(|min| length < *min) {
+                    violations.push(format!("is shorter than minLength {}", min));
+                }
+                if let Some(max) = max_length.filter(|max| length > *max) {
+                    violations.push(format!("is longer than maxLength {}", max));
+                }
+                if let Some(regex) = pattern.as_ref().filter(|regex| !regex.is_match(&value)) {
+                    violations.push(format!("does not match pattern '{}'", regex.as_str()));
+                }
+
+                for violation in violations {
+                    let message = format!(
+                        "The {} '{}' of schema at '{}' {}",
+                        field,
+                        value,
+                        json_pointer(path),
+                        violation
+                    );
+                    annotate_warning(schema, field, &message);
+                    found.push(warning(EXAMPLE_NONCONFORMANT_CODE, message, path));
+                }
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Non-negative integral value of a length keyword
+fn length_constraint(schema: &ObjectElement, keyword: &str) -> Option<usize> {
+    match schema.get(keyword) {
+        Some(Element::Number(n)) if n.content >= 0.0 && n.content.fract() == 0.0 => {
+            Some(n.content as usize)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn check(schema: &str) -> Vec<ValidationWarning> {
+        let doc = json_source_to_ast(&format!(
+            r#"{{ "openapi": "3.0.3", "components": {{ "schemas": {{ "Code": {} }} }} }}"#,
+            schema
+        ));
+        let pass = ExampleConformancePass::new();
+        pass.apply(&doc);
+        pass.warnings()
+    }
+
+    #[test]
+    fn test_default_shorter_than_min_length() {
+        let warnings = check(r#"{ "type": "string", "minLength": 3, "default": "ab", "example": "abc" }"#);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, EXAMPLE_NONCONFORMANT_CODE);
+        assert_eq!(warnings[0].path, vec!["components", "schemas", "Code"]);
+        assert!(warnings[0].message.contains("minLength 3"));
+    }
+
+    #[test]
+    fn test_default_violating_pattern() {
+        let warnings = check(r#"{ "type": "string", "pattern": "^[A-Z]{2}$", "default": "us" }"#);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("does not match pattern"));
+
+        assert!(check(r#"{ "type": "string", "pattern": "^[A-Z]{2}$", "default": "US" }"#).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pattern_warns_instead_of_panicking() {
+        let warnings = check(r#"{ "type": "string", "pattern": "([a-z", "default": "abc", "maxLength": 2 }"#);
+        let codes: Vec<&str> = warnings.iter().map(|w| w.code.as_str()).collect();
+        assert_eq!(codes, vec![INVALID_PATTERN_CODE, EXAMPLE_NONCONFORMANT_CODE]);
+        assert!(warnings[1].message.contains("maxLength 2"));
+    }
+}
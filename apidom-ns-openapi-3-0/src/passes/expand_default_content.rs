@@ -0,0 +1,149 @@
+/*!
+ * Default content expansion pass (opt-in)
+ *
+ * A common authoring mistake puts `schema` (and `example`) directly on a
+ * request body or response instead of under a `content` map. This pass
+ * moves such fields into `content: { <default media type>: { ... } }`,
+ * using `application/json` unless configured otherwise, and records the
+ * media type in `expanded-content` metadata. Bodies that already have a
+ * `content` map are left untouched.
+ */
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::fold_pass::FoldPass;
+
+/// Media type used when none is configured
+pub const DEFAULT_CONTENT_MEDIA_TYPE: &str = "application/json";
+
+/// Fields that belong on a media type object rather than on a body
+const MISPLACED_FIELDS: [&str; 3] = ["schema", "example", "examples"];
+
+/// Pass that nests misplaced body schemas under a `content` map
+pub struct ExpandDefaultContentPass {
+    name: String,
+    media_type: String,
+}
+
+impl ExpandDefaultContentPass {
+    pub fn new() -> Self {
+        Self::with_media_type(DEFAULT_CONTENT_MEDIA_TYPE)
+    }
+
+    /// Expand into `media_type` instead of `application/json`
+    pub fn with_media_type(media_type: &str) -> Self {
+        Self {
+            name: "ExpandDefaultContent".to_string(),
+            media_type: media_type.to_string(),
+        }
+    }
+
+    pub fn media_type(&self) -> &str {
+        &self.media_type
+    }
+}
+
+impl Default for ExpandDefaultContentPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for ExpandDefaultContentPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut changed = false;
+
+        walk_openapi_objects_mut(&mut result, |element_type, _, body| {
+            if !matches!(element_type, "requestBody" | "response")
+                || body.has_key("content")
+                || body.has_key("$ref")
+                || !body.has_key("schema")
+            {
+                return;
+            }
+
+            let mut media_type = ObjectElement::new();
+            body.content.retain(|member| {
+                let misplaced = matches!(
+                    member.key.as_ref(),
+                    Element::String(k) if MISPLACED_FIELDS.contains(&k.content.as_str())
+                );
+                if misplaced {
+                    media_type.content.push(member.clone());
+                }
+                !misplaced
+            });
+
+            let mut content = ObjectElement::new();
+            content.set(&self.media_type, Element::Object(media_type));
+            body.set("content", Element::Object(content));
+            body.meta.properties.insert(
+                "expanded-content".to_string(),
+                Value::String(self.media_type.clone()),
+            );
+            changed = true;
+        });
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::passes::support::element_at;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_misnested_schema_expanded() {
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.3", "paths": { "/pets": { "get": { "responses": {
+                "200": { "description": "ok", "schema": { "type": "array" } }
+            } } } } }"#,
+        );
+
+        let result = ExpandDefaultContentPass::new()
+            .apply(&doc)
+            .expect("schema should be moved under content");
+
+        let response = result.as_object().unwrap().to_value();
+        let response = &response["paths"]["/pets"]["get"]["responses"]["200"];
+        assert_eq!(
+            response["content"]["application/json"]["schema"]["type"],
+            "array"
+        );
+        assert!(response.get("schema").is_none());
+        assert_eq!(response["description"], "ok");
+
+        let path: Vec<String> = ["paths", "/pets", "get", "responses", "200"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let tagged = element_at(&result, &path).and_then(Element::as_object).unwrap();
+        assert_eq!(
+            tagged.meta.properties.get("expanded-content"),
+            Some(&Value::String("application/json".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_correct_document_untouched() {
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.3", "paths": { "/pets": { "post": {
+                "requestBody": { "content": { "application/xml": { "schema": { "type": "object" } } } },
+                "responses": { "204": { "description": "done" } }
+            } } } }"#,
+        );
+
+        assert!(ExpandDefaultContentPass::with_media_type("text/plain")
+            .apply(&doc)
+            .is_none());
+    }
+}
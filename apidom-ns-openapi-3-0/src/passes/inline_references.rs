@@ -0,0 +1,187 @@
+/*!
+ * Local component reference inlining
+ *
+ * Replaces `$ref`s to local components (`#/components/...`) with a copy of
+ * the referenced component, which is what bundlers do before handing a
+ * document to tools that cannot follow references. The replacement keeps the
+ * reference's `source-pointer`, if any, so provenance survives inlining.
+ *
+ * Components that take part in a reference cycle are never inlined. A
+ * component referenced from more places than the configured fan-out
+ * threshold is left as a `$ref` as well, since copying it into every usage
+ * would blow up the document; such references get `high-fanout` metadata
+ * holding the component's usage count.
+ */
+
+use std::collections::BTreeMap;
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{element_at, pointer_segments, walk_objects_mut};
+use crate::provenance::carry_source_pointer;
+use crate::ref_graph::RefGraph;
+
+/// Metadata key set on references left in place because of their fan-out
+pub const HIGH_FANOUT_KEY: &str = "high-fanout";
+
+/// Pass that inlines references to local components
+pub struct InlineReferencesPass {
+    name: String,
+    fanout_threshold: Option<usize>,
+}
+
+impl InlineReferencesPass {
+    /// Create a pass that inlines every non-recursive component
+    pub fn new() -> Self {
+        Self {
+            name: "InlineReferences".to_string(),
+            fanout_threshold: None,
+        }
+    }
+
+    /// Leave components referenced more than `threshold` times as `$ref`s
+    pub fn with_fanout_threshold(mut self, threshold: usize) -> Self {
+        self.fanout_threshold = Some(threshold);
+        self
+    }
+
+    pub fn fanout_threshold(&self) -> Option<usize> {
+        self.fanout_threshold
+    }
+}
+
+impl Default for InlineReferencesPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What to do with the references to one component
+enum Plan {
+    Inline(Box<ObjectElement>),
+    KeepHighFanout(usize),
+}
+
+impl FoldPass for InlineReferencesPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let graph = RefGraph::from_element(element);
+        let mut plans = BTreeMap::new();
+        for (pointer, fan_out) in graph.fan_out() {
+            if fan_out == 0 || graph.reachable_from(&pointer).contains(&pointer) {
+                continue;
+            }
+            let plan = match self.fanout_threshold {
+                Some(threshold) if fan_out > threshold => Plan::KeepHighFanout(fan_out),
+                _ => match element_at(element, &pointer_segments(&pointer)) {
+                    Some(Element::Object(target)) => Plan::Inline(Box::new(target.clone())),
+                    _ => continue,
+                },
+            };
+            plans.insert(pointer, plan);
+        }
+        if plans.is_empty() {
+            return None;
+        }
+
+        let mut result = element.clone();
+        let mut changed = false;
+        walk_objects_mut(&mut result, &mut |_, obj| {
+            let Some(reference) = obj.get("$ref").and_then(Element::as_string) else {
+                return;
+            };
+            match plans.get(&reference.content) {
+                Some(Plan::Inline(target)) => {
+                    let mut inlined = target.as_ref().clone();
+                    carry_source_pointer(obj, &mut inlined);
+                    *obj = inlined;
+                    changed = true;
+                }
+                Some(Plan::KeepHighFanout(count))
+                    if !obj.meta.properties.contains_key(HIGH_FANOUT_KEY) =>
+                {
+                    obj.meta
+                        .properties
+                        .insert(HIGH_FANOUT_KEY.to_string(), Value::from(*count));
+                    changed = true;
+                }
+                _ => {}
+            }
+        });
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r##"{
+        "openapi": "3.0.3",
+        "paths": { "/pets": {
+            "get": { "responses": {
+                "200": { "description": "ok", "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } } },
+                "default": { "$ref": "#/components/responses/Error" }
+            } },
+            "post": { "responses": { "default": { "$ref": "#/components/responses/Error" } } },
+            "put": { "responses": { "default": { "$ref": "#/components/responses/Error" } } }
+        } },
+        "components": {
+            "schemas": { "Pet": { "type": "object" } },
+            "responses": { "Error": { "description": "unexpected error" } }
+        }
+    }"##;
+
+    fn at<'a>(doc: &'a Element, pointer: &str) -> &'a ObjectElement {
+        element_at(doc, &pointer_segments(pointer))
+            .and_then(Element::as_object)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_references_are_inlined() {
+        let result = InlineReferencesPass::new()
+            .apply(&json_source_to_ast(DOCUMENT))
+            .expect("references should be inlined");
+
+        let schema = at(&result, "/paths/~1pets/get/responses/200/content/application~1json/schema");
+        assert!(!schema.has_key("$ref"));
+        assert!(schema.has_key("type"));
+        assert!(!at(&result, "/paths/~1pets/put/responses/default").has_key("$ref"));
+    }
+
+    #[test]
+    fn test_high_fanout_component_left_as_reference() {
+        let pass = InlineReferencesPass::new().with_fanout_threshold(2);
+        let result = pass
+            .apply(&json_source_to_ast(DOCUMENT))
+            .expect("references should be processed");
+
+        for method in ["get", "post", "put"] {
+            let response = at(&result, &format!("/paths/~1pets/{}/responses/default", method));
+            assert!(response.has_key("$ref"));
+            assert_eq!(response.meta.properties.get(HIGH_FANOUT_KEY), Some(&Value::from(3)));
+        }
+        let schema = at(&result, "/paths/~1pets/get/responses/200/content/application~1json/schema");
+        assert!(!schema.has_key("$ref"));
+
+        assert!(pass.apply(&result).is_none());
+    }
+
+    #[test]
+    fn test_recursive_component_not_inlined() {
+        let doc = json_source_to_ast(
+            r##"{ "components": { "schemas": {
+                "Node": { "type": "object", "properties": { "next": { "$ref": "#/components/schemas/Node" } } }
+            } } }"##,
+        );
+        assert!(InlineReferencesPass::new().apply(&doc).is_none());
+    }
+}
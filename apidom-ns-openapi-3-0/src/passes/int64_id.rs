@@ -0,0 +1,220 @@
+/*!
+ * Integer identifier format advisory
+ *
+ * An `integer` schema without `format` is generated as a 32-bit integer by
+ * most code generators, which silently truncates large database ids. This
+ * opt-in heuristic reports integer properties whose name looks like an
+ * identifier (`id` or `*Id` by default) and that declare no `format`,
+ * suggesting `int64`. The name patterns are configurable; a pattern may
+ * start and/or end with `*` to match any prefix or suffix.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for identifier properties lacking `format: int64`
+pub const INT64_ID_CODE: &str = "INT64_ID_FORMAT";
+
+/// Property name patterns checked by default
+pub const DEFAULT_ID_PATTERNS: [&str; 2] = ["id", "*Id"];
+
+/// Pass that flags integer id properties without a `format`
+pub struct Int64IdPass {
+    name: String,
+    patterns: Vec<String>,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl Int64IdPass {
+    pub fn new() -> Self {
+        Self {
+            name: "Int64Id".to_string(),
+            patterns: DEFAULT_ID_PATTERNS.iter().map(|p| p.to_string()).collect(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Replace the property name patterns treated as identifiers
+    pub fn with_name_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.patterns = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn name_patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+
+    fn is_id_name(&self, name: &str) -> bool {
+        self.patterns.iter().any(|pattern| matches_pattern(pattern, name))
+    }
+}
+
+impl Default for Int64IdPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for Int64IdPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, schema| {
+            if element_type != "schema" {
+                return;
+            }
+            let Some(properties) = schema.get("properties").and_then(Element::as_object) else {
+                return;
+            };
+
+            let flagged: Vec<String> = properties
+                .content
+                .iter()
+                .filter_map(|member| {
+                    let name = member.key.as_string()?;
+                    let property = member.value.as_object()?;
+                    (self.is_id_name(&name.content) && is_unformatted_integer(property))
+                        .then(|| name.content.clone())
+                })
+                .collect();
+
+            for property in flagged {
+                let message = format!(
+                    "Integer property '{}' of schema at '{}' has no format; consider 'int64'",
+                    property,
+                    json_pointer(path)
+                );
+                annotate_warning(schema, &format!("properties_{}", property), &message);
+                let mut property_path = path.to_vec();
+                property_path.extend(["properties".to_string(), property]);
+                found.push(warning(INT64_ID_CODE, message, &property_path));
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Whether `schema` is an integer schema without a `format`
+fn is_unformatted_integer(schema: &ObjectElement) -> bool {
+    let integer = match schema.get("type") {
+        Some(Element::String(t)) => t.content == "integer",
+        Some(Element::Array(types)) => types
+            .content
+            .iter()
+            .filter_map(Element::as_string)
+            .any(|t| t.content == "integer"),
+        _ => false,
+    };
+    integer && !schema.has_key("format")
+}
+
+/// Match `name` against a pattern with an optional leading and/or trailing `*`
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match (pattern.strip_prefix('*'), pattern.strip_suffix('*')) {
+        (Some(rest), _) if rest.ends_with('*') => name.contains(&rest[..rest.len() - 1]),
+        (Some(suffix), _) => name.ends_with(suffix),
+        (None, Some(prefix)) => name.starts_with(prefix),
+        (None, None) => name == pattern,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(properties: &str) -> Element {
+        json_source_to_ast(&format!(
+            r#"{{ "openapi": "3.0.3", "components": {{ "schemas": {{
+                "User": {{ "type": "object", "properties": {} }}
+            }} }} }}"#,
+            properties
+        ))
+    }
+
+    #[test]
+    fn test_unformatted_integer_id_warns() {
+        let doc = document(
+            r#"{ "id": { "type": "integer" }, "ownerId": { "type": "integer", "format": "int64" }, "age": { "type": "integer" } }"#,
+        );
+
+        let pass = Int64IdPass::new();
+        pass.apply(&doc).expect("advisory should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, INT64_ID_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["components", "schemas", "User", "properties", "id"]
+        );
+        assert!(warnings[0].message.contains("int64"));
+    }
+
+    #[test]
+    fn test_each_flagged_property_keeps_its_annotation() {
+        let doc = document(r#"{ "id": { "type": "integer" }, "ownerId": { "type": "integer" } }"#);
+
+        let result = Int64IdPass::new().apply(&doc).unwrap();
+        let schema = result
+            .as_object()
+            .and_then(|root| root.get("components"))
+            .and_then(Element::as_object)
+            .and_then(|components| components.get("schemas"))
+            .and_then(Element::as_object)
+            .and_then(|schemas| schemas.get("User"))
+            .and_then(Element::as_object)
+            .unwrap();
+        for property in ["id", "ownerId"] {
+            let key = format!("validationWarning_properties_{}", property);
+            assert!(
+                matches!(schema.meta.properties.get(&key), Some(serde_json::Value::String(m)) if m.contains(property)),
+                "missing annotation {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn test_int64_id_passes() {
+        let doc = document(r#"{ "id": { "type": "integer", "format": "int64" } }"#);
+        assert!(Int64IdPass::new().apply(&doc).is_none());
+    }
+
+    #[test]
+    fn test_custom_name_patterns() {
+        let doc = document(r#"{ "id": { "type": "integer" }, "user_key": { "type": "integer" } }"#);
+
+        let pass = Int64IdPass::new().with_name_patterns(["*_key"]);
+        pass.apply(&doc).unwrap();
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path.last().map(String::as_str), Some("user_key"));
+    }
+}
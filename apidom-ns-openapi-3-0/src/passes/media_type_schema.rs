@@ -0,0 +1,111 @@
+/*!
+ * Media type schema lint
+ *
+ * A `content` entry with neither `schema` nor `example`/`examples` is
+ * allowed by the specification (it means "any payload") but is usually an
+ * incomplete definition. This pass reports such entries as warnings.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for a media type without schema or example
+pub const MEDIA_TYPE_WITHOUT_SCHEMA_CODE: &str = "MEDIA_TYPE_WITHOUT_SCHEMA";
+
+/// Pass that flags media types lacking both a schema and examples
+pub struct MediaTypeSchemaPass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl MediaTypeSchemaPass {
+    pub fn new() -> Self {
+        Self {
+            name: "MediaTypeSchema".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for MediaTypeSchemaPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for MediaTypeSchemaPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, media_type| {
+            if element_type != "mediaType"
+                || ["schema", "example", "examples"]
+                    .iter()
+                    .any(|field| media_type.has_key(field))
+            {
+                return;
+            }
+
+            let message = format!(
+                "Media type at '{}' declares neither a schema nor examples",
+                json_pointer(path)
+            );
+            annotate_warning(media_type, "schema", &message);
+            found.push(warning(MEDIA_TYPE_WITHOUT_SCHEMA_CODE, message, path));
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_empty_media_type_warns() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "paths": { "/pets": { "post": {
+                    "requestBody": { "content": { "application/json": { "schema": { "type": "object" } } } },
+                    "responses": { "200": { "description": "ok", "content": { "text/plain": {} } } }
+                } } }
+            }"#,
+        );
+
+        let pass = MediaTypeSchemaPass::new();
+        pass.apply(&doc).expect("warning should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, MEDIA_TYPE_WITHOUT_SCHEMA_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["paths", "/pets", "post", "responses", "200", "content", "text/plain"]
+        );
+        assert!(warnings[0].message.contains("/content/text~1plain"));
+    }
+}
@@ -0,0 +1,43 @@
+pub mod binary_media_type;
+pub mod co_requirement;
+pub mod consumes_consistency;
+pub mod duplicate_schema;
+pub mod example_conformance;
+pub mod expand_default_content;
+pub mod inline_references;
+pub mod int64_id;
+pub mod media_type_schema;
+pub mod nested_path_items;
+pub mod normalize_ref;
+pub mod nullable_enum;
+pub mod one_of_ambiguity;
+pub mod produces_consistency;
+pub mod ref_override_drift;
+pub mod ref_target_policy;
+pub mod relative_resolution;
+pub mod required_nullable;
+pub mod table_of_contents;
+pub mod trailing_slash;
+
+pub(crate) mod support;
+
+pub use binary_media_type::*;
+pub use co_requirement::*;
+pub use consumes_consistency::*;
+pub use duplicate_schema::*;
+pub use example_conformance::*;
+pub use expand_default_content::*;
+pub use inline_references::*;
+pub use int64_id::*;
+pub use media_type_schema::*;
+pub use nested_path_items::*;
+pub use normalize_ref::*;
+pub use nullable_enum::*;
+pub use one_of_ambiguity::*;
+pub use produces_consistency::*;
+pub use ref_override_drift::*;
+pub use ref_target_policy::*;
+pub use relative_resolution::*;
+pub use required_nullable::*;
+pub use table_of_contents::*;
+pub use trailing_slash::*;
@@ -0,0 +1,151 @@
+/*!
+ * Webhook and callback path item validation
+ *
+ * Path items under `webhooks` (OpenAPI 3.1) and under operation or component
+ * `callbacks` hold operations just like `paths`, but are easy to overlook
+ * when reviewing a document. This pass runs the operation and parameter
+ * rules of a [`RuleSet`] and reports the findings located inside those path
+ * items as warnings, using the rule id as the warning code.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, element_at_mut, json_pointer, warning};
+use crate::rules::{
+    RuleSet, RULE_OPERATION_EMPTY_RESPONSES, RULE_OPERATION_ID_UNIQUE, RULE_OPERATION_RESPONSES,
+    RULE_PATH_PARAMETER_REQUIRED,
+};
+
+/// Rules applied to webhook and callback path items
+pub const NESTED_PATH_ITEM_RULES: [&str; 4] = [
+    RULE_OPERATION_RESPONSES,
+    RULE_OPERATION_ID_UNIQUE,
+    RULE_OPERATION_EMPTY_RESPONSES,
+    RULE_PATH_PARAMETER_REQUIRED,
+];
+
+/// Pass that validates operations nested in webhooks and callbacks
+pub struct NestedPathItemPass {
+    name: String,
+    rules: RuleSet,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl NestedPathItemPass {
+    pub fn new() -> Self {
+        Self::with_rules(RuleSet::default())
+    }
+
+    /// Use `rules` instead of the default rule set; disabled rules are skipped
+    pub fn with_rules(rules: RuleSet) -> Self {
+        Self {
+            name: "NestedPathItem".to_string(),
+            rules,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for NestedPathItemPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for NestedPathItemPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        for error in self.rules.check(element) {
+            if !NESTED_PATH_ITEM_RULES.contains(&error.code.as_str())
+                || !is_nested_path_item_location(&error.path)
+            {
+                continue;
+            }
+
+            let message = format!("{} (at '{}')", error.message, json_pointer(&error.path));
+            if let Some(Element::Object(obj)) = element_at_mut(&mut result, &error.path) {
+                annotate_warning(obj, &error.code, &message);
+            }
+            found.push(warning(&error.code, message, &error.path));
+        }
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Whether `path` lies inside a webhook or a callback
+fn is_nested_path_item_location(path: &[String]) -> bool {
+    path.first().is_some_and(|first| first == "webhooks")
+        || path.iter().any(|segment| segment == "callbacks")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_webhook_operation_without_responses_warns() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.1.0",
+                "info": { "title": "Pets", "version": "1.0" },
+                "paths": { "/pets": { "get": { "responses": {} } } },
+                "webhooks": { "newPet": { "post": {
+                    "requestBody": { "content": { "application/json": {} } },
+                    "responses": {}
+                } } }
+            }"#,
+        );
+
+        let pass = NestedPathItemPass::new();
+        pass.apply(&doc).expect("webhook finding should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, RULE_OPERATION_EMPTY_RESPONSES);
+        assert_eq!(warnings[0].path, vec!["webhooks", "newPet", "post"]);
+    }
+
+    #[test]
+    fn test_callback_operation_checked() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "paths": { "/subscribe": { "post": {
+                    "responses": { "201": { "description": "subscribed" } },
+                    "callbacks": { "onEvent": { "{$request.body#/url}": { "post": {
+                        "parameters": [ { "name": "id", "in": "path" } ]
+                    } } } }
+                } } }
+            }"#,
+        );
+
+        let pass = NestedPathItemPass::new();
+        pass.apply(&doc).unwrap();
+
+        let codes: Vec<String> = pass.warnings().into_iter().map(|w| w.code).collect();
+        assert!(codes.contains(&RULE_OPERATION_RESPONSES.to_string()));
+        assert!(codes.contains(&RULE_PATH_PARAMETER_REQUIRED.to_string()));
+    }
+}
@@ -0,0 +1,126 @@
+/*!
+ * `$ref` normalization pass
+ *
+ * Trims surrounding whitespace from `$ref` values, which otherwise breaks
+ * reference resolution. Every rewritten reference keeps its original value
+ * in the `normalized-ref` metadata of the object holding the `$ref`, so the
+ * author error remains visible to later tooling.
+ */
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::fold_pass::FoldPass;
+
+/// Pass that trims whitespace around `$ref` values
+pub struct NormalizeRefPass {
+    name: String,
+}
+
+impl NormalizeRefPass {
+    pub fn new() -> Self {
+        Self {
+            name: "NormalizeRef".to_string(),
+        }
+    }
+}
+
+impl Default for NormalizeRefPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for NormalizeRefPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        normalize_refs(&mut result).then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Normalize every `$ref` below `element`, returning whether anything changed
+pub fn normalize_refs(element: &mut Element) -> bool {
+    match element {
+        Element::Object(obj) => {
+            let mut changed = normalize_object_ref(obj);
+            for member in &mut obj.content {
+                changed |= normalize_refs(&mut member.value);
+            }
+            changed
+        }
+        Element::Array(arr) => arr
+            .content
+            .iter_mut()
+            .fold(false, |changed, item| normalize_refs(item) | changed),
+        _ => false,
+    }
+}
+
+fn normalize_object_ref(obj: &mut ObjectElement) -> bool {
+    let Some(member) = obj
+        .content
+        .iter_mut()
+        .find(|m| matches!(m.key.as_ref(), Element::String(k) if k.content == "$ref"))
+    else {
+        return false;
+    };
+    let Element::String(reference) = member.value.as_mut() else {
+        return false;
+    };
+
+    let trimmed = reference.content.trim().to_string();
+    if trimmed.len() == reference.content.len() {
+        return false;
+    }
+
+    let original = std::mem::replace(&mut reference.content, trimmed);
+    obj.meta
+        .properties
+        .insert("normalized-ref".to_string(), Value::String(original));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn schema_ref(doc: &Element) -> &ObjectElement {
+        doc.as_object()
+            .and_then(|o| o.get("schema"))
+            .and_then(Element::as_object)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_trims_padded_ref() {
+        let doc = json_source_to_ast(r##"{ "schema": { "$ref": "  #/components/schemas/Pet  " } }"##);
+
+        let result = NormalizeRefPass::new().apply(&doc).expect("ref should be rewritten");
+        let obj = schema_ref(&result);
+
+        assert_eq!(
+            obj.get("$ref").and_then(Element::as_string).unwrap().content,
+            "#/components/schemas/Pet"
+        );
+        assert_eq!(
+            obj.meta.properties.get("normalized-ref"),
+            Some(&Value::String("  #/components/schemas/Pet  ".to_string()))
+        );
+
+        // A second run has nothing left to do
+        assert!(NormalizeRefPass::new().apply(&result).is_none());
+    }
+
+    #[test]
+    fn test_clean_ref_untouched() {
+        let doc = json_source_to_ast(r##"{ "schema": { "$ref": "#/components/schemas/Pet" } }"##);
+
+        assert!(NormalizeRefPass::new().apply(&doc).is_none());
+        assert!(!schema_ref(&doc).meta.properties.contains_key("normalized-ref"));
+    }
+}
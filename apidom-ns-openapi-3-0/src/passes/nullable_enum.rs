@@ -0,0 +1,225 @@
+/*!
+ * Nullable / enum reconciliation pass
+ *
+ * OpenAPI 3.0 expresses nullability with `nullable: true`, and an `enum` on
+ * a nullable schema must list `null` for null to be accepted. OpenAPI 3.1
+ * drops `nullable`: listing `null` in `enum` is enough.
+ *
+ * Per version this pass:
+ * - 3.1: removes `nullable: true` when `enum` already lists `null`
+ *   (recorded as `nullable-collapsed` metadata);
+ * - both: warns when `nullable` and the presence of `null` in `enum`
+ *   disagree.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported when `nullable` and `enum` disagree
+pub const NULLABLE_ENUM_MISMATCH_CODE: &str = "NULLABLE_ENUM_MISMATCH";
+
+/// Schema dialect used to reconcile `nullable` with `enum`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullableDialect {
+    /// OpenAPI 3.0: `nullable` is meaningful
+    OpenApi30,
+    /// OpenAPI 3.1: `null` in `enum` replaces `nullable`
+    OpenApi31,
+}
+
+impl NullableDialect {
+    /// Dialect of a document, from its `openapi` version field
+    pub fn detect(document: &Element) -> Self {
+        let version = document
+            .as_object()
+            .and_then(|o| o.get("openapi"))
+            .and_then(Element::as_string)
+            .map(|s| s.content.as_str())
+            .unwrap_or_default();
+        if version.starts_with("3.1") {
+            Self::OpenApi31
+        } else {
+            Self::OpenApi30
+        }
+    }
+}
+
+/// Pass that reconciles `nullable` with `null` membership in `enum`
+pub struct NullableEnumPass {
+    name: String,
+    dialect: Option<NullableDialect>,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl NullableEnumPass {
+    /// Create a pass that detects the dialect from each document
+    pub fn new() -> Self {
+        Self {
+            name: "NullableEnum".to_string(),
+            dialect: None,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Force a dialect instead of detecting it
+    pub fn with_dialect(mut self, dialect: NullableDialect) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for NullableEnumPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for NullableEnumPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let dialect = self
+            .dialect
+            .unwrap_or_else(|| NullableDialect::detect(element));
+
+        let mut result = element.clone();
+        let mut found = Vec::new();
+        let mut collapsed = false;
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, schema| {
+            if element_type != "schema" {
+                return;
+            }
+            let Some(enum_has_null) = schema
+                .get("enum")
+                .and_then(Element::as_array)
+                .map(|values| values.content.iter().any(|v| matches!(v, Element::Null(_))))
+            else {
+                return;
+            };
+            let nullable = match schema.get("nullable") {
+                Some(Element::Boolean(b)) => Some(b.content),
+                _ => None,
+            };
+
+            match (dialect, nullable, enum_has_null) {
+                (NullableDialect::OpenApi31, Some(true), true) => {
+                    schema.content.retain(|m| {
+                        !matches!(m.key.as_ref(), Element::String(k) if k.content == "nullable")
+                    });
+                    schema
+                        .meta
+                        .properties
+                        .insert("nullable-collapsed".to_string(), Value::Bool(true));
+                    collapsed = true;
+                }
+                (_, Some(true), false) => {
+                    let message = format!(
+                        "Schema at '{}' is nullable but its enum does not list null",
+                        json_pointer(path)
+                    );
+                    annotate_warning(schema, "nullable", &message);
+                    found.push(warning(NULLABLE_ENUM_MISMATCH_CODE, message, path));
+                }
+                (NullableDialect::OpenApi30, None | Some(false), true)
+                | (NullableDialect::OpenApi31, Some(false), true) => {
+                    let message = format!(
+                        "Schema at '{}' lists null in its enum but is not nullable",
+                        json_pointer(path)
+                    );
+                    annotate_warning(schema, "nullable", &message);
+                    found.push(warning(NULLABLE_ENUM_MISMATCH_CODE, message, path));
+                }
+                _ => {}
+            }
+        });
+
+        let changed = collapsed || !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(version: &str, schema: &str) -> Element {
+        json_source_to_ast(&format!(
+            r#"{{ "openapi": "{}", "components": {{ "schemas": {{ "Status": {} }} }} }}"#,
+            version, schema
+        ))
+    }
+
+    fn status_schema(doc: &Element) -> &ObjectElement {
+        doc.as_object()
+            .and_then(|o| o.get("components"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("schemas"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("Status"))
+            .and_then(Element::as_object)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_redundant_nullable_collapsed_in_3_1() {
+        let pass = NullableEnumPass::new();
+        let doc = document("3.1.0", r#"{ "enum": ["a", "b", null], "nullable": true }"#);
+
+        let result = pass.apply(&doc).expect("nullable should be collapsed");
+        let schema = status_schema(&result);
+
+        assert!(!schema.has_key("nullable"));
+        assert!(schema.has_key("enum"));
+        assert_eq!(
+            schema.meta.properties.get("nullable-collapsed"),
+            Some(&Value::Bool(true))
+        );
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_consistent_schema_untouched_in_3_0() {
+        let pass = NullableEnumPass::new();
+        let doc = document("3.0.3", r#"{ "enum": ["a", null], "nullable": true }"#);
+
+        assert!(pass.apply(&doc).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_nullable_warns() {
+        let pass = NullableEnumPass::new();
+        let doc = document("3.0.3", r#"{ "enum": ["a", "b"], "nullable": true }"#);
+
+        pass.apply(&doc).expect("mismatch should be annotated");
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, NULLABLE_ENUM_MISMATCH_CODE);
+        assert_eq!(warnings[0].path, vec!["components", "schemas", "Status"]);
+
+        let doc = document("3.0.3", r#"{ "enum": ["a", null] }"#);
+        pass.apply(&doc);
+        assert_eq!(pass.warnings().len(), 1);
+        assert!(pass.warnings()[0].message.contains("not nullable"));
+    }
+}
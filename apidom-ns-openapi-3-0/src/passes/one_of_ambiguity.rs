@@ -0,0 +1,243 @@
+/*!
+ * `oneOf` ambiguity lint (heuristic)
+ *
+ * Without a `discriminator`, a validator has to try every `oneOf` member and
+ * expects exactly one to match. Object members whose required properties
+ * are the same, or a subset of another member's, usually match the same
+ * payloads and make validation fail as ambiguous. This pass warns about such
+ * pairs; members whose required properties only partly overlap are not
+ * flagged, since each still requires a property the other lacks. Members of different `type`s are considered distinguishable, and
+ * required properties contributed through `allOf` and local `$ref`s are
+ * taken into account.
+ */
+
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+use crate::schema_analysis::{effective_required, local_ref_resolver};
+
+/// Warning code reported for indistinguishable `oneOf` members
+pub const ONE_OF_AMBIGUITY_CODE: &str = "ONE_OF_AMBIGUITY";
+
+/// Pass that flags `oneOf` members that cannot be told apart
+pub struct OneOfAmbiguityPass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl OneOfAmbiguityPass {
+    pub fn new() -> Self {
+        Self {
+            name: "OneOfAmbiguity".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for OneOfAmbiguityPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What the heuristic looks at for each `oneOf` member
+struct MemberShape {
+    schema_type: Option<String>,
+    required: BTreeSet<String>,
+}
+
+impl FoldPass for OneOfAmbiguityPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let resolver = local_ref_resolver(element);
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, schema| {
+            if element_type != "schema" || schema.has_key("discriminator") {
+                return;
+            }
+            let Some(members) = schema.get("oneOf").and_then(Element::as_array) else {
+                return;
+            };
+
+            let shapes: Vec<Option<MemberShape>> = members
+                .content
+                .iter()
+                .map(|member| member_shape(member, &resolver))
+                .collect();
+
+            let mut ambiguous = Vec::new();
+            for (i, first) in shapes.iter().enumerate() {
+                for (j, second) in shapes.iter().enumerate().skip(i + 1) {
+                    if let (Some(first), Some(second)) = (first, second)
+                        && first.schema_type == second.schema_type
+                        && (first.required.is_subset(&second.required)
+                            || second.required.is_subset(&first.required))
+                    {
+                        ambiguous.push((i, j));
+                    }
+                }
+            }
+
+            for (i, j) in ambiguous {
+                let message = format!(
+                    "oneOf members {} and {} at '{}' differ by no required property and have \
+                     no discriminator; payloads may match both",
+                    i,
+                    j,
+                    json_pointer(path)
+                );
+                annotate_warning(schema, &format!("oneOf_{}_{}", i, j), &message);
+                found.push(warning(ONE_OF_AMBIGUITY_CODE, message, path));
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Shape of an object-like member; `None` for members the heuristic skips
+fn member_shape<R>(member: &Element, resolver: &R) -> Option<MemberShape>
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let resolved = match member
+        .as_object()?
+        .get("$ref")
+        .and_then(Element::as_string)
+    {
+        Some(reference) => resolver(&reference.content)?,
+        None => member.clone(),
+    };
+    let obj = resolved.as_object()?;
+
+    let schema_type = obj
+        .get("type")
+        .and_then(Element::as_string)
+        .map(|t| t.content.clone());
+    let object_like = match schema_type.as_deref() {
+        Some(ty) => ty == "object",
+        None => ["properties", "required", "allOf"].iter().any(|key| obj.has_key(key)),
+    };
+    if !object_like {
+        return None;
+    }
+
+    Some(MemberShape {
+        schema_type,
+        required: effective_required(&resolved, resolver).into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(pet: &str) -> Element {
+        json_source_to_ast(&format!(
+            r##"{{
+                "openapi": "3.0.3",
+                "components": {{ "schemas": {{
+                    "Cat": {{ "type": "object", "required": ["name"], "properties": {{ "name": {{ "type": "string" }} }} }},
+                    "Dog": {{ "type": "object", "required": ["name"], "properties": {{ "name": {{ "type": "string" }} }} }},
+                    "Pet": {}
+                }} }}
+            }}"##,
+            pet
+        ))
+    }
+
+    #[test]
+    fn test_shared_required_properties_are_ambiguous() {
+        let doc = document(
+            r##"{ "oneOf": [ { "$ref": "#/components/schemas/Cat" }, { "$ref": "#/components/schemas/Dog" } ] }"##,
+        );
+
+        let pass = OneOfAmbiguityPass::new();
+        pass.apply(&doc).expect("ambiguity should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ONE_OF_AMBIGUITY_CODE);
+        assert_eq!(warnings[0].path, vec!["components", "schemas", "Pet"]);
+    }
+
+    #[test]
+    fn test_each_ambiguous_pair_keeps_its_annotation() {
+        let doc = document(
+            r##"{ "oneOf": [
+                { "$ref": "#/components/schemas/Cat" },
+                { "$ref": "#/components/schemas/Dog" },
+                { "type": "object", "required": ["name"] }
+            ] }"##,
+        );
+
+        let pass = OneOfAmbiguityPass::new();
+        let result = pass.apply(&doc).unwrap();
+        assert_eq!(pass.warnings().len(), 3);
+
+        let pet = result
+            .as_object()
+            .and_then(|root| root.get("components"))
+            .and_then(Element::as_object)
+            .and_then(|components| components.get("schemas"))
+            .and_then(Element::as_object)
+            .and_then(|schemas| schemas.get("Pet"))
+            .and_then(Element::as_object)
+            .unwrap();
+        for pair in ["0_1", "0_2", "1_2"] {
+            let key = format!("validationWarning_oneOf_{}", pair);
+            assert!(pet.meta.properties.contains_key(&key), "missing annotation {}", key);
+        }
+    }
+
+    #[test]
+    fn test_partly_overlapping_required_properties_are_quiet() {
+        let doc = document(
+            r#"{ "oneOf": [
+                { "type": "object", "required": ["name", "meow"] },
+                { "type": "object", "required": ["name", "bark"] }
+            ] }"#,
+        );
+        assert!(OneOfAmbiguityPass::new().apply(&doc).is_none());
+    }
+
+    #[test]
+    fn test_discriminator_or_distinct_members_are_quiet() {
+        let with_discriminator = document(
+            r##"{ "oneOf": [ { "$ref": "#/components/schemas/Cat" }, { "$ref": "#/components/schemas/Dog" } ],
+                  "discriminator": { "propertyName": "petType" } }"##,
+        );
+        assert!(OneOfAmbiguityPass::new().apply(&with_discriminator).is_none());
+
+        let distinct = document(
+            r#"{ "oneOf": [
+                { "type": "object", "required": ["meow"] },
+                { "type": "object", "required": ["bark"] },
+                { "type": "string" }
+            ] }"#,
+        );
+        assert!(OneOfAmbiguityPass::new().apply(&distinct).is_none());
+    }
+}
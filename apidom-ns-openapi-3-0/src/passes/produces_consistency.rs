@@ -0,0 +1,242 @@
+/*!
+ * Produces consistency pass (Swagger 2 interop)
+ *
+ * Documents upgraded from Swagger 2 keep their `produces` lists (document
+ * level and operation level) next to the migrated response `content`. This
+ * pass warns when a response declares a media type that is not covered by
+ * the operation's effective `produces` list, which usually means the
+ * migration went wrong.
+ *
+ * Operations without any effective `produces` list are not checked.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+
+/// Warning code reported for an undeclared response media type
+pub const PRODUCES_MISMATCH_CODE: &str = "PRODUCES_MISMATCH";
+
+/// Pass that checks response media types against the effective `produces`
+pub struct ProducesConsistencyPass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl ProducesConsistencyPass {
+    pub fn new() -> Self {
+        Self {
+            name: "ProducesConsistency".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for ProducesConsistencyPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for ProducesConsistencyPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let document_produces = element
+            .as_object()
+            .and_then(|root| media_type_list(root, "produces"));
+
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, operation| {
+            if element_type != "operation" {
+                return;
+            }
+            let Some(produces) =
+                media_type_list(operation, "produces").or_else(|| document_produces.clone())
+            else {
+                return;
+            };
+
+            let Some(responses) = object_member_mut(operation, "responses") else {
+                return;
+            };
+            for response_member in &mut responses.content {
+                let Some(status) = response_member.key.as_string().map(|s| s.content.clone())
+                else {
+                    continue;
+                };
+                let Element::Object(response) = response_member.value.as_mut() else {
+                    continue;
+                };
+                let Some(content) = response.get("content").and_then(Element::as_object) else {
+                    continue;
+                };
+
+                let undeclared: Vec<String> = content
+                    .content
+                    .iter()
+                    .filter_map(|m| m.key.as_string())
+                    .map(|k| k.content.clone())
+                    .filter(|media_type| !media_type_listed(media_type, &produces))
+                    .collect();
+
+                for media_type in undeclared {
+                    let mut location = path.to_vec();
+                    location.extend([
+                        "responses".to_string(),
+                        status.clone(),
+                        "content".to_string(),
+                        media_type.clone(),
+                    ]);
+                    let message = format!(
+                        "Response content type '{}' at '{}' is not listed in the operation's produces [{}]",
+                        media_type,
+                        json_pointer(&location),
+                        produces.join(", ")
+                    );
+                    annotate_warning(response, &format!("content_{}", media_type), &message);
+                    found.push(warning(PRODUCES_MISMATCH_CODE, message, &location));
+                }
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Read a Swagger 2 style media type list (`produces` / `consumes`)
+pub(crate) fn media_type_list(obj: &ObjectElement, key: &str) -> Option<Vec<String>> {
+    let list = obj.get(key).and_then(Element::as_array)?;
+    Some(
+        list.content
+            .iter()
+            .filter_map(Element::as_string)
+            .map(|s| s.content.clone())
+            .collect(),
+    )
+}
+
+/// Whether `media_type` is covered by `list`, honouring `*/*` and `type/*`
+/// wildcards and ignoring media type parameters
+pub(crate) fn media_type_listed(media_type: &str, list: &[String]) -> bool {
+    let essence = |m: &str| m.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    let candidate = essence(media_type);
+    let (candidate_type, _) = candidate.split_once('/').unwrap_or((candidate.as_str(), ""));
+
+    list.iter().map(|m| essence(m)).any(|declared| {
+        declared == candidate
+            || declared == "*/*"
+            || declared
+                .strip_suffix("/*")
+                .is_some_and(|declared_type| declared_type == candidate_type)
+    })
+}
+
+fn object_member_mut<'a>(obj: &'a mut ObjectElement, key: &str) -> Option<&'a mut ObjectElement> {
+    obj.content.iter_mut().find_map(|member| match (member.key.as_ref(), member.value.as_mut()) {
+        (Element::String(k), Element::Object(value)) if k.content == key => Some(value),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const UPGRADED: &str = r#"{
+        "openapi": "3.0.0",
+        "info": { "title": "Upgraded from Swagger 2", "version": "1.0" },
+        "produces": ["application/json"],
+        "paths": {
+            "/pets": {
+                "get": {
+                    "responses": { "200": { "description": "ok", "content": {
+                        "application/json": {},
+                        "application/xml": {}
+                    } } }
+                },
+                "post": {
+                    "produces": ["text/*"],
+                    "responses": { "201": { "description": "created", "content": {
+                        "text/plain; charset=utf-8": {}
+                    } } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_undeclared_response_content_type() {
+        let pass = ProducesConsistencyPass::new();
+        let result = pass
+            .apply(&json_source_to_ast(UPGRADED))
+            .expect("mismatch should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, PRODUCES_MISMATCH_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["paths", "/pets", "get", "responses", "200", "content", "application/xml"]
+        );
+
+        let response = result
+            .as_object()
+            .and_then(|o| o.get("paths"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("/pets"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("get"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("responses"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("200"))
+            .and_then(Element::as_object)
+            .unwrap();
+        assert!(response
+            .meta
+            .properties
+            .contains_key("validationWarning_content_application/xml"));
+    }
+
+    #[test]
+    fn test_document_without_produces_is_skipped() {
+        let pass = ProducesConsistencyPass::new();
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.0", "paths": { "/a": { "get": { "responses": {
+                "200": { "description": "ok", "content": { "application/xml": {} } }
+            } } } } }"#,
+        );
+
+        assert!(pass.apply(&doc).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_media_type_listed() {
+        let list = vec!["application/*".to_string()];
+        assert!(media_type_listed("application/json", &list));
+        assert!(!media_type_listed("text/plain", &list));
+        assert!(media_type_listed("anything/else", &["*/*".to_string()]));
+    }
+}
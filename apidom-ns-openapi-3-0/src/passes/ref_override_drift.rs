@@ -0,0 +1,179 @@
+/*!
+ * Reference override drift pass
+ *
+ * A component referenced from several places may have sibling fields next
+ * to each `$ref` (e.g. a `summary` overriding the referenced path item's).
+ * When two usages of the same target override the same field with
+ * different values, the inline copies have drifted apart. This pass builds
+ * the [`RefGraph`] of the document, compares the overrides of every target
+ * with more than one usage and warns on each conflicting usage.
+ */
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{
+    annotate_warning, element_at, element_at_mut, json_pointer, pointer_segments, warning,
+};
+use crate::ref_graph::RefGraph;
+
+/// Warning code reported for conflicting overrides of one `$ref` target
+pub const REF_OVERRIDE_DRIFT_CODE: &str = "REF_OVERRIDE_DRIFT";
+
+/// Location of a `$ref` and the sibling fields overriding its target
+type RefUsage = (Vec<String>, BTreeMap<String, Value>);
+
+/// Pass that detects conflicting sibling overrides of a shared `$ref` target
+pub struct RefOverrideDriftPass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl RefOverrideDriftPass {
+    pub fn new() -> Self {
+        Self {
+            name: "RefOverrideDrift".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for RefOverrideDriftPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for RefOverrideDriftPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let graph = RefGraph::from_element(element);
+
+        let mut usages: BTreeMap<&str, Vec<RefUsage>> = BTreeMap::new();
+        for edge in graph.edges() {
+            let location = pointer_segments(&edge.location);
+            let Some(obj) = element_at(element, &location).and_then(Element::as_object) else {
+                continue;
+            };
+            usages
+                .entry(edge.to.as_str())
+                .or_default()
+                .push((location, ref_siblings(obj)));
+        }
+
+        let mut conflicts = Vec::new();
+        for (target, uses) in usages.iter().filter(|(_, uses)| uses.len() > 1) {
+            let mut values_by_field: BTreeMap<&String, Vec<&Value>> = BTreeMap::new();
+            for (_, siblings) in uses {
+                for (field, value) in siblings {
+                    values_by_field.entry(field).or_default().push(value);
+                }
+            }
+
+            for (field, values) in values_by_field {
+                if values.windows(2).all(|pair| pair[0] == pair[1]) {
+                    continue;
+                }
+                for (location, _) in uses.iter().filter(|(_, s)| s.contains_key(field)) {
+                    let message = format!(
+                        "'{}' overrides '{}' of shared reference '{}' with a value that differs from other usages",
+                        json_pointer(location),
+                        field,
+                        target
+                    );
+                    conflicts.push((location.clone(), field.clone(), message));
+                }
+            }
+        }
+
+        let mut result = element.clone();
+        let mut found = Vec::new();
+        for (location, field, message) in conflicts {
+            if let Some(Element::Object(obj)) = element_at_mut(&mut result, &location) {
+                annotate_warning(obj, &field, &message);
+            }
+            found.push(warning(REF_OVERRIDE_DRIFT_CODE, message, &location));
+        }
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn ref_siblings(obj: &ObjectElement) -> BTreeMap<String, Value> {
+    obj.content
+        .iter()
+        .filter_map(|member| {
+            let key = member.key.as_string()?;
+            (key.content != "$ref").then(|| (key.content.clone(), member.value.to_value()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(first_summary: &str, second_summary: &str) -> Element {
+        json_source_to_ast(&format!(
+            r##"{{
+                "openapi": "3.0.3",
+                "paths": {{
+                    "/pets": {{ "$ref": "#/components/pathItems/Shared", "summary": "{}" }},
+                    "/animals": {{ "$ref": "#/components/pathItems/Shared", "summary": "{}" }}
+                }},
+                "components": {{ "pathItems": {{ "Shared": {{
+                    "summary": "Shared item",
+                    "get": {{ "responses": {{ "200": {{ "description": "ok" }} }} }}
+                }} }} }}
+            }}"##,
+            first_summary, second_summary
+        ))
+    }
+
+    #[test]
+    fn test_conflicting_overrides_warn() {
+        let pass = RefOverrideDriftPass::new();
+        let result = pass
+            .apply(&document("List pets", "List animals"))
+            .expect("drift should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|w| w.code == REF_OVERRIDE_DRIFT_CODE));
+        assert!(warnings.iter().any(|w| w.path == vec!["paths", "/pets"]));
+        assert!(warnings.iter().any(|w| w.path == vec!["paths", "/animals"]));
+        assert!(warnings[0].message.contains("#/components/pathItems/Shared"));
+
+        let pets = element_at(&result, &["paths".to_string(), "/pets".to_string()])
+            .and_then(Element::as_object)
+            .unwrap();
+        assert!(pets.meta.properties.contains_key("validationWarning_summary"));
+    }
+
+    #[test]
+    fn test_matching_overrides_pass() {
+        let pass = RefOverrideDriftPass::new();
+
+        assert!(pass.apply(&document("Same", "Same")).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+}
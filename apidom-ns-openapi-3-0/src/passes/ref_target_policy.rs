@@ -0,0 +1,178 @@
+/*!
+ * Reference target policy pass
+ *
+ * Many style guides require local references to point into
+ * `#/components/...` instead of into arbitrary inline locations such as
+ * `#/paths/~1users/get/responses/200`. This pass warns on every local
+ * `$ref` whose target is outside a configurable allowlist of pointer
+ * prefixes. External references are ignored unless explicitly checked.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, walk_objects_mut, warning};
+
+/// Warning code reported for a `$ref` outside the allowed prefixes
+pub const REF_TARGET_POLICY_CODE: &str = "REF_TARGET_POLICY";
+
+/// Pointer prefix allowed by default
+pub const DEFAULT_ALLOWED_REF_PREFIX: &str = "#/components/";
+
+/// Pass that restricts where `$ref`s may point to
+pub struct RefTargetPolicyPass {
+    name: String,
+    allowed_prefixes: Vec<String>,
+    check_external: bool,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl RefTargetPolicyPass {
+    pub fn new() -> Self {
+        Self::with_allowed_prefixes(&[DEFAULT_ALLOWED_REF_PREFIX])
+    }
+
+    /// Create a pass with a custom allowlist of pointer prefixes
+    pub fn with_allowed_prefixes(prefixes: &[&str]) -> Self {
+        Self {
+            name: "RefTargetPolicy".to_string(),
+            allowed_prefixes: prefixes.iter().map(|p| p.to_string()).collect(),
+            check_external: false,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Allow an additional pointer prefix
+    pub fn allow_prefix(mut self, prefix: &str) -> Self {
+        self.allowed_prefixes.push(prefix.to_string());
+        self
+    }
+
+    /// Also apply the allowlist to the fragment of external references
+    /// (`file.yaml#/...`)
+    pub fn check_external(mut self, enabled: bool) -> Self {
+        self.check_external = enabled;
+        self
+    }
+
+    pub fn allowed_prefixes(&self) -> &[String] {
+        &self.allowed_prefixes
+    }
+
+    /// Whether `reference` satisfies the policy; only the `#` fragment of a
+    /// reference is compared against the allowed prefixes
+    pub fn is_allowed(&self, reference: &str) -> bool {
+        if !reference.starts_with('#') && !self.check_external {
+            return true;
+        }
+        let fragment = reference.find('#').map_or("#", |index| &reference[index..]);
+        self.allowed_prefixes
+            .iter()
+            .any(|prefix| fragment.starts_with(prefix.as_str()))
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for RefTargetPolicyPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for RefTargetPolicyPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_objects_mut(&mut result, &mut |path, obj| {
+            let Some(reference) = obj
+                .get("$ref")
+                .and_then(Element::as_string)
+                .map(|s| s.content.clone())
+            else {
+                return;
+            };
+            if self.is_allowed(&reference) {
+                return;
+            }
+
+            let message = format!(
+                "$ref '{}' at '{}' points outside the allowed locations [{}]",
+                reference,
+                json_pointer(path),
+                self.allowed_prefixes.join(", ")
+            );
+            annotate_warning(obj, "$ref", &message);
+            found.push(warning(REF_TARGET_POLICY_CODE, message, path));
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(reference: &str) -> Element {
+        json_source_to_ast(&format!(
+            r#"{{ "openapi": "3.0.3", "paths": {{ "/admins": {{ "get": {{ "responses": {{
+                "200": {{ "$ref": "{}" }}
+            }} }} }} }} }}"#,
+            reference
+        ))
+    }
+
+    #[test]
+    fn test_inline_target_warns() {
+        let pass = RefTargetPolicyPass::new();
+        pass.apply(&document("#/paths/~1users/get/responses/200"))
+            .expect("policy violation should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, REF_TARGET_POLICY_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["paths", "/admins", "get", "responses", "200"]
+        );
+    }
+
+    #[test]
+    fn test_components_target_passes() {
+        let pass = RefTargetPolicyPass::new();
+
+        assert!(pass.apply(&document("#/components/schemas/X")).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_custom_allowlist_and_external_refs() {
+        let pass = RefTargetPolicyPass::new().allow_prefix("#/paths/");
+        assert!(pass.is_allowed("#/paths/~1users/get/responses/200"));
+        assert!(pass.is_allowed("common.yaml#/Pet"));
+
+        let strict = RefTargetPolicyPass::new().check_external(true);
+        assert!(!strict.is_allowed("common.yaml#/Pet"));
+        assert!(!strict.is_allowed("common.yaml"));
+        assert!(strict.is_allowed("other.yaml#/components/schemas/X"));
+        assert!(strict.apply(&document("other.yaml#/components/schemas/X")).is_none());
+    }
+}
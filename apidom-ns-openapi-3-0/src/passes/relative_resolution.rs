@@ -0,0 +1,163 @@
+/*!
+ * Relative resolution base advisory
+ *
+ * Relative server URLs resolve against the location the document was
+ * served from, and relative external `$ref`s resolve against the document's
+ * own URI. When a document uses both, readers and tools easily assume the
+ * wrong base for one of them. This pass reports that combination once per
+ * relative server URL and suggests configuring an explicit base URI; no
+ * advisory is issued once a base URI is set with [`RelativeResolutionPass::with_base_uri`].
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, element_at_mut, json_pointer, warning};
+use crate::ref_graph::RefGraph;
+
+/// Warning code reported for an ambiguous resolution base
+pub const RELATIVE_RESOLUTION_CODE: &str = "RELATIVE_RESOLUTION_AMBIGUITY";
+
+/// Pass that flags relative server URLs used together with relative external references
+pub struct RelativeResolutionPass {
+    name: String,
+    base_uri: Option<String>,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl RelativeResolutionPass {
+    pub fn new() -> Self {
+        Self {
+            name: "RelativeResolution".to_string(),
+            base_uri: None,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Base URI the document is resolved against; silences the advisory
+    pub fn with_base_uri(mut self, base_uri: &str) -> Self {
+        self.base_uri = Some(base_uri.to_string());
+        self
+    }
+
+    pub fn base_uri(&self) -> Option<&str> {
+        self.base_uri.as_deref()
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for RelativeResolutionPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for RelativeResolutionPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let mut found = Vec::new();
+        let mut result = element.clone();
+
+        if self.base_uri.is_none()
+            && let Some(external) = RefGraph::from_element(element)
+                .edges()
+                .find(|edge| is_relative_reference(&edge.to))
+        {
+            let mut relative_servers = Vec::new();
+            walk_openapi_objects(element, |element_type, path, server| {
+                if element_type == "server"
+                    && let Some(url) = server.get("url").and_then(Element::as_string)
+                    && is_relative_url(&url.content)
+                {
+                    relative_servers.push((path.to_vec(), url.content.clone()));
+                }
+            });
+
+            for (path, url) in relative_servers {
+                let message = format!(
+                    "Server URL '{}' at '{}' is relative while '{}' (at '{}') is a relative \
+                     external reference; they may resolve against different bases, set an \
+                     explicit base URI",
+                    url,
+                    json_pointer(&path),
+                    external.to,
+                    external.location
+                );
+                if let Some(Element::Object(server)) = element_at_mut(&mut result, &path) {
+                    annotate_warning(server, "url", &message);
+                }
+                found.push(warning(RELATIVE_RESOLUTION_CODE, message, &path));
+            }
+        }
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn is_absolute_url(url: &str) -> bool {
+    url.contains("://") || url.starts_with("urn:")
+}
+
+/// Server URLs without a scheme resolve against the document location
+fn is_relative_url(url: &str) -> bool {
+    !is_absolute_url(url)
+}
+
+/// References into other documents given by a relative location
+fn is_relative_reference(reference: &str) -> bool {
+    !reference.starts_with('#') && !is_absolute_url(reference)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r#"{
+        "openapi": "3.1.0",
+        "servers": [ { "url": "/v1" }, { "url": "https://api.example.com/v1" } ],
+        "paths": { "/pets": { "get": { "responses": { "200": {
+            "description": "ok",
+            "content": { "application/json": { "schema": { "$ref": "schemas/pet.yaml#/Pet" } } }
+        } } } } }
+    }"#;
+
+    #[test]
+    fn test_relative_server_with_relative_ref_warns() {
+        let pass = RelativeResolutionPass::new();
+        pass.apply(&json_source_to_ast(DOCUMENT))
+            .expect("advisory should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, RELATIVE_RESOLUTION_CODE);
+        assert_eq!(warnings[0].path, vec!["servers", "0"]);
+        assert!(warnings[0].message.contains("schemas/pet.yaml#/Pet"));
+    }
+
+    #[test]
+    fn test_explicit_base_uri_or_local_refs_are_quiet() {
+        let doc = json_source_to_ast(DOCUMENT);
+        let pass = RelativeResolutionPass::new().with_base_uri("https://example.com/spec/openapi.json");
+        assert!(pass.apply(&doc).is_none());
+
+        let local = json_source_to_ast(&DOCUMENT.replace("schemas/pet.yaml#/Pet", "#/components/schemas/Pet"));
+        assert!(RelativeResolutionPass::new().apply(&local).is_none());
+    }
+}
@@ -0,0 +1,193 @@
+/*!
+ * Required-but-nullable property advisory
+ *
+ * Listing a property in `required` only demands that the key is present;
+ * when its schema also accepts `null`, `{ "name": null }` still validates.
+ * That combination is legal but frequently unintended, so this pass reports
+ * each required property whose schema allows null, either through
+ * `nullable: true` (OpenAPI 3.0) or a `null` entry in `type` (OpenAPI 3.1).
+ * Property schemas given as local `$ref`s are resolved before checking.
+ */
+
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::walk_openapi_objects_mut;
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, json_pointer, warning};
+use crate::schema_analysis::local_ref_resolver;
+
+/// Warning code reported for required properties that accept null
+pub const REQUIRED_NULLABLE_CODE: &str = "REQUIRED_NULLABLE";
+
+/// Pass that flags required properties whose schema allows null
+pub struct RequiredNullablePass {
+    name: String,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl RequiredNullablePass {
+    pub fn new() -> Self {
+        Self {
+            name: "RequiredNullable".to_string(),
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for RequiredNullablePass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for RequiredNullablePass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let resolver = local_ref_resolver(element);
+        let mut result = element.clone();
+        let mut found = Vec::new();
+
+        walk_openapi_objects_mut(&mut result, |element_type, path, schema| {
+            if element_type != "schema" {
+                return;
+            }
+            let (Some(required), Some(properties)) = (
+                schema.get("required").and_then(Element::as_array),
+                schema.get("properties").and_then(Element::as_object),
+            ) else {
+                return;
+            };
+
+            let nullable: Vec<String> = required
+                .content
+                .iter()
+                .filter_map(Element::as_string)
+                .map(|name| name.content.clone())
+                .filter(|name| {
+                    properties
+                        .get(name)
+                        .is_some_and(|property| allows_null(property, &resolver))
+                })
+                .collect();
+
+            for property in nullable {
+                let message = format!(
+                    "Property '{}' of schema at '{}' is required but its schema allows null",
+                    property,
+                    json_pointer(path)
+                );
+                annotate_warning(schema, "required", &message);
+                let mut property_path = path.to_vec();
+                property_path.extend(["properties".to_string(), property]);
+                found.push(warning(REQUIRED_NULLABLE_CODE, message, &property_path));
+            }
+        });
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Whether a property schema accepts null, following one local `$ref`
+fn allows_null<R>(property: &Element, resolver: &R) -> bool
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let resolved = match property
+        .as_object()
+        .and_then(|o| o.get("$ref"))
+        .and_then(Element::as_string)
+    {
+        Some(reference) => match resolver(&reference.content) {
+            Some(target) => target,
+            None => return false,
+        },
+        None => property.clone(),
+    };
+    let Some(schema) = resolved.as_object() else {
+        return false;
+    };
+
+    let nullable = matches!(schema.get("nullable"), Some(Element::Boolean(b)) if b.content);
+    let null_type = match schema.get("type") {
+        Some(Element::String(t)) => t.content == "null",
+        Some(Element::Array(types)) => types
+            .content
+            .iter()
+            .filter_map(Element::as_string)
+            .any(|t| t.content == "null"),
+        _ => false,
+    };
+    nullable || null_type
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn document(properties: &str) -> Element {
+        json_source_to_ast(&format!(
+            r##"{{
+                "openapi": "3.1.0",
+                "components": {{ "schemas": {{
+                    "MaybeName": {{ "type": "string", "nullable": true }},
+                    "User": {{ "type": "object", "required": ["id", "name"], "properties": {} }}
+                }} }}
+            }}"##,
+            properties
+        ))
+    }
+
+    #[test]
+    fn test_required_property_with_null_type_warns() {
+        let doc = document(
+            r#"{ "id": { "type": "integer" }, "name": { "type": ["string", "null"] } }"#,
+        );
+
+        let pass = RequiredNullablePass::new();
+        pass.apply(&doc).expect("advisory should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, REQUIRED_NULLABLE_CODE);
+        assert_eq!(
+            warnings[0].path,
+            vec!["components", "schemas", "User", "properties", "name"]
+        );
+    }
+
+    #[test]
+    fn test_nullable_through_reference_warns() {
+        let doc = document(
+            r##"{ "id": { "type": "integer" }, "name": { "$ref": "#/components/schemas/MaybeName" } }"##,
+        );
+
+        let pass = RequiredNullablePass::new();
+        pass.apply(&doc).unwrap();
+        assert_eq!(pass.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_non_nullable_required_properties_are_quiet() {
+        let doc = document(
+            r#"{ "id": { "type": "integer" }, "name": { "type": "string" }, "nick": { "type": ["string", "null"] } }"#,
+        );
+        assert!(RequiredNullablePass::new().apply(&doc).is_none());
+    }
+}
@@ -0,0 +1,102 @@
+//! Helpers shared by the analysis passes.
+
+use apidom_ast::minim_model::{Element, ObjectElement};
+use serde_json::Value;
+
+use crate::extensible_framework::ValidationWarning;
+
+/// Build a warning for the object found at `path`
+pub(crate) fn warning(code: &str, message: String, path: &[String]) -> ValidationWarning {
+    ValidationWarning {
+        message,
+        path: path.to_vec(),
+        code: code.to_string(),
+    }
+}
+
+/// Record a warning on the offending object, mirroring the builders'
+/// `validationError_<field>` metadata
+pub(crate) fn annotate_warning(obj: &mut ObjectElement, field: &str, message: &str) {
+    obj.meta.properties.insert(
+        format!("validationWarning_{}", field),
+        Value::String(message.to_string()),
+    );
+}
+
+/// Render a path as an RFC 6901 JSON pointer
+pub(crate) fn json_pointer(path: &[String]) -> String {
+    path.iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Split a JSON pointer (optionally `#`-prefixed) into unescaped segments
+pub(crate) fn pointer_segments(pointer: &str) -> Vec<String> {
+    let pointer = pointer.strip_prefix('#').unwrap_or(pointer);
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+/// Element found by following member keys and array indices from `root`
+pub(crate) fn element_at<'a>(root: &'a Element, path: &[String]) -> Option<&'a Element> {
+    path.iter().try_fold(root, |current, segment| match current {
+        Element::Object(obj) => obj.get(segment),
+        Element::Array(arr) => segment.parse().ok().and_then(|i: usize| arr.content.get(i)),
+        _ => None,
+    })
+}
+
+/// Mutable variant of [`element_at`]
+pub(crate) fn element_at_mut<'a>(root: &'a mut Element, path: &[String]) -> Option<&'a mut Element> {
+    path.iter().try_fold(root, |current, segment| match current {
+        Element::Object(obj) => obj
+            .content
+            .iter_mut()
+            .find(|m| matches!(m.key.as_ref(), Element::String(k) if &k.content == segment))
+            .map(|m| m.value.as_mut()),
+        Element::Array(arr) => segment
+            .parse()
+            .ok()
+            .and_then(|i: usize| arr.content.get_mut(i)),
+        _ => None,
+    })
+}
+
+/// Visit every object below `root` (including `root`) with its path,
+/// regardless of its element type
+pub(crate) fn walk_objects_mut<F>(root: &mut Element, visit: &mut F)
+where
+    F: FnMut(&[String], &mut ObjectElement),
+{
+    fn walk<F>(element: &mut Element, path: &mut Vec<String>, visit: &mut F)
+    where
+        F: FnMut(&[String], &mut ObjectElement),
+    {
+        match element {
+            Element::Object(obj) => {
+                visit(path, obj);
+                for member in &mut obj.content {
+                    let Element::String(key) = member.key.as_ref() else {
+                        continue;
+                    };
+                    path.push(key.content.clone());
+                    walk(&mut member.value, path, visit);
+                    path.pop();
+                }
+            }
+            Element::Array(arr) => {
+                for (index, item) in arr.content.iter_mut().enumerate() {
+                    path.push(index.to_string());
+                    walk(item, path, visit);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    walk(root, &mut Vec::new(), visit);
+}
@@ -0,0 +1,294 @@
+/*!
+ * Table of contents pass
+ *
+ * Builds a navigable summary of a document for documentation generators:
+ * tag groups with their operations, followed by the component names of each
+ * category. Tags are ordered as declared in the root `tags` list, then in
+ * order of first use; untagged operations go to a default group.
+ *
+ * The result is attached to the root element as `tableOfContents` metadata
+ * and is also available from [`TableOfContentsPass::table_of_contents`].
+ */
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use serde_json::{json, Value};
+
+use crate::element_classifier::OPERATION_KEYS;
+use crate::fold_pass::FoldPass;
+
+/// Group name used for operations without tags
+pub const DEFAULT_TOC_GROUP: &str = "default";
+
+/// An operation entry in the table of contents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocOperation {
+    pub method: String,
+    pub path: String,
+    pub operation_id: Option<String>,
+    pub summary: Option<String>,
+}
+
+/// A tag with the operations it groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocGroup {
+    pub tag: String,
+    pub description: Option<String>,
+    pub operations: Vec<TocOperation>,
+}
+
+/// Navigable summary of a document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableOfContents {
+    pub groups: Vec<TocGroup>,
+    /// Component names by category (`schemas`, `securitySchemes`, ...)
+    pub components: BTreeMap<String, Vec<String>>,
+}
+
+impl TableOfContents {
+    /// Build the table of contents of a document
+    pub fn from_document(document: &Element, default_group: &str) -> Self {
+        let mut toc = Self::default();
+        let Some(root) = document.as_object() else {
+            return toc;
+        };
+
+        if let Some(tags) = root.get("tags").and_then(Element::as_array) {
+            for tag in tags.content.iter().filter_map(Element::as_object) {
+                if let Some(name) = string_field(tag, "name") {
+                    toc.group_mut(&name).description = string_field(tag, "description");
+                }
+            }
+        }
+
+        if let Some(paths) = root.get("paths").and_then(Element::as_object) {
+            for path_member in &paths.content {
+                let (Some(path), Some(path_item)) =
+                    (path_member.key.as_string(), path_member.value.as_object())
+                else {
+                    continue;
+                };
+                for method in OPERATION_KEYS {
+                    let Some(operation) = path_item.get(method).and_then(Element::as_object) else {
+                        continue;
+                    };
+                    let entry = TocOperation {
+                        method: method.to_string(),
+                        path: path.content.clone(),
+                        operation_id: string_field(operation, "operationId"),
+                        summary: string_field(operation, "summary"),
+                    };
+
+                    let tags: Vec<String> = operation
+                        .get("tags")
+                        .and_then(Element::as_array)
+                        .map(|tags| {
+                            tags.content
+                                .iter()
+                                .filter_map(Element::as_string)
+                                .map(|t| t.content.clone())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    if tags.is_empty() {
+                        toc.group_mut(default_group).operations.push(entry);
+                    } else {
+                        for tag in tags {
+                            toc.group_mut(&tag).operations.push(entry.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(components) = root.get("components").and_then(Element::as_object) {
+            for category in &components.content {
+                let (Some(name), Some(entries)) =
+                    (category.key.as_string(), category.value.as_object())
+                else {
+                    continue;
+                };
+                toc.components.insert(
+                    name.content.clone(),
+                    entries
+                        .content
+                        .iter()
+                        .filter_map(|m| m.key.as_string().map(|k| k.content.clone()))
+                        .collect(),
+                );
+            }
+        }
+
+        toc
+    }
+
+    pub fn group(&self, tag: &str) -> Option<&TocGroup> {
+        self.groups.iter().find(|g| g.tag == tag)
+    }
+
+    /// Total number of operation entries (tagged operations count once per tag)
+    pub fn operation_count(&self) -> usize {
+        self.groups.iter().map(|g| g.operations.len()).sum()
+    }
+
+    pub fn to_value(&self) -> Value {
+        let groups: Vec<Value> = self
+            .groups
+            .iter()
+            .map(|group| {
+                json!({
+                    "tag": group.tag,
+                    "description": group.description,
+                    "operations": group.operations.iter().map(|op| json!({
+                        "method": op.method,
+                        "path": op.path,
+                        "operationId": op.operation_id,
+                        "summary": op.summary,
+                    })).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        json!({ "groups": groups, "components": self.components })
+    }
+
+    fn group_mut(&mut self, tag: &str) -> &mut TocGroup {
+        let index = match self.groups.iter().position(|g| g.tag == tag) {
+            Some(index) => index,
+            None => {
+                self.groups.push(TocGroup {
+                    tag: tag.to_string(),
+                    description: None,
+                    operations: Vec::new(),
+                });
+                self.groups.len() - 1
+            }
+        };
+        &mut self.groups[index]
+    }
+}
+
+/// Pass that attaches a table of contents to the root element
+pub struct TableOfContentsPass {
+    name: String,
+    default_group: String,
+    toc: Mutex<Option<TableOfContents>>,
+}
+
+impl TableOfContentsPass {
+    pub fn new() -> Self {
+        Self::with_default_group(DEFAULT_TOC_GROUP)
+    }
+
+    /// Use `name` as the group of untagged operations
+    pub fn with_default_group(name: &str) -> Self {
+        Self {
+            name: "TableOfContents".to_string(),
+            default_group: name.to_string(),
+            toc: Mutex::new(None),
+        }
+    }
+
+    /// Table of contents built by the last run of the pass
+    pub fn table_of_contents(&self) -> Option<TableOfContents> {
+        self.toc.lock().ok().and_then(|toc| toc.clone())
+    }
+}
+
+impl Default for TableOfContentsPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for TableOfContentsPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let toc = TableOfContents::from_document(element, &self.default_group);
+        let value = toc.to_value();
+        if let Ok(mut stored) = self.toc.lock() {
+            *stored = Some(toc);
+        }
+
+        let Element::Object(root) = element else {
+            return None;
+        };
+        if root.meta.properties.get("tableOfContents") == Some(&value) {
+            return None;
+        }
+        let mut root = root.clone();
+        root.meta
+            .properties
+            .insert("tableOfContents".to_string(), value);
+        Some(Element::Object(root))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn string_field(obj: &ObjectElement, key: &str) -> Option<String> {
+    obj.get(key)
+        .and_then(Element::as_string)
+        .map(|s| s.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn petstore() -> Element {
+        let yaml = std::fs::read_to_string("tests/test_data/petstore.yaml")
+            .expect("Failed to read tests/test_data/petstore.yaml");
+        let value: serde_json::Value = serde_yaml::from_str(&yaml).expect("Failed to parse YAML");
+        json_source_to_ast(&value.to_string())
+    }
+
+    #[test]
+    fn test_petstore_table_of_contents() {
+        let pass = TableOfContentsPass::new();
+        let result = pass.apply(&petstore()).expect("root should be annotated");
+        let toc = pass.table_of_contents().unwrap();
+
+        let tags: Vec<&str> = toc.groups.iter().map(|g| g.tag.as_str()).collect();
+        assert_eq!(tags, vec!["pet", "store", "user"]);
+        assert_eq!(toc.group("pet").unwrap().operations.len(), 8);
+        assert_eq!(toc.group("store").unwrap().operations.len(), 4);
+        assert_eq!(toc.group("user").unwrap().operations.len(), 7);
+        assert_eq!(
+            toc.group("pet").unwrap().description.as_deref(),
+            Some("Everything about your Pets")
+        );
+
+        assert_eq!(toc.components["schemas"].len(), 7);
+        assert_eq!(toc.components["requestBodies"], vec!["Pet", "UserArray"]);
+
+        let meta = &result.as_object().unwrap().meta.properties["tableOfContents"];
+        assert_eq!(meta["groups"][0]["tag"], "pet");
+
+        // Running again over the annotated document changes nothing
+        assert!(pass.apply(&result).is_none());
+    }
+
+    #[test]
+    fn test_untagged_operations_use_default_group() {
+        let doc = json_source_to_ast(
+            r#"{ "openapi": "3.0.3", "paths": {
+                "/health": { "get": { "operationId": "health", "responses": {} } },
+                "/pets": { "get": { "tags": ["pets", "public"], "responses": {} } }
+            } }"#,
+        );
+
+        let toc = TableOfContents::from_document(&doc, DEFAULT_TOC_GROUP);
+        let tags: Vec<&str> = toc.groups.iter().map(|g| g.tag.as_str()).collect();
+        assert_eq!(tags, vec!["default", "pets", "public"]);
+        assert_eq!(
+            toc.group("default").unwrap().operations[0].operation_id.as_deref(),
+            Some("health")
+        );
+        assert_eq!(toc.operation_count(), 3);
+    }
+}
@@ -0,0 +1,230 @@
+/*!
+ * Trailing slash path lint
+ *
+ * `/users` and `/users/` are distinct keys of the Paths object, but most
+ * servers route them to the same resource, so declaring both usually hides
+ * a routing bug. This pass warns about path keys that only differ by
+ * trailing slashes. In normalize mode the entries are merged under the key
+ * without the trailing slash, keeping the last one in document order, and
+ * the surviving path item records the original keys in
+ * `trailing-slash-normalized` metadata. The root path `/` is never touched.
+ */
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::extensible_framework::ValidationWarning;
+use crate::fold_pass::FoldPass;
+use crate::passes::support::{annotate_warning, element_at_mut, json_pointer, warning};
+
+/// Warning code reported for paths that only differ by a trailing slash
+pub const TRAILING_SLASH_CODE: &str = "TRAILING_SLASH_DUPLICATE";
+
+/// Metadata key listing the keys merged into a normalized path item
+pub const TRAILING_SLASH_NORMALIZED_KEY: &str = "trailing-slash-normalized";
+
+/// Pass that reports (and optionally merges) paths differing by a trailing slash
+pub struct TrailingSlashPass {
+    name: String,
+    normalize: bool,
+    warnings: Mutex<Vec<ValidationWarning>>,
+}
+
+impl TrailingSlashPass {
+    pub fn new() -> Self {
+        Self {
+            name: "TrailingSlash".to_string(),
+            normalize: false,
+            warnings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Merge the conflicting entries (last wins) instead of only reporting them
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Warnings reported by the last run of the pass
+    pub fn warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.lock().map(|w| w.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for TrailingSlashPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FoldPass for TrailingSlashPass {
+    fn apply(&self, element: &Element) -> Option<Element> {
+        let conflicts = element
+            .as_object()
+            .and_then(|root| root.get("paths"))
+            .and_then(Element::as_object)
+            .map(conflicting_paths)
+            .unwrap_or_default();
+
+        let mut result = element.clone();
+        let mut found = Vec::new();
+        for (normalized, keys) in conflicts {
+            let last = keys[keys.len() - 1].clone();
+            let path = vec!["paths".to_string(), last];
+            let message = format!(
+                "Paths {} at '{}' only differ by a trailing slash",
+                keys.iter().map(|k| format!("'{}'", k)).collect::<Vec<_>>().join(", "),
+                json_pointer(&path[..1])
+            );
+
+            if self.normalize {
+                if let Some(Element::Object(paths)) = element_at_mut(&mut result, &path[..1]) {
+                    merge_paths(paths, &normalized, &keys);
+                }
+            } else if let Some(Element::Object(item)) = element_at_mut(&mut result, &path) {
+                annotate_warning(item, "path", &message);
+            }
+            found.push(warning(TRAILING_SLASH_CODE, message, &path));
+        }
+
+        let changed = !found.is_empty();
+        if let Ok(mut warnings) = self.warnings.lock() {
+            *warnings = found;
+        }
+
+        changed.then_some(result)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Path keys without their trailing slashes; `/` stays as is
+fn normalize_path(key: &str) -> &str {
+    match key.trim_end_matches('/') {
+        "" => key,
+        trimmed => trimmed,
+    }
+}
+
+/// Groups of two or more path keys sharing a normalized form, in document order
+fn conflicting_paths(paths: &ObjectElement) -> BTreeMap<String, Vec<String>> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for key in paths.content.iter().filter_map(|m| m.key.as_string()) {
+        if key.content.starts_with("x-") || key.content == "/" {
+            continue;
+        }
+        groups
+            .entry(normalize_path(&key.content).to_string())
+            .or_default()
+            .push(key.content.clone());
+    }
+    groups.retain(|_, keys| keys.len() > 1);
+    groups
+}
+
+/// Keep the last of `keys` under `normalized` and drop the others
+fn merge_paths(paths: &mut ObjectElement, normalized: &str, keys: &[String]) {
+    let Some(winner) = keys.last() else {
+        return;
+    };
+    let losers = &keys[..keys.len() - 1];
+    paths
+        .content
+        .retain(|m| !matches!(m.key.as_ref(), Element::String(k) if losers.contains(&k.content)));
+
+    for member in &mut paths.content {
+        if matches!(member.key.as_ref(), Element::String(k) if &k.content == winner) {
+            *member.key = Element::String(StringElement::new(normalized));
+            if let Element::Object(item) = member.value.as_mut() {
+                item.meta.properties.insert(
+                    TRAILING_SLASH_NORMALIZED_KEY.to_string(),
+                    Value::Array(keys.iter().cloned().map(Value::String).collect()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r#"{
+        "openapi": "3.0.3",
+        "paths": {
+            "/": { "get": { "operationId": "root" } },
+            "/users": { "get": { "operationId": "listUsers" } },
+            "/users/": { "get": { "operationId": "listUsersSlash" } },
+            "/pets": { "get": { "operationId": "listPets" } }
+        }
+    }"#;
+
+    fn paths(doc: &Element) -> &ObjectElement {
+        doc.as_object()
+            .and_then(|o| o.get("paths"))
+            .and_then(Element::as_object)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_trailing_slash_conflict_warns() {
+        let pass = TrailingSlashPass::new();
+        let result = pass
+            .apply(&json_source_to_ast(DOCUMENT))
+            .expect("conflict should be annotated");
+
+        let warnings = pass.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, TRAILING_SLASH_CODE);
+        assert_eq!(warnings[0].path, vec!["paths", "/users/"]);
+        assert!(paths(&result).has_key("/users"));
+        assert!(paths(&result).has_key("/users/"));
+    }
+
+    #[test]
+    fn test_root_path_not_flagged() {
+        let doc = json_source_to_ast(
+            r#"{ "paths": { "/": { "get": {} }, "/pets": { "get": {} }, "/pets/{id}": { "get": {} } } }"#,
+        );
+        let pass = TrailingSlashPass::new();
+        assert!(pass.apply(&doc).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_document_without_paths_clears_warnings() {
+        let pass = TrailingSlashPass::new();
+        pass.apply(&json_source_to_ast(DOCUMENT)).unwrap();
+        assert_eq!(pass.warnings().len(), 1);
+
+        assert!(pass.apply(&json_source_to_ast(r#"{ "openapi": "3.0.3" }"#)).is_none());
+        assert!(pass.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_normalize_keeps_last_entry() {
+        let result = TrailingSlashPass::new()
+            .with_normalize(true)
+            .apply(&json_source_to_ast(DOCUMENT))
+            .expect("paths should be merged");
+
+        let paths = paths(&result);
+        assert!(!paths.has_key("/users/"));
+        assert!(paths.has_key("/"));
+        let users = paths.get("/users").and_then(Element::as_object).unwrap();
+        assert_eq!(
+            users.get("get").unwrap().to_value()["operationId"],
+            serde_json::json!("listUsersSlash")
+        );
+        assert_eq!(
+            users.meta.properties.get(TRAILING_SLASH_NORMALIZED_KEY),
+            Some(&serde_json::json!(["/users", "/users/"]))
+        );
+    }
+}
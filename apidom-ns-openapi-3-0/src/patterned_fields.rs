@@ -19,6 +19,8 @@ pub struct PatternedFieldsProcessor {
     config: PatternConfig,
     /// Cache for compiled patterns
     pattern_cache: HashMap<String, CompiledPattern>,
+    /// Response keys (`200`, `4XX`, `default`, ...) used by `match_status`
+    status_keys: Vec<String>,
 }
 
 impl fmt::Debug for PatternedFieldsProcessor {
@@ -196,6 +198,7 @@ impl PatternedFieldsProcessor {
             handlers: HashMap::new(),
             config: PatternConfig::default(),
             pattern_cache: HashMap::new(),
+            status_keys: Vec::new(),
         };
         
         // Register default handlers
@@ -263,6 +266,33 @@ impl PatternedFieldsProcessor {
         Err(PatternError::UnsupportedPattern(field_name.to_string()))
     }
 
+    /// Set the response keys of a Responses object, consulted by
+    /// [`PatternedFieldsProcessor::match_status`]
+    pub fn with_status_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.status_keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Response key that handles the status `code`: the exact code if
+    /// declared, otherwise its range (`404` → `4XX`, case-insensitive),
+    /// otherwise `default`
+    pub fn match_status(&self, code: &str) -> Option<String> {
+        let declared = |key: &str| self.status_keys.iter().find(|k| k.eq_ignore_ascii_case(key));
+
+        let exact = declared(code);
+        let range = match code.as_bytes() {
+            [class @ b'1'..=b'5', rest @ ..] if rest.len() == 2 && rest.iter().all(u8::is_ascii_digit) => {
+                declared(&format!("{}XX", *class as char))
+            }
+            _ => None,
+        };
+        exact.or(range).or_else(|| declared("default")).cloned()
+    }
+
     /// Register a custom pattern handler
     pub fn register_handler(&mut self, pattern_type: PatternType, handler: Box<dyn PatternHandler>) {
         self.handlers.insert(pattern_type, handler);
@@ -0,0 +1,112 @@
+//! Source provenance for built elements.
+//!
+//! Before folding, every OpenAPI object of the generic AST is tagged with
+//! `source-pointer` metadata holding its JSON pointer in the document as the
+//! user wrote it. Builders keep object metadata, so the tag travels with the
+//! element through building and decoration and lets tooling map a built node
+//! back to its source location.
+//!
+//! Existing tags are never overwritten: when a reference is replaced by its
+//! target (see [`carry_source_pointer`]) or the tree is re-tagged after
+//! resolution, each element keeps the pointer it had before resolution.
+
+use apidom_ast::fold::Fold;
+use apidom_ast::minim_model::{Element, ObjectElement};
+use serde_json::Value;
+
+use crate::element_classifier::{tag_element_types, walk_openapi_objects_mut, ClassificationOverrides};
+use crate::fold::OpenApiBuilderFolder;
+use crate::passes::support::json_pointer;
+
+/// Metadata key holding an element's JSON pointer in the source document
+pub const SOURCE_POINTER_KEY: &str = "source-pointer";
+
+/// Tag every classifiable object in `document` with its JSON pointer,
+/// keeping tags that are already present
+pub fn annotate_source_pointers(document: &mut Element) {
+    walk_openapi_objects_mut(document, |_, path, obj| {
+        if !obj.meta.properties.contains_key(SOURCE_POINTER_KEY) {
+            obj.meta
+                .properties
+                .insert(SOURCE_POINTER_KEY.to_string(), Value::String(json_pointer(path)));
+        }
+    });
+}
+
+/// Source pointer recorded on `obj`, if any
+pub fn source_pointer(obj: &ObjectElement) -> Option<&str> {
+    obj.meta.properties.get(SOURCE_POINTER_KEY).and_then(Value::as_str)
+}
+
+/// Give `resolved` the source pointer of the reference object it replaces
+pub fn carry_source_pointer(reference: &ObjectElement, resolved: &mut ObjectElement) {
+    if let Some(pointer) = source_pointer(reference) {
+        resolved
+            .meta
+            .properties
+            .insert(SOURCE_POINTER_KEY.to_string(), Value::String(pointer.to_string()));
+    }
+}
+
+/// Classify, tag and fold `document`, returning the built tree with every
+/// OpenAPI object carrying its source pointer
+pub fn build_with_provenance(document: &Element) -> Element {
+    let mut tagged = document.clone();
+    tag_element_types(&mut tagged, &ClassificationOverrides::default());
+    annotate_source_pointers(&mut tagged);
+    OpenApiBuilderFolder::new().fold_element(tagged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::passes::support::{element_at, element_at_mut, pointer_segments};
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": { "/pets/{id}": { "get": {
+            "responses": { "200": {
+                "description": "ok",
+                "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } }
+            } }
+        } } },
+        "components": { "schemas": { "Pet": { "type": "object" } } }
+    }"##;
+
+    fn object_at<'a>(doc: &'a Element, pointer: &str) -> &'a ObjectElement {
+        element_at(doc, &pointer_segments(pointer))
+            .and_then(Element::as_object)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_nested_operation_carries_source_pointer() {
+        let built = build_with_provenance(&json_source_to_ast(DOCUMENT));
+
+        let operation = object_at(&built, "/paths/~1pets~1{id}/get");
+        assert_eq!(operation.element, "operation");
+        assert_eq!(source_pointer(operation), Some("/paths/~1pets~1{id}/get"));
+        assert_eq!(source_pointer(built.as_object().unwrap()), Some(""));
+    }
+
+    #[test]
+    fn test_resolved_reference_keeps_original_pointer() {
+        let mut doc = json_source_to_ast(DOCUMENT);
+        annotate_source_pointers(&mut doc);
+
+        let schema_pointer = "/paths/~1pets~1{id}/get/responses/200/content/application~1json/schema";
+        let reference = object_at(&doc, schema_pointer).clone();
+        let mut target = object_at(&doc, "/components/schemas/Pet").clone();
+        carry_source_pointer(&reference, &mut target);
+
+        let media_type = pointer_segments("/paths/~1pets~1{id}/get/responses/200/content/application~1json");
+        if let Some(Element::Object(obj)) = element_at_mut(&mut doc, &media_type) {
+            obj.set("schema", Element::Object(target));
+        }
+        annotate_source_pointers(&mut doc);
+
+        assert_eq!(source_pointer(object_at(&doc, schema_pointer)), Some(schema_pointer));
+    }
+}
@@ -0,0 +1,361 @@
+//! Reference dependency graph for OpenAPI documents.
+//!
+//! Nodes are component pointers (e.g. `#/components/schemas/Pet`) and edges
+//! are `$ref` usages. A `$ref` found outside of `components` is attributed to
+//! the document root node `#`. The graph can be built in one go with
+//! [`RefGraph::from_element`] or maintained incrementally with
+//! [`RefGraph::update_component`] as single components change.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use apidom_ast::minim_model::Element;
+
+use crate::passes::support::json_pointer;
+
+/// Node that stands for every usage outside of `components`
+pub const ROOT_NODE: &str = "#";
+
+/// A single `$ref` usage
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefEdge {
+    /// Component (or root) containing the reference
+    pub from: String,
+    /// Referenced pointer
+    pub to: String,
+    /// JSON pointer of the object holding the `$ref`
+    pub location: String,
+}
+
+/// Reference dependency graph
+#[derive(Debug, Clone, Default)]
+pub struct RefGraph {
+    nodes: BTreeSet<String>,
+    edges: BTreeSet<RefEdge>,
+}
+
+impl RefGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the graph for a whole document
+    pub fn from_element(document: &Element) -> Self {
+        let mut graph = Self::new();
+        graph.add_node(ROOT_NODE);
+
+        if let Some(components) = document
+            .as_object()
+            .and_then(|o| o.get("components"))
+            .and_then(Element::as_object)
+        {
+            for category in &components.content {
+                let Some(category_name) = category.key.as_string().map(|s| s.content.clone())
+                else {
+                    continue;
+                };
+                let Some(entries) = category.value.as_object() else {
+                    continue;
+                };
+                for entry in &entries.content {
+                    if let Some(name) = entry.key.as_string() {
+                        graph.add_node(&component_pointer(&category_name, &name.content));
+                    }
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        graph.scan(document, &mut path);
+        graph
+    }
+
+    /// Add a node without any edges
+    pub fn add_node(&mut self, pointer: &str) {
+        self.nodes.insert(pointer.to_string());
+    }
+
+    /// Record a `$ref` usage, adding missing nodes
+    pub fn add_edge(&mut self, from: &str, to: &str, location: &str) {
+        self.add_node(from);
+        self.add_node(to);
+        self.edges.insert(RefEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            location: location.to_string(),
+        });
+    }
+
+    /// Drop every edge that originates from `pointer`
+    pub fn remove_edges_from(&mut self, pointer: &str) {
+        self.edges.retain(|edge| edge.from != pointer);
+    }
+
+    /// Remove a node together with its incoming and outgoing edges
+    pub fn remove_node(&mut self, pointer: &str) {
+        self.nodes.remove(pointer);
+        self.edges.retain(|edge| edge.from != pointer && edge.to != pointer);
+    }
+
+    /// Rescan a single component after it changed
+    pub fn update_component(&mut self, category: &str, name: &str, value: &Element) {
+        let pointer = component_pointer(category, name);
+        self.remove_edges_from(&pointer);
+        self.add_node(&pointer);
+
+        let mut path = vec![
+            "components".to_string(),
+            category.to_string(),
+            name.to_string(),
+        ];
+        self.scan(value, &mut path);
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().map(String::as_str)
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &RefEdge> {
+        self.edges.iter()
+    }
+
+    pub fn contains(&self, pointer: &str) -> bool {
+        self.nodes.contains(pointer)
+    }
+
+    /// Pointers referenced from `pointer`
+    pub fn dependencies_of(&self, pointer: &str) -> Vec<String> {
+        let targets: BTreeSet<&String> = self
+            .edges
+            .iter()
+            .filter(|edge| edge.from == pointer)
+            .map(|edge| &edge.to)
+            .collect();
+        targets.into_iter().cloned().collect()
+    }
+
+    /// Nodes that reference `pointer`
+    pub fn dependents_of(&self, pointer: &str) -> Vec<String> {
+        let sources: BTreeSet<&String> = self
+            .edges
+            .iter()
+            .filter(|edge| edge.to == pointer)
+            .map(|edge| &edge.from)
+            .collect();
+        sources.into_iter().cloned().collect()
+    }
+
+    /// Number of `$ref` usages of each component, including unused ones
+    pub fn fan_out(&self) -> BTreeMap<String, usize> {
+        let mut counts: BTreeMap<String, usize> = self
+            .nodes
+            .iter()
+            .filter(|node| node.as_str() != ROOT_NODE)
+            .map(|node| (node.clone(), 0))
+            .collect();
+        for edge in &self.edges {
+            if let Some(count) = counts.get_mut(&edge.to) {
+                *count += 1;
+            }
+        }
+        counts
+    }
+
+    /// Number of `$ref` usages of `pointer`
+    pub fn fan_out_of(&self, pointer: &str) -> usize {
+        self.edges.iter().filter(|edge| edge.to == pointer).count()
+    }
+
+    /// Every node reachable from `pointer`, excluding `pointer` itself unless
+    /// it lies on a cycle
+    pub fn reachable_from(&self, pointer: &str) -> BTreeSet<String> {
+        let adjacency = self.adjacency();
+        let mut seen = BTreeSet::new();
+        let mut stack: Vec<&str> = vec![pointer];
+        while let Some(current) = stack.pop() {
+            for next in adjacency.get(current).into_iter().flatten() {
+                if seen.insert(next.to_string()) {
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Whether any reference cycle exists
+    pub fn is_cyclic(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// One reference cycle, as the list of pointers along it
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Visiting,
+            Done,
+        }
+
+        fn visit<'a>(
+            node: &'a str,
+            adjacency: &BTreeMap<&'a str, Vec<&'a str>>,
+            marks: &mut BTreeMap<&'a str, Mark>,
+            stack: &mut Vec<&'a str>,
+        ) -> Option<Vec<String>> {
+            marks.insert(node, Mark::Visiting);
+            stack.push(node);
+            for &next in adjacency.get(node).into_iter().flatten() {
+                match marks.get(next) {
+                    Some(Mark::Visiting) => {
+                        let start = stack.iter().position(|n| *n == next).unwrap_or(0);
+                        return Some(stack[start..].iter().map(|n| n.to_string()).collect());
+                    }
+                    Some(Mark::Done) => {}
+                    None => {
+                        if let Some(cycle) = visit(next, adjacency, marks, stack) {
+                            return Some(cycle);
+                        }
+                    }
+                }
+            }
+            stack.pop();
+            marks.insert(node, Mark::Done);
+            None
+        }
+
+        let adjacency = self.adjacency();
+        let mut marks = BTreeMap::new();
+        for node in &self.nodes {
+            if !marks.contains_key(node.as_str()) {
+                let mut stack = Vec::new();
+                if let Some(cycle) = visit(node, &adjacency, &mut marks, &mut stack) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+
+    fn adjacency(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for edge in &self.edges {
+            adjacency.entry(&edge.from).or_default().push(&edge.to);
+        }
+        adjacency
+    }
+
+    fn scan(&mut self, element: &Element, path: &mut Vec<String>) {
+        match element {
+            Element::Object(obj) => {
+                if let Some(Element::String(target)) = obj.get("$ref") {
+                    let from = owner_of(path);
+                    self.add_edge(&from, &target.content, &to_pointer(path));
+                }
+                for member in &obj.content {
+                    let Some(key) = member.key.as_string() else {
+                        continue;
+                    };
+                    path.push(key.content.clone());
+                    self.scan(&member.value, path);
+                    path.pop();
+                }
+            }
+            Element::Array(arr) => {
+                for (index, item) in arr.content.iter().enumerate() {
+                    path.push(index.to_string());
+                    self.scan(item, path);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Pointer of a named component, e.g. `#/components/schemas/Pet`
+pub fn component_pointer(category: &str, name: &str) -> String {
+    to_pointer(&["components".to_string(), category.to_string(), name.to_string()])
+}
+
+fn to_pointer(path: &[String]) -> String {
+    format!("#{}", json_pointer(path))
+}
+
+fn owner_of(path: &[String]) -> String {
+    match path {
+        [components, category, name, ..] if components == "components" => {
+            component_pointer(category, name)
+        }
+        _ => ROOT_NODE.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const CYCLIC: &str = r##"{
+        "openapi": "3.0.3",
+        "paths": {
+            "/nodes": { "get": { "responses": { "200": {
+                "description": "ok",
+                "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Node" } } }
+            } } } }
+        },
+        "components": { "schemas": {
+            "Node": { "type": "object", "properties": {
+                "children": { "type": "array", "items": { "$ref": "#/components/schemas/Node" } },
+                "owner": { "$ref": "#/components/schemas/Owner" }
+            } },
+            "Owner": { "type": "object" },
+            "Unused": { "type": "string" }
+        } }
+    }"##;
+
+    #[test]
+    fn test_cyclic_graph() {
+        let graph = RefGraph::from_element(&json_source_to_ast(CYCLIC));
+
+        assert!(graph.is_cyclic());
+        assert_eq!(
+            graph.find_cycle(),
+            Some(vec!["#/components/schemas/Node".to_string()])
+        );
+        assert_eq!(
+            graph.dependents_of("#/components/schemas/Node"),
+            vec!["#".to_string(), "#/components/schemas/Node".to_string()]
+        );
+        assert_eq!(
+            graph.dependents_of("#/components/schemas/Owner"),
+            vec!["#/components/schemas/Node".to_string()]
+        );
+        assert!(graph.dependents_of("#/components/schemas/Unused").is_empty());
+        assert!(graph.contains("#/components/schemas/Unused"));
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let mut graph = RefGraph::from_element(&json_source_to_ast(CYCLIC));
+
+        let replacement = json_source_to_ast(r#"{ "type": "object" }"#);
+        graph.update_component("schemas", "Node", &replacement);
+
+        assert!(!graph.is_cyclic());
+        assert!(graph.dependents_of("#/components/schemas/Owner").is_empty());
+        assert_eq!(
+            graph.reachable_from(ROOT_NODE),
+            BTreeSet::from(["#/components/schemas/Node".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_fan_out_report() {
+        let graph = RefGraph::from_element(&json_source_to_ast(CYCLIC));
+
+        let report = graph.fan_out();
+        assert_eq!(report.get("#/components/schemas/Node"), Some(&2));
+        assert_eq!(report.get("#/components/schemas/Owner"), Some(&1));
+        assert_eq!(report.get("#/components/schemas/Unused"), Some(&0));
+        assert!(!report.contains_key(ROOT_NODE));
+        assert_eq!(graph.fan_out_of("#/components/schemas/Node"), 2);
+    }
+}
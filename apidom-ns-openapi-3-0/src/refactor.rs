@@ -0,0 +1,214 @@
+//! Refactoring utilities for OpenAPI documents.
+
+use apidom_ast::minim_model::*;
+
+use crate::passes::support::walk_objects_mut;
+use crate::ref_graph::component_pointer;
+
+/// Errors raised by refactoring utilities
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RefactorError {
+    #[error("Document has no components.{0} section")]
+    CategoryNotFound(String),
+    #[error("Component not found: {0}")]
+    ComponentNotFound(String),
+    #[error("Component already exists: {0}")]
+    NameCollision(String),
+}
+
+/// Rename `components.<category>.<old_name>` to `new_name` and rewrite every
+/// local `$ref` (and discriminator mapping) pointing at it or into it.
+///
+/// The definition keeps its position in the components map. Returns the
+/// number of references that were rewritten.
+pub fn rename_component(
+    document: &mut Element,
+    category: &str,
+    old_name: &str,
+    new_name: &str,
+) -> Result<usize, RefactorError> {
+    let old_pointer = component_pointer(category, old_name);
+    let new_pointer = component_pointer(category, new_name);
+    if old_name == new_name {
+        return Ok(0);
+    }
+
+    let entries = components_category_mut(document, category)
+        .ok_or_else(|| RefactorError::CategoryNotFound(category.to_string()))?;
+    if entries.has_key(new_name) {
+        return Err(RefactorError::NameCollision(new_pointer));
+    }
+    let definition = entries
+        .content
+        .iter_mut()
+        .find(|m| matches!(m.key.as_ref(), Element::String(k) if k.content == old_name))
+        .ok_or_else(|| RefactorError::ComponentNotFound(old_pointer.clone()))?;
+    if let Element::String(key) = definition.key.as_mut() {
+        key.content = new_name.to_string();
+    }
+
+    Ok(redirect_references(document, &old_pointer, &new_pointer))
+}
+
+/// Merge `components.<category>.<duplicate>` into `canonical`: remove the
+/// duplicate definition and redirect every local `$ref` (and discriminator
+/// mapping) that points at it or into it.
+///
+/// Returns the number of references that were rewritten.
+pub fn merge_component(
+    document: &mut Element,
+    category: &str,
+    duplicate: &str,
+    canonical: &str,
+) -> Result<usize, RefactorError> {
+    let duplicate_pointer = component_pointer(category, duplicate);
+    let canonical_pointer = component_pointer(category, canonical);
+    if duplicate == canonical {
+        return Ok(0);
+    }
+
+    let entries = components_category_mut(document, category)
+        .ok_or_else(|| RefactorError::CategoryNotFound(category.to_string()))?;
+    if !entries.has_key(canonical) {
+        return Err(RefactorError::ComponentNotFound(canonical_pointer));
+    }
+    let before = entries.content.len();
+    entries
+        .content
+        .retain(|m| !matches!(m.key.as_ref(), Element::String(k) if k.content == duplicate));
+    if entries.content.len() == before {
+        return Err(RefactorError::ComponentNotFound(duplicate_pointer));
+    }
+
+    Ok(redirect_references(document, &duplicate_pointer, &canonical_pointer))
+}
+
+fn redirect_references(document: &mut Element, old_pointer: &str, new_pointer: &str) -> usize {
+    let mut rewritten = 0;
+    walk_objects_mut(document, &mut |path, obj| {
+        let in_mapping = path.last().is_some_and(|key| key == "mapping");
+        for member in &mut obj.content {
+            let is_ref = matches!(member.key.as_ref(), Element::String(k) if k.content == "$ref");
+            if !is_ref && !in_mapping {
+                continue;
+            }
+            if let Element::String(value) = member.value.as_mut()
+                && let Some(updated) = rewrite_pointer(&value.content, old_pointer, new_pointer)
+            {
+                value.content = updated;
+                rewritten += 1;
+            }
+        }
+    });
+    rewritten
+}
+
+fn components_category_mut<'a>(
+    document: &'a mut Element,
+    category: &str,
+) -> Option<&'a mut ObjectElement> {
+    let Element::Object(root) = document else {
+        return None;
+    };
+    let components = object_value_mut(root, "components")?;
+    object_value_mut(components, category)
+}
+
+fn object_value_mut<'a>(obj: &'a mut ObjectElement, key: &str) -> Option<&'a mut ObjectElement> {
+    obj.content.iter_mut().find_map(|m| match (m.key.as_ref(), m.value.as_mut()) {
+        (Element::String(k), Element::Object(value)) if k.content == key => Some(value),
+        _ => None,
+    })
+}
+
+fn rewrite_pointer(reference: &str, old_pointer: &str, new_pointer: &str) -> Option<String> {
+    let rest = reference.strip_prefix(old_pointer)?;
+    (rest.is_empty() || rest.starts_with('/')).then(|| format!("{}{}", new_pointer, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r##"{
+        "openapi": "3.0.3",
+        "paths": { "/users": { "get": { "responses": { "200": {
+            "description": "ok",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" } } }
+        } } } } },
+        "components": { "schemas": {
+            "User": { "type": "object", "properties": { "id": { "type": "integer" } } },
+            "UserList": { "type": "array", "items": { "$ref": "#/components/schemas/User" } },
+            "UserId": { "$ref": "#/components/schemas/User/properties/id" },
+            "Pet": { "oneOf": [{ "$ref": "#/components/schemas/UserList" }],
+                     "discriminator": { "propertyName": "kind", "mapping": { "user": "#/components/schemas/User" } } }
+        } }
+    }"##;
+
+    fn schema_keys(doc: &Element) -> Vec<String> {
+        doc.as_object()
+            .and_then(|o| o.get("components"))
+            .and_then(Element::as_object)
+            .and_then(|o| o.get("schemas"))
+            .and_then(Element::as_object)
+            .unwrap()
+            .content
+            .iter()
+            .filter_map(|m| m.key.as_string().map(|k| k.content.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_rename_referenced_schema() {
+        let mut doc = json_source_to_ast(DOCUMENT);
+
+        let count = rename_component(&mut doc, "schemas", "User", "Customer").unwrap();
+
+        assert_eq!(count, 4);
+        assert_eq!(schema_keys(&doc), vec!["Customer", "UserList", "UserId", "Pet"]);
+
+        let serialized = doc.to_value().to_string();
+        assert!(!serialized.contains("#/components/schemas/User\""));
+        assert!(!serialized.contains("#/components/schemas/User/"));
+        assert!(serialized.contains("#/components/schemas/Customer/properties/id"));
+        // Similarly named components are left alone
+        assert!(serialized.contains("#/components/schemas/UserList"));
+    }
+
+    #[test]
+    fn test_rename_collision_errors() {
+        let mut doc = json_source_to_ast(DOCUMENT);
+
+        let err = rename_component(&mut doc, "schemas", "User", "Pet").unwrap_err();
+        assert_eq!(
+            err,
+            RefactorError::NameCollision("#/components/schemas/Pet".to_string())
+        );
+        assert!(schema_keys(&doc).contains(&"User".to_string()));
+
+        assert!(matches!(
+            rename_component(&mut doc, "schemas", "Missing", "Other"),
+            Err(RefactorError::ComponentNotFound(_))
+        ));
+        assert!(matches!(
+            rename_component(&mut doc, "headers", "User", "Other"),
+            Err(RefactorError::CategoryNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_component_redirects_references() {
+        let mut doc = json_source_to_ast(DOCUMENT);
+
+        let count = merge_component(&mut doc, "schemas", "UserList", "User").unwrap();
+
+        assert_eq!(count, 1);
+        assert_eq!(schema_keys(&doc), vec!["User", "UserId", "Pet"]);
+        assert!(!doc.to_value().to_string().contains("UserList"));
+        assert!(matches!(
+            merge_component(&mut doc, "schemas", "UserList", "User"),
+            Err(RefactorError::ComponentNotFound(_))
+        ));
+    }
+}
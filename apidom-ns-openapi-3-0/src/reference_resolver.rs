@@ -28,6 +28,8 @@ pub struct ReferenceResolver {
     allow_local: bool,
     /// Custom resolvers for specific schemes
     custom_resolvers: HashMap<String, Box<dyn CustomResolver>>,
+    /// Loader for documents addressed by the URI part of a reference
+    loader: Option<Arc<dyn DocumentLoader>>,
 }
 
 impl std::fmt::Debug for ReferenceResolver {
@@ -39,6 +41,7 @@ impl std::fmt::Debug for ReferenceResolver {
             .field("allow_remote", &self.allow_remote)
             .field("allow_local", &self.allow_local)
             .field("custom_resolvers", &format!("{} resolvers", self.custom_resolvers.len()))
+            .field("loader", &self.loader.is_some())
             .finish()
     }
 }
@@ -95,6 +98,71 @@ pub trait CustomResolver: Send + Sync {
     fn can_resolve(&self, reference: &str) -> bool;
 }
 
+/// Loads the document a reference's URI part points to
+pub trait DocumentLoader: Send + Sync {
+    /// Load and parse the document at `uri` (the reference without its fragment)
+    fn load(&self, uri: &str) -> Result<Element, LoaderError>;
+}
+
+/// Document loading errors
+#[derive(Debug, thiserror::Error)]
+pub enum LoaderError {
+    #[error("Document not found: {0}")]
+    NotFound(String),
+    #[error("Failed to read document {uri}: {message}")]
+    Io { uri: String, message: String },
+    #[error("Failed to parse document {uri}: {message}")]
+    Parse { uri: String, message: String },
+}
+
+/// [`DocumentLoader`] reading JSON or YAML files, relative to a base directory
+#[derive(Debug, Clone, Default)]
+pub struct FileSystemLoader {
+    base_dir: Option<PathBuf>,
+}
+
+impl FileSystemLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve relative URIs against `base_dir` instead of the working directory
+    pub fn with_base_dir(mut self, base_dir: PathBuf) -> Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+}
+
+impl DocumentLoader for FileSystemLoader {
+    fn load(&self, uri: &str) -> Result<Element, LoaderError> {
+        let relative = uri.strip_prefix("file://").unwrap_or(uri);
+        let path = match &self.base_dir {
+            Some(base_dir) if Path::new(relative).is_relative() => base_dir.join(relative),
+            _ => PathBuf::from(relative),
+        };
+
+        let content = std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => LoaderError::NotFound(uri.to_string()),
+            _ => LoaderError::Io { uri: uri.to_string(), message: e.to_string() },
+        })?;
+
+        let is_yaml = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml") | Some("yml")
+        );
+        if is_yaml {
+            let value: Value = serde_yaml::from_str(&content)
+                .map_err(|e| LoaderError::Parse { uri: uri.to_string(), message: e.to_string() })?;
+            json_to_element(&value)
+                .map_err(|e| LoaderError::Parse { uri: uri.to_string(), message: e.to_string() })
+        } else {
+            serde_json::from_str::<Value>(&content)
+                .map_err(|e| LoaderError::Parse { uri: uri.to_string(), message: e.to_string() })?;
+            Ok(apidom_ast::fold::json_source_to_ast(&content))
+        }
+    }
+}
+
 /// Context for reference resolution
 #[derive(Debug, Clone)]
 pub struct ResolutionContext {
@@ -133,6 +201,8 @@ pub enum ResolverError {
     JsonPointerError(String),
     #[error("Custom resolver error: {0}")]
     CustomError(String),
+    #[error("Document loader error: {0}")]
+    Loader(#[from] LoaderError),
 }
 
 impl ReferenceResolver {
@@ -146,6 +216,7 @@ impl ReferenceResolver {
             allow_remote: true,
             allow_local: true,
             custom_resolvers: HashMap::new(),
+            loader: None,
         }
     }
 
@@ -179,6 +250,13 @@ impl ReferenceResolver {
         self
     }
 
+    /// Load the documents of external references (`./user.yaml#/User`)
+    /// with `loader` instead of the built-in file and HTTP fetching
+    pub fn with_loader(mut self, loader: Arc<dyn DocumentLoader>) -> Self {
+        self.loader = Some(loader);
+        self
+    }
+
     /// Add a custom resolver for a specific scheme
     pub fn with_custom_resolver(mut self, scheme: String, resolver: Box<dyn CustomResolver>) -> Self {
         self.custom_resolvers.insert(scheme, resolver);
@@ -221,6 +299,20 @@ impl ReferenceResolver {
 
     /// Internal reference resolution logic
     async fn resolve_reference_internal(&self, reference: &str, context: &ResolutionContext) -> Result<ResolvedReference, ResolverError> {
+        // With a loader every reference carrying a URI part (including bare
+        // file names like `user.json`) is loaded, except custom schemes
+        if let Some(loader) = &self.loader && !reference.starts_with('#') {
+            let resolution_type = match self.determine_reference_type(reference) {
+                Ok(ResolutionType::Custom(_)) => None,
+                Ok(ResolutionType::Remote) => Some(ResolutionType::Remote),
+                _ => Some(ResolutionType::Local),
+            };
+            if let Some(resolution_type) = resolution_type {
+                self.ensure_allowed(&resolution_type)?;
+                return self.resolve_with_loader(loader.as_ref(), reference, resolution_type, context);
+            }
+        }
+        
         let reference_type = self.determine_reference_type(reference)?;
         
         match reference_type {
@@ -232,6 +324,15 @@ impl ReferenceResolver {
         }
     }
 
+    /// Reject remote or local references when their resolution is disabled
+    fn ensure_allowed(&self, resolution_type: &ResolutionType) -> Result<(), ResolverError> {
+        match resolution_type {
+            ResolutionType::Remote if !self.allow_remote => Err(ResolverError::RemoteDisabled),
+            ResolutionType::Local if !self.allow_local => Err(ResolverError::LocalDisabled),
+            _ => Ok(()),
+        }
+    }
+
     /// Determine the type of reference
     fn determine_reference_type(&self, reference: &str) -> Result<ResolutionType, ResolverError> {
         if reference.starts_with('#') {
@@ -260,11 +361,39 @@ impl ReferenceResolver {
         Err(ResolverError::InvalidReference(reference.to_string()))
     }
 
+    /// Load the document named by the reference's URI part and resolve the
+    /// fragment within it
+    fn resolve_with_loader(
+        &self,
+        loader: &dyn DocumentLoader,
+        reference: &str,
+        resolution_type: ResolutionType,
+        context: &ResolutionContext,
+    ) -> Result<ResolvedReference, ResolverError> {
+        let (uri, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+        if uri.is_empty() {
+            return Err(ResolverError::InvalidReference(reference.to_string()));
+        }
+        let document = loader.load(uri)?;
+        let element = json_pointer_target(&document, &percent_decode(fragment))?.clone();
+
+        Ok(ResolvedReference {
+            element,
+            original_ref: reference.to_string(),
+            resolved_uri: uri.to_string(),
+            metadata: ReferenceMetadata {
+                resolution_type,
+                resolved_at: chrono::Utc::now(),
+                resolution_depth: context.depth,
+                from_cache: false,
+                properties: HashMap::new(),
+            },
+        })
+    }
+
     /// Resolve remote HTTP/HTTPS reference
     async fn resolve_remote(&self, reference: &str, context: &ResolutionContext) -> Result<ResolvedReference, ResolverError> {
-        if !self.allow_remote {
-            return Err(ResolverError::RemoteDisabled);
-        }
+        self.ensure_allowed(&ResolutionType::Remote)?;
 
         let full_url = if reference.starts_with("http://") || reference.starts_with("https://") {
             reference.to_string()
@@ -329,9 +458,7 @@ impl ReferenceResolver {
 
     /// Resolve local file reference
     async fn resolve_local(&self, reference: &str, context: &ResolutionContext) -> Result<ResolvedReference, ResolverError> {
-        if !self.allow_local {
-            return Err(ResolverError::LocalDisabled);
-        }
+        self.ensure_allowed(&ResolutionType::Local)?;
 
         let path = if Path::new(reference).is_absolute() {
             PathBuf::from(reference)
@@ -429,6 +556,19 @@ impl ReferenceResolver {
         })
     }
 
+    /// Resolve an RFC 6901 JSON Pointer against `root` without copying.
+    ///
+    /// Accepts the plain form (`/components/schemas/User`) and the URI
+    /// fragment form (`#/components/schemas/User`, percent-decoded first).
+    /// `~1` and `~0` in a token stand for `/` and `~`, and tokens address
+    /// array items by index. Returns `None` when any token does not match.
+    pub fn resolve_pointer<'a>(&self, root: &'a Element, pointer: &str) -> Option<&'a Element> {
+        match pointer.strip_prefix('#') {
+            Some(fragment) => json_pointer_target(root, &percent_decode(fragment)).ok(),
+            None => json_pointer_target(root, pointer).ok(),
+        }
+    }
+
     /// Get resolved reference from cache
     async fn get_from_cache(&self, reference: &str) -> Option<ResolvedReference> {
         let cache = self.cache.read().await;
@@ -479,6 +619,7 @@ fn json_to_element(json: &Value) -> Result<Element, ResolverError> {
                     meta: MetaElement::default(),
                     attributes: AttributesElement::default(),
                     content: i as f64,
+                    is_integer: true,
                 }))
             } else if let Some(f) = n.as_f64() {
                 Ok(Element::Number(NumberElement {
@@ -486,6 +627,7 @@ fn json_to_element(json: &Value) -> Result<Element, ResolverError> {
                     meta: MetaElement::default(),
                     attributes: AttributesElement::default(),
                     content: f,
+                    is_integer: false,
                 }))
             } else {
                 Err(ResolverError::JsonError("Invalid number".to_string()))
@@ -515,13 +657,20 @@ fn json_to_element(json: &Value) -> Result<Element, ResolverError> {
 
 /// Apply JSON Pointer to an element
 fn apply_json_pointer(element: &Element, pointer: &str) -> Result<Element, ResolverError> {
+    json_pointer_target(element, pointer).cloned()
+}
+
+/// Element addressed by an RFC 6901 JSON Pointer (without the leading `#`)
+fn json_pointer_target<'a>(element: &'a Element, pointer: &str) -> Result<&'a Element, ResolverError> {
     if pointer.is_empty() {
-        return Ok(element.clone());
+        return Ok(element);
+    }
+    if !pointer.starts_with('/') {
+        return Err(ResolverError::JsonPointerError(format!("Pointer must start with '/': {}", pointer)));
     }
 
     let parts: Vec<&str> = pointer.split('/').skip(1).collect(); // Skip empty first part
     let mut current = element;
-    let _owned_element: Option<Element> = None;
 
     for part in parts {
         let unescaped_part = unescape_json_pointer_token(part);
@@ -541,8 +690,10 @@ fn apply_json_pointer(element: &Element, pointer: &str) -> Result<Element, Resol
                 }
             }
             Element::Array(arr) => {
-                let index: usize = unescaped_part.parse()
-                    .map_err(|_| ResolverError::JsonPointerError(format!("Invalid array index: {}", unescaped_part)))?;
+                // RFC 6901 array indices are decimal without leading zeros
+                let canonical = unescaped_part == "0" || !unescaped_part.starts_with('0');
+                let index: usize = unescaped_part.parse().ok().filter(|_| canonical)
+                    .ok_or_else(|| ResolverError::JsonPointerError(format!("Invalid array index: {}", unescaped_part)))?;
                 
                 if index >= arr.content.len() {
                     return Err(ResolverError::JsonPointerError(format!("Array index out of bounds: {}", index)));
@@ -556,7 +707,33 @@ fn apply_json_pointer(element: &Element, pointer: &str) -> Result<Element, Resol
         }
     }
 
-    Ok(current.clone())
+    Ok(current)
+}
+
+/// Decode `%XX` escapes of a URI fragment; malformed escapes are kept as is
+fn percent_decode(fragment: &str) -> String {
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i + 1..i + 3) {
+            Some(hex) if bytes[i] == b'%' => std::str::from_utf8(hex)
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
 }
 
 /// Unescape JSON Pointer token
@@ -661,4 +838,122 @@ mod tests {
         assert_eq!(resolver.determine_reference_type("./schema.json").unwrap(), ResolutionType::Local);
         assert_eq!(resolver.determine_reference_type("file:///path/to/schema.json").unwrap(), ResolutionType::Local);
     }
+
+    fn pointer_document() -> Element {
+        apidom_ast::fold::json_source_to_ast(
+            r#"{
+                "components": { "schemas": { "User": { "type": "object" } } },
+                "servers": [ { "url": "https://a.example" }, { "url": "https://b.example" } ],
+                "paths": { "/users/{id}": { "get": { "x-a~b": "tilde" } } }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn test_resolve_pointer_nested_object_path() {
+        let doc = pointer_document();
+        let resolver = ReferenceResolver::new();
+
+        let user = resolver.resolve_pointer(&doc, "#/components/schemas/User").unwrap();
+        assert_eq!(user.to_value(), serde_json::json!({ "type": "object" }));
+        assert!(std::ptr::eq(resolver.resolve_pointer(&doc, "").unwrap(), &doc));
+        assert!(resolver.resolve_pointer(&doc, "#/components/schemas/Missing").is_none());
+    }
+
+    #[test]
+    fn test_resolve_pointer_array_index_path() {
+        let doc = pointer_document();
+        let resolver = ReferenceResolver::new();
+
+        let url = resolver.resolve_pointer(&doc, "/servers/1/url").unwrap();
+        assert_eq!(url.to_value(), serde_json::json!("https://b.example"));
+        assert!(resolver.resolve_pointer(&doc, "/servers/2").is_none());
+        assert!(resolver.resolve_pointer(&doc, "/servers/01").is_none());
+    }
+
+    #[test]
+    fn test_resolve_pointer_escaped_keys() {
+        let doc = pointer_document();
+        let resolver = ReferenceResolver::new();
+
+        let value = resolver.resolve_pointer(&doc, "#/paths/~1users~1%7Bid%7D/get/x-a~0b").unwrap();
+        assert_eq!(value.to_value(), serde_json::json!("tilde"));
+        assert!(resolver.resolve_pointer(&doc, "/paths/~1users~1{id}/get/x-a~0b").is_some());
+    }
+
+    /// Loader serving documents from memory, counting loads per URI
+    #[derive(Default)]
+    struct MemoryLoader {
+        documents: HashMap<String, String>,
+        loads: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl DocumentLoader for MemoryLoader {
+        fn load(&self, uri: &str) -> Result<Element, LoaderError> {
+            self.loads.lock().unwrap().push(uri.to_string());
+            self.documents
+                .get(uri)
+                .map(|source| apidom_ast::fold::json_source_to_ast(source))
+                .ok_or_else(|| LoaderError::NotFound(uri.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cross_file_reference_with_loader() {
+        let mut loader = MemoryLoader::default();
+        loader.documents.insert(
+            "./schemas/user.json".to_string(),
+            r#"{ "User": { "type": "object", "properties": { "id": { "type": "integer" } } } }"#.to_string(),
+        );
+        let loader = Arc::new(loader);
+        let resolver = ReferenceResolver::new().with_loader(loader.clone());
+
+        let resolved = resolver
+            .resolve_async("./schemas/user.json#/User/properties/id", None)
+            .await
+            .unwrap();
+        assert_eq!(resolved.element.to_value(), serde_json::json!({ "type": "integer" }));
+        assert_eq!(resolved.resolved_uri, "./schemas/user.json");
+        assert_eq!(resolved.metadata.resolution_type, ResolutionType::Local);
+        assert_eq!(*loader.loads.lock().unwrap(), vec!["./schemas/user.json"]);
+
+        let missing = resolver.resolve_async("pet.json#/Pet", None).await;
+        assert!(matches!(missing, Err(ResolverError::Loader(LoaderError::NotFound(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_loader_respects_disabled_local_resolution() {
+        let mut loader = MemoryLoader::default();
+        loader.documents.insert("./user.json".to_string(), r#"{ "User": {} }"#.to_string());
+        let loader = Arc::new(loader);
+        let resolver = ReferenceResolver::new().allow_local(false).with_loader(loader.clone());
+
+        let result = resolver.resolve_async("./user.json#/User", None).await;
+        assert!(matches!(result, Err(ResolverError::LocalDisabled)));
+        assert!(loader.loads.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_loader_respects_disabled_remote_resolution() {
+        let mut loader = MemoryLoader::default();
+        loader.documents.insert("https://example.com/user.json".to_string(), r#"{ "User": {} }"#.to_string());
+        let loader = Arc::new(loader);
+        let resolver = ReferenceResolver::new().allow_remote(false).with_loader(loader.clone());
+
+        let result = resolver.resolve_async("https://example.com/user.json#/User", None).await;
+        assert!(matches!(result, Err(ResolverError::RemoteDisabled)));
+        assert!(loader.loads.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_file_system_loader_reads_yaml() {
+        let dir = std::env::temp_dir().join(format!("apidom-loader-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("user.yaml"), "User:\n  type: object\n").unwrap();
+
+        let loaded = FileSystemLoader::new().with_base_dir(dir.clone()).load("user.yaml");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.unwrap().to_value(), serde_json::json!({ "User": { "type": "object" } }));
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,441 @@
+//! Validation rule registry.
+//!
+//! Every check run by [`ValidationPass`](crate::fold_pass::ValidationPass)
+//! has a stable id (e.g. `OAS3-OP-001`), a default severity and an enable
+//! flag. A [`RuleSet`] lets users disable single rules, change severities or
+//! switch the whole set off.
+
+use std::collections::{BTreeMap, HashSet};
+
+use apidom_ast::minim_model::*;
+
+use crate::element_classifier::{walk_openapi_objects_as, ROOT_ELEMENT_TYPE};
+use crate::extensible_framework::{ErrorSeverity, ValidationError};
+
+pub const RULE_OPENAPI_FIELD: &str = "OAS3-DOC-001";
+pub const RULE_OPENAPI_VERSION: &str = "invalid-openapi-version";
+pub const RULE_INFO_TITLE: &str = "OAS3-INFO-001";
+pub const RULE_INFO_VERSION: &str = "OAS3-INFO-002";
+pub const RULE_PATH_LEADING_SLASH: &str = "OAS3-PATH-001";
+pub const RULE_OPERATION_RESPONSES: &str = "OAS3-OP-001";
+pub const RULE_OPERATION_ID_UNIQUE: &str = "OAS3-OP-002";
+pub const RULE_OPERATION_EMPTY_RESPONSES: &str = "OAS3-OP-003";
+pub const RULE_PATH_PARAMETER_REQUIRED: &str = "OAS3-PARAM-001";
+pub const RULE_RESPONSE_DESCRIPTION: &str = "OAS3-RESP-001";
+pub const RULE_SECURITY_SCHEME_DEFINED: &str = "OAS3-SEC-001";
+
+/// A single validation rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    /// Stable rule id, used as the error code
+    pub id: String,
+    /// Short description of what the rule checks
+    pub description: String,
+    /// Severity of findings reported by this rule
+    pub severity: ErrorSeverity,
+    /// Whether the rule runs
+    pub enabled: bool,
+}
+
+impl Rule {
+    pub fn new(id: &str, description: &str, severity: ErrorSeverity) -> Self {
+        Self {
+            id: id.to_string(),
+            description: description.to_string(),
+            severity,
+            enabled: true,
+        }
+    }
+}
+
+/// Configurable set of validation rules
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSet {
+    rules: BTreeMap<String, Rule>,
+    enabled: bool,
+}
+
+impl RuleSet {
+    /// Create an empty rule set
+    pub fn empty() -> Self {
+        Self {
+            rules: BTreeMap::new(),
+            enabled: true,
+        }
+    }
+
+    /// Rule set with every built-in OpenAPI 3.0 rule enabled
+    pub fn openapi_3_0() -> Self {
+        use ErrorSeverity::*;
+        [
+            Rule::new(RULE_OPENAPI_FIELD, "Document declares the `openapi` field", Critical),
+            Rule::new(RULE_OPENAPI_VERSION, "`openapi` is a 3.0.x version string", Warning),
+            Rule::new(RULE_INFO_TITLE, "Info object has a title", Error),
+            Rule::new(RULE_INFO_VERSION, "Info object has a version", Error),
+            Rule::new(RULE_PATH_LEADING_SLASH, "Path keys start with `/`", Error),
+            Rule::new(RULE_OPERATION_RESPONSES, "Operation declares responses", Error),
+            Rule::new(RULE_OPERATION_ID_UNIQUE, "operationId values are unique", Error),
+            Rule::new(RULE_OPERATION_EMPTY_RESPONSES, "Operation responses are not empty", Warning),
+            Rule::new(RULE_PATH_PARAMETER_REQUIRED, "Path parameters are required", Error),
+            Rule::new(RULE_RESPONSE_DESCRIPTION, "Response has a description", Error),
+            Rule::new(
+                RULE_SECURITY_SCHEME_DEFINED,
+                "Global security requirements name defined security schemes",
+                Error,
+            ),
+        ]
+        .into_iter()
+        .fold(Self::empty(), |set, rule| set.with_rule(rule))
+    }
+
+    /// Add or replace a rule
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rules.insert(rule.id.clone(), rule);
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Rule> {
+        self.rules.get(id)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> {
+        self.rules.values()
+    }
+
+    /// Disable a rule, returning whether it exists
+    pub fn disable(&mut self, id: &str) -> bool {
+        self.set_enabled(id, false)
+    }
+
+    /// Enable a rule, returning whether it exists
+    pub fn enable(&mut self, id: &str) -> bool {
+        self.set_enabled(id, true)
+    }
+
+    /// Override the severity of a rule, returning whether it exists
+    pub fn set_severity(&mut self, id: &str, severity: ErrorSeverity) -> bool {
+        self.rules
+            .get_mut(id)
+            .map(|rule| rule.severity = severity)
+            .is_some()
+    }
+
+    fn set_enabled(&mut self, id: &str, enabled: bool) -> bool {
+        self.rules
+            .get_mut(id)
+            .map(|rule| rule.enabled = enabled)
+            .is_some()
+    }
+
+    /// Switch the whole rule set on or off
+    pub fn set_all_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Whether the rule `id` exists and runs
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.enabled && self.rules.get(id).is_some_and(|rule| rule.enabled)
+    }
+
+    /// Run every enabled built-in rule against a document
+    pub fn check(&self, document: &Element) -> Vec<ValidationError> {
+        let mut findings = Findings { rules: self, errors: Vec::new() };
+        if !self.enabled {
+            return findings.errors;
+        }
+
+        if let Some(root) = document.as_object()
+            && !root.has_key("openapi")
+        {
+            findings.report(
+                RULE_OPENAPI_FIELD,
+                "Document is missing the 'openapi' field".to_string(),
+                &[],
+            );
+        }
+
+        if let Some(root) = document.as_object() {
+            check_openapi_version(root, &mut findings);
+            check_global_security(root, &mut findings);
+        }
+
+        let mut operation_ids: HashSet<String> = HashSet::new();
+        walk_openapi_objects_as(document, ROOT_ELEMENT_TYPE, |element_type, path, obj| {
+            match element_type {
+                "info" => {
+                    for (field, rule) in [("title", RULE_INFO_TITLE), ("version", RULE_INFO_VERSION)] {
+                        if !obj.has_key(field) {
+                            findings.report(rule, format!("Info is missing '{}'", field), path);
+                        }
+                    }
+                }
+                "paths" => {
+                    for key in obj.content.iter().filter_map(|m| m.key.as_string()) {
+                        if !key.content.starts_with('/') && !key.content.starts_with("x-") {
+                            let mut location = path.to_vec();
+                            location.push(key.content.clone());
+                            findings.report(
+                                RULE_PATH_LEADING_SLASH,
+                                format!("Path '{}' must start with '/'", key.content),
+                                &location,
+                            );
+                        }
+                    }
+                }
+                "operation" => {
+                    match obj.get("responses") {
+                        None => findings.report(
+                            RULE_OPERATION_RESPONSES,
+                            "Operation is missing 'responses'".to_string(),
+                            path,
+                        ),
+                        Some(Element::Object(responses)) if responses.content.is_empty() => {
+                            findings.report(
+                                RULE_OPERATION_EMPTY_RESPONSES,
+                                "Operation declares no responses".to_string(),
+                                path,
+                            )
+                        }
+                        _ => {}
+                    }
+                    if let Some(id) = obj.get("operationId").and_then(Element::as_string)
+                        && !operation_ids.insert(id.content.clone())
+                    {
+                        findings.report(
+                            RULE_OPERATION_ID_UNIQUE,
+                            format!("Duplicate operationId '{}'", id.content),
+                            path,
+                        );
+                    }
+                }
+                "parameter" => {
+                    let in_path = obj
+                        .get("in")
+                        .and_then(Element::as_string)
+                        .is_some_and(|s| s.content == "path");
+                    let required = matches!(obj.get("required"), Some(Element::Boolean(b)) if b.content);
+                    if in_path && !required {
+                        findings.report(
+                            RULE_PATH_PARAMETER_REQUIRED,
+                            "Path parameter must set 'required: true'".to_string(),
+                            path,
+                        );
+                    }
+                }
+                "response" if !obj.has_key("description") && !obj.has_key("$ref") => {
+                    findings.report(
+                        RULE_RESPONSE_DESCRIPTION,
+                        "Response is missing 'description'".to_string(),
+                        path,
+                    );
+                }
+                _ => {}
+            }
+        });
+
+        findings.errors
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::openapi_3_0()
+    }
+}
+
+/// The root `openapi` field must be a string matching `3\.0\.\d+`; a
+/// missing field is reported here as well as by [`RULE_OPENAPI_FIELD`]
+fn check_openapi_version(root: &ObjectElement, findings: &mut Findings) {
+    let message = match root.get("openapi") {
+        None => "Missing 'openapi' version, expected 3.0.x".to_string(),
+        Some(Element::String(version)) if is_openapi_3_0_version(&version.content) => return,
+        Some(Element::String(version)) => {
+            format!("Unsupported OpenAPI version '{}', expected 3.0.x", version.content)
+        }
+        Some(_) => "The 'openapi' version must be a string".to_string(),
+    };
+    findings.report(RULE_OPENAPI_VERSION, message, &["openapi".to_string()]);
+}
+
+fn is_openapi_3_0_version(version: &str) -> bool {
+    version
+        .strip_prefix("3.0.")
+        .is_some_and(|patch| !patch.is_empty() && patch.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Every scheme named by the top-level `security` array must be declared in
+/// `components.securitySchemes`
+fn check_global_security(root: &ObjectElement, findings: &mut Findings) {
+    let Some(requirements) = root.get("security").and_then(Element::as_array) else {
+        return;
+    };
+    let defined: HashSet<&str> = root
+        .get("components")
+        .and_then(Element::as_object)
+        .and_then(|components| components.get("securitySchemes"))
+        .and_then(Element::as_object)
+        .map(|schemes| {
+            schemes
+                .content
+                .iter()
+                .filter_map(|m| m.key.as_string())
+                .map(|k| k.content.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for (index, requirement) in requirements.content.iter().enumerate() {
+        let Some(requirement) = requirement.as_object() else {
+            continue;
+        };
+        for name in requirement.content.iter().filter_map(|m| m.key.as_string()) {
+            if !defined.contains(name.content.as_str()) {
+                findings.report(
+                    RULE_SECURITY_SCHEME_DEFINED,
+                    format!(
+                        "Global security requirement references undefined security scheme '{}'",
+                        name.content
+                    ),
+                    &["security".to_string(), index.to_string()],
+                );
+            }
+        }
+    }
+}
+
+struct Findings<'a> {
+    rules: &'a RuleSet,
+    errors: Vec<ValidationError>,
+}
+
+impl Findings<'_> {
+    fn report(&mut self, id: &str, message: String, path: &[String]) {
+        let Some(rule) = self.rules.get(id).filter(|_| self.rules.is_enabled(id)) else {
+            return;
+        };
+        self.errors.push(ValidationError {
+            message,
+            path: path.to_vec(),
+            code: rule.id.clone(),
+            severity: rule.severity.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r#"{
+        "openapi": "3.0.3",
+        "info": { "version": "1.0" },
+        "paths": {
+            "pets": { "get": { "operationId": "listPets" } },
+            "/pets/{id}": { "get": {
+                "operationId": "listPets",
+                "parameters": [ { "name": "id", "in": "path" } ],
+                "responses": { "200": {} }
+            } }
+        }
+    }"#;
+
+    fn codes(errors: &[ValidationError]) -> Vec<&str> {
+        errors.iter().map(|e| e.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_rules_report_findings() {
+        let errors = RuleSet::default().check(&json_source_to_ast(DOCUMENT));
+        let codes = codes(&errors);
+
+        for expected in [
+            RULE_INFO_TITLE,
+            RULE_PATH_LEADING_SLASH,
+            RULE_OPERATION_RESPONSES,
+            RULE_OPERATION_ID_UNIQUE,
+            RULE_PATH_PARAMETER_REQUIRED,
+            RULE_RESPONSE_DESCRIPTION,
+        ] {
+            assert!(codes.contains(&expected), "missing {}", expected);
+        }
+        assert!(!codes.contains(&RULE_OPENAPI_FIELD));
+        assert!(!codes.contains(&RULE_INFO_VERSION));
+
+        let title = errors.iter().find(|e| e.code == RULE_INFO_TITLE).unwrap();
+        assert_eq!(title.path, vec!["info"]);
+        assert_eq!(title.severity, ErrorSeverity::Error);
+    }
+
+    #[test]
+    fn test_disabled_rule_is_skipped() {
+        let mut rules = RuleSet::default();
+        assert!(rules.disable(RULE_OPERATION_RESPONSES));
+        assert!(!rules.disable("OAS3-UNKNOWN-999"));
+
+        let errors = rules.check(&json_source_to_ast(DOCUMENT));
+        let codes = codes(&errors);
+        assert!(!codes.contains(&RULE_OPERATION_RESPONSES));
+        assert!(codes.contains(&RULE_INFO_TITLE));
+
+        rules.set_all_enabled(false);
+        assert!(rules.check(&json_source_to_ast(DOCUMENT)).is_empty());
+    }
+
+    #[test]
+    fn test_severity_override() {
+        let mut rules = RuleSet::default();
+        rules.set_severity(RULE_INFO_TITLE, ErrorSeverity::Info);
+
+        let errors = rules.check(&json_source_to_ast(DOCUMENT));
+        let title = errors.iter().find(|e| e.code == RULE_INFO_TITLE).unwrap();
+        assert_eq!(title.severity, ErrorSeverity::Info);
+    }
+
+    #[test]
+    fn test_openapi_version() {
+        let versions = |source: &str| -> Vec<ValidationError> {
+            RuleSet::default()
+                .check(&json_source_to_ast(source))
+                .into_iter()
+                .filter(|e| e.code == RULE_OPENAPI_VERSION)
+                .collect()
+        };
+
+        assert!(versions(r#"{ "openapi": "3.0.3", "paths": {} }"#).is_empty());
+
+        let swagger = versions(r#"{ "openapi": "2.0", "paths": {} }"#);
+        assert_eq!(swagger.len(), 1);
+        assert_eq!(swagger[0].path, vec!["openapi"]);
+        assert_eq!(swagger[0].severity, ErrorSeverity::Warning);
+
+        let missing = versions(r#"{ "paths": {} }"#);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].path, vec!["openapi"]);
+    }
+
+    #[test]
+    fn test_global_security_requires_defined_scheme() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "info": { "title": "Pets", "version": "1.0" },
+                "security": [ { "apiKey": [] }, { "oauth": ["read"] } ],
+                "paths": {},
+                "components": { "securitySchemes": {
+                    "apiKey": { "type": "apiKey", "name": "X-Key", "in": "header" }
+                } }
+            }"#,
+        );
+
+        let errors = RuleSet::default().check(&doc);
+        let undefined: Vec<&ValidationError> = errors
+            .iter()
+            .filter(|e| e.code == RULE_SECURITY_SCHEME_DEFINED)
+            .collect();
+        assert_eq!(undefined.len(), 1);
+        assert_eq!(undefined[0].path, vec!["security", "1"]);
+        assert!(undefined[0].message.contains("'oauth'"));
+        assert_eq!(undefined[0].severity, ErrorSeverity::Error);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+//! Analysis helpers for schema objects.
+
+use std::collections::HashSet;
+
+use apidom_ast::minim_model::Element;
+
+use crate::passes::support::{element_at, pointer_segments};
+
+/// Resolver for local references of a document, usable with
+/// [`effective_required`]
+pub fn local_ref_resolver(document: &Element) -> impl Fn(&str) -> Option<Element> + '_ {
+    move |reference: &str| {
+        if !reference.starts_with('#') {
+            return None;
+        }
+        element_at(document, &pointer_segments(reference)).cloned()
+    }
+}
+
+/// Required properties of `schema`, including those contributed by `allOf`
+/// members and referenced schemas.
+///
+/// `resolver` maps a `$ref` value to the referenced schema; references it
+/// cannot resolve contribute nothing. Property names are returned in
+/// first-seen order without duplicates. Reference cycles are visited once.
+pub fn effective_required<R>(schema: &Element, resolver: R) -> Vec<String>
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let mut required = Vec::new();
+    let mut visited = HashSet::new();
+    collect_required(schema, &resolver, &mut visited, &mut required);
+    required
+}
+
+fn collect_required<R>(
+    schema: &Element,
+    resolver: &R,
+    visited: &mut HashSet<String>,
+    required: &mut Vec<String>,
+) where
+    R: Fn(&str) -> Option<Element>,
+{
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Element::as_string) {
+        if visited.insert(reference.content.clone())
+            && let Some(target) = resolver(&reference.content)
+        {
+            collect_required(&target, resolver, visited, required);
+        }
+        return;
+    }
+
+    if let Some(names) = obj.get("required").and_then(Element::as_array) {
+        for name in names.content.iter().filter_map(Element::as_string) {
+            if !required.contains(&name.content) {
+                required.push(name.content.clone());
+            }
+        }
+    }
+
+    if let Some(members) = obj.get("allOf").and_then(Element::as_array) {
+        for member in &members.content {
+            collect_required(member, resolver, visited, required);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_effective_required_across_all_of() {
+        let doc = json_source_to_ast(
+            r##"{
+                "components": { "schemas": {
+                    "Identified": { "type": "object", "required": ["id"] },
+                    "Named": { "allOf": [ { "$ref": "#/components/schemas/Identified" } ], "required": ["name"] },
+                    "Pet": {
+                        "allOf": [
+                            { "$ref": "#/components/schemas/Named" },
+                            { "required": ["name", "species"] },
+                            { "$ref": "#/components/schemas/Missing" }
+                        ],
+                        "required": ["owner"]
+                    }
+                } }
+            }"##,
+        );
+        let resolver = local_ref_resolver(&doc);
+        let pet = resolver("#/components/schemas/Pet").unwrap();
+
+        assert_eq!(
+            effective_required(&pet, &resolver),
+            vec!["owner", "name", "id", "species"]
+        );
+    }
+
+    #[test]
+    fn test_effective_required_with_cycle() {
+        let doc = json_source_to_ast(
+            r##"{ "components": { "schemas": {
+                "Node": { "required": ["id"], "allOf": [ { "$ref": "#/components/schemas/Node" } ] }
+            } } }"##,
+        );
+        let resolver = local_ref_resolver(&doc);
+        let node = resolver("#/components/schemas/Node").unwrap();
+
+        assert_eq!(effective_required(&node, resolver), vec!["id"]);
+    }
+}
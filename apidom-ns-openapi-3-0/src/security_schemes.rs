@@ -0,0 +1,174 @@
+//! Enumeration of the security schemes declared by a document.
+
+use apidom_ast::minim_model::{Element, ObjectElement};
+
+use crate::schema_analysis::local_ref_resolver;
+
+/// Type-specific details of a security scheme
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecuritySchemeDetails {
+    /// `apiKey`: parameter name and its location (`query`, `header`, `cookie`)
+    ApiKey {
+        name: Option<String>,
+        location: Option<String>,
+    },
+    /// `http`: authorization scheme (`basic`, `bearer`, ...)
+    Http {
+        scheme: Option<String>,
+        bearer_format: Option<String>,
+    },
+    /// `oauth2`: the flow types declared under `flows`
+    OAuth2 { flows: Vec<String> },
+    /// `openIdConnect`: the discovery URL
+    OpenIdConnect { url: Option<String> },
+    /// Missing or unknown `type`
+    Unknown,
+}
+
+/// A security scheme declared in `components.securitySchemes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecuritySchemeInfo {
+    /// Key of the scheme in `components.securitySchemes`
+    pub name: String,
+    /// Value of the scheme's `type` field
+    pub scheme_type: Option<String>,
+    pub details: SecuritySchemeDetails,
+}
+
+/// List every security scheme of `document` in declaration order.
+///
+/// Entries that are local `$ref`s are reported with the details of their
+/// target; unresolvable entries are reported with unknown details.
+pub fn collect_security_schemes(document: &Element) -> Vec<SecuritySchemeInfo> {
+    let resolver = local_ref_resolver(document);
+    let Some(schemes) = document
+        .as_object()
+        .and_then(|root| root.get("components"))
+        .and_then(Element::as_object)
+        .and_then(|components| components.get("securitySchemes"))
+        .and_then(Element::as_object)
+    else {
+        return Vec::new();
+    };
+
+    schemes
+        .content
+        .iter()
+        .filter_map(|member| {
+            let name = member.key.as_string()?.content.clone();
+            let mut scheme = member.value.as_object().cloned();
+            if let Some(reference) = scheme
+                .as_ref()
+                .and_then(|s| s.get("$ref"))
+                .and_then(Element::as_string)
+            {
+                scheme = resolver(&reference.content).and_then(|t| t.as_object().cloned());
+            }
+            Some(scheme_info(name, scheme.as_ref()))
+        })
+        .collect()
+}
+
+fn scheme_info(name: String, scheme: Option<&ObjectElement>) -> SecuritySchemeInfo {
+    let field = |key: &str| {
+        scheme
+            .and_then(|s| s.get(key))
+            .and_then(Element::as_string)
+            .map(|s| s.content.clone())
+    };
+    let scheme_type = field("type");
+
+    let details = match scheme_type.as_deref() {
+        Some("apiKey") => SecuritySchemeDetails::ApiKey {
+            name: field("name"),
+            location: field("in"),
+        },
+        Some("http") => SecuritySchemeDetails::Http {
+            scheme: field("scheme"),
+            bearer_format: field("bearerFormat"),
+        },
+        Some("oauth2") => SecuritySchemeDetails::OAuth2 {
+            flows: scheme
+                .and_then(|s| s.get("flows"))
+                .and_then(Element::as_object)
+                .map(|flows| {
+                    flows
+                        .content
+                        .iter()
+                        .filter_map(|m| m.key.as_string().map(|k| k.content.clone()))
+                        .filter(|k| !k.starts_with("x-"))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        },
+        Some("openIdConnect") => SecuritySchemeDetails::OpenIdConnect {
+            url: field("openIdConnectUrl"),
+        },
+        _ => SecuritySchemeDetails::Unknown,
+    };
+
+    SecuritySchemeInfo {
+        name,
+        scheme_type,
+        details,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_collect_api_key_and_oauth2() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "components": { "securitySchemes": {
+                    "api_key": { "type": "apiKey", "name": "X-API-Key", "in": "header" },
+                    "petstore_auth": { "type": "oauth2", "flows": {
+                        "implicit": { "authorizationUrl": "https://example.com/auth", "scopes": {} },
+                        "clientCredentials": { "tokenUrl": "https://example.com/token", "scopes": {} }
+                    } }
+                } }
+            }"#,
+        );
+
+        let schemes = collect_security_schemes(&doc);
+        assert_eq!(schemes.len(), 2);
+
+        assert_eq!(schemes[0].name, "api_key");
+        assert_eq!(schemes[0].scheme_type.as_deref(), Some("apiKey"));
+        assert_eq!(
+            schemes[0].details,
+            SecuritySchemeDetails::ApiKey {
+                name: Some("X-API-Key".to_string()),
+                location: Some("header".to_string()),
+            }
+        );
+
+        assert_eq!(schemes[1].name, "petstore_auth");
+        assert_eq!(
+            schemes[1].details,
+            SecuritySchemeDetails::OAuth2 {
+                flows: vec!["implicit".to_string(), "clientCredentials".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_referenced_scheme_resolved() {
+        let doc = json_source_to_ast(
+            r##"{
+                "components": { "securitySchemes": {
+                    "bearer": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+                    "alias": { "$ref": "#/components/securitySchemes/bearer" }
+                } }
+            }"##,
+        );
+
+        let schemes = collect_security_schemes(&doc);
+        assert_eq!(schemes[1].name, "alias");
+        assert_eq!(schemes[1].details, schemes[0].details);
+    }
+}
@@ -0,0 +1,293 @@
+//! Serialization of element trees to JSON or YAML text.
+//!
+//! Both formats are produced from the same intermediate value, so they share
+//! the key ordering, annotation and empty-value omission options.
+
+use apidom_ast::minim_model::{Element, MetaElement, NumberElement};
+use serde_yaml::{Mapping, Value};
+
+/// Member under which element metadata is written when annotations are included
+pub const ANNOTATIONS_KEY: &str = "x-apidom-meta";
+
+/// Document positions whose empty value is kept by default, as JSON pointers
+/// where `*` matches any single segment: `paths` and operation `responses`
+/// are required, an empty `security` list disables authentication and OAuth
+/// flows require `scopes`
+pub const DEFAULT_OMIT_EMPTY_EXEMPT: [&str; 5] = [
+    "/paths",
+    "/paths/*/*/responses",
+    "/security",
+    "/paths/*/*/security",
+    "/components/securitySchemes/*/flows/*/scopes",
+];
+
+/// Output format of [`serialize`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Yaml,
+}
+
+/// Options shared by every output format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Sort object keys alphabetically instead of keeping document order
+    pub sort_keys: bool,
+    /// Write each object's metadata under [`ANNOTATIONS_KEY`]
+    pub include_annotations: bool,
+    /// Indent JSON output (YAML output is always block-formatted)
+    pub pretty: bool,
+    /// Skip object members whose value is `null`, an empty array or an
+    /// empty object (after their own empty members have been skipped)
+    pub omit_empty: bool,
+    /// Positions kept by `omit_empty` even when empty, because the field is
+    /// required or its empty value is meaningful (`security: []` disables
+    /// authentication); see [`DEFAULT_OMIT_EMPTY_EXEMPT`] for the syntax
+    pub omit_empty_exempt: Vec<String>,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            sort_keys: false,
+            include_annotations: false,
+            pretty: true,
+            omit_empty: false,
+            omit_empty_exempt: DEFAULT_OMIT_EMPTY_EXEMPT.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+}
+
+/// Errors raised while serializing
+#[derive(Debug, thiserror::Error)]
+pub enum SerializeError {
+    #[error("JSON serialization failed: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("YAML serialization failed: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+}
+
+/// Serialize `element` as `format`
+pub fn serialize(
+    element: &Element,
+    format: OutputFormat,
+    options: &SerializeOptions,
+) -> Result<String, SerializeError> {
+    let value = to_serializable(element, options, &mut Vec::new())?;
+    Ok(match format {
+        OutputFormat::Json if options.pretty => serde_json::to_string_pretty(&value)?,
+        OutputFormat::Json => serde_json::to_string(&value)?,
+        OutputFormat::Yaml => serde_yaml::to_string(&value)?,
+    })
+}
+
+/// Convert an element into an order-preserving value; `path` holds the
+/// segments leading to `element`
+fn to_serializable(
+    element: &Element,
+    options: &SerializeOptions,
+    path: &mut Vec<String>,
+) -> Result<Value, SerializeError> {
+    Ok(match element {
+        Element::Null(_) | Element::Member(_) => Value::Null,
+        Element::Boolean(b) => Value::Bool(b.content),
+        Element::Number(n) => number_value(n),
+        Element::String(s) => Value::String(s.content.clone()),
+        Element::Ref(r) => Value::String(r.path.clone()),
+        Element::Link(l) => Value::String(l.href.clone()),
+        Element::Custom(_, custom) => serde_yaml::to_value(&custom.content)?,
+        Element::Array(arr) => {
+            let mut items = Vec::with_capacity(arr.content.len());
+            for (index, item) in arr.content.iter().enumerate() {
+                path.push(index.to_string());
+                let value = to_serializable(item, options, path);
+                path.pop();
+                items.push(value?);
+            }
+            Value::Sequence(items)
+        }
+        Element::Object(obj) => {
+            let mut members = Vec::with_capacity(obj.content.len());
+            for member in &obj.content {
+                let key = match member.key.as_ref() {
+                    Element::String(k) => k.content.clone(),
+                    other => other.to_value().to_string(),
+                };
+                path.push(key);
+                let value = to_serializable(&member.value, options, path);
+                let omit = options.omit_empty
+                    && value.as_ref().is_ok_and(is_empty_value)
+                    && !is_exempt(&options.omit_empty_exempt, path);
+                let key = path.pop().unwrap_or_default();
+                if !omit {
+                    members.push((key, value?));
+                }
+            }
+            if options.sort_keys {
+                members.sort_by(|a, b| a.0.cmp(&b.0));
+            }
+            if options.include_annotations && !obj.meta.properties.is_empty() {
+                members.push((ANNOTATIONS_KEY.to_string(), annotations(&obj.meta)?));
+            }
+
+            let mut mapping = Mapping::new();
+            for (key, value) in members {
+                mapping.insert(Value::String(key), value);
+            }
+            Value::Mapping(mapping)
+        }
+    })
+}
+
+/// Whether `path` matches one of the exempt position patterns
+fn is_exempt(patterns: &[String], path: &[String]) -> bool {
+    patterns.iter().any(|pattern| {
+        let segments: Vec<&str> = pattern.split('/').skip(1).collect();
+        segments.len() == path.len()
+            && segments
+                .iter()
+                .zip(path)
+                .all(|(segment, key)| *segment == "*" || segment == key)
+    })
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Sequence(items) => items.is_empty(),
+        Value::Mapping(mapping) => mapping.is_empty(),
+        _ => false,
+    }
+}
+
+/// Numbers parsed as integers are written without a fractional part, the
+/// same way as [`Element::to_json_string`]; `2.0` stays `2.0`
+fn number_value(number: &NumberElement) -> Value {
+    match number.as_i64() {
+        Some(integer) => Value::Number(integer.into()),
+        None => Value::Number(number.content.into()),
+    }
+}
+
+fn annotations(meta: &MetaElement) -> Result<Value, SerializeError> {
+    let mut keys: Vec<&String> = meta.properties.keys().collect();
+    keys.sort();
+
+    let mut mapping = Mapping::new();
+    for key in keys {
+        mapping.insert(
+            Value::String(key.clone()),
+            serde_yaml::to_value(&meta.properties[key])?,
+        );
+    }
+    Ok(Value::Mapping(mapping))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    const DOCUMENT: &str = r#"{
+        "openapi": "3.0.3",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": { "/pets": { "get": {
+            "parameters": [ { "name": "limit", "in": "query", "schema": { "type": "integer", "maximum": 100 } } ],
+            "responses": { "200": { "description": "ok" } }
+        } } }
+    }"#;
+
+    #[test]
+    fn test_json_and_yaml_are_structurally_equivalent() {
+        let doc = json_source_to_ast(DOCUMENT);
+        let options = SerializeOptions::default();
+
+        let json = serialize(&doc, OutputFormat::Json, &options).unwrap();
+        let yaml = serialize(&doc, OutputFormat::Yaml, &options).unwrap();
+
+        let from_json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let from_yaml: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(DOCUMENT).unwrap();
+        assert_eq!(from_json, expected);
+        assert_eq!(from_yaml, expected);
+
+        assert!(json.contains("\"maximum\": 100\n") || json.contains("\"maximum\": 100,"));
+        assert!(yaml.starts_with("openapi: 3.0.3\n"));
+    }
+
+    #[test]
+    fn test_key_order_and_annotations() {
+        let mut doc = json_source_to_ast(r#"{ "b": 1, "a": 2 }"#);
+
+        let kept = serialize(&doc, OutputFormat::Json, &SerializeOptions { pretty: false, ..Default::default() }).unwrap();
+        assert_eq!(kept, r#"{"b":1,"a":2}"#);
+
+        let sorted = SerializeOptions { sort_keys: true, pretty: false, ..Default::default() };
+        assert_eq!(serialize(&doc, OutputFormat::Json, &sorted).unwrap(), r#"{"a":2,"b":1}"#);
+
+        if let Element::Object(obj) = &mut doc {
+            obj.meta.properties.clear();
+            obj.meta.properties.insert("validated".to_string(), serde_json::Value::Bool(true));
+        }
+        let annotated = SerializeOptions { include_annotations: true, ..sorted };
+        assert_eq!(
+            serialize(&doc, OutputFormat::Yaml, &annotated).unwrap(),
+            "a: 2\nb: 1\nx-apidom-meta:\n  validated: true\n"
+        );
+    }
+
+    #[test]
+    fn test_numbers_keep_their_written_form() {
+        let doc = json_source_to_ast(r#"{ "minimum": 2.0, "maximum": 100, "multipleOf": 0.5 }"#);
+        let options = SerializeOptions { pretty: false, ..Default::default() };
+
+        let json = serialize(&doc, OutputFormat::Json, &options).unwrap();
+        assert_eq!(json, r#"{"minimum":2.0,"maximum":100,"multipleOf":0.5}"#);
+        assert_eq!(json, doc.to_json_string());
+    }
+
+    #[test]
+    fn test_omit_empty_keeps_exempt_keys() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "info": { "title": "Pets", "description": null, "version": "1.0" },
+                "security": [],
+                "tags": [],
+                "components": { "schemas": {} }
+            }"#,
+        );
+        let options = SerializeOptions { omit_empty: true, pretty: false, ..Default::default() };
+
+        assert_eq!(
+            serialize(&doc, OutputFormat::Json, &options).unwrap(),
+            r#"{"openapi":"3.0.3","info":{"title":"Pets","version":"1.0"},"security":[]}"#
+        );
+
+        let kept = serialize(&doc, OutputFormat::Json, &SerializeOptions { pretty: false, ..Default::default() }).unwrap();
+        assert!(kept.contains(r#""description":null"#));
+    }
+
+    #[test]
+    fn test_omit_empty_exemptions_match_document_positions() {
+        let doc = json_source_to_ast(
+            r#"{
+                "openapi": "3.0.3",
+                "paths": { "/pets": { "get": { "security": [], "responses": {}, "tags": [] } } },
+                "components": { "schemas": { "Pet": { "properties": { "paths": {}, "security": [] } } } }
+            }"#,
+        );
+        let options = SerializeOptions { omit_empty: true, pretty: false, ..Default::default() };
+
+        assert_eq!(
+            serialize(&doc, OutputFormat::Json, &options).unwrap(),
+            r#"{"openapi":"3.0.3","paths":{"/pets":{"get":{"security":[],"responses":{}}}}}"#
+        );
+
+        let empty_paths = json_source_to_ast(r#"{ "openapi": "3.0.3", "paths": {} }"#);
+        assert_eq!(
+            serialize(&empty_paths, OutputFormat::Json, &options).unwrap(),
+            r#"{"openapi":"3.0.3","paths":{}}"#
+        );
+    }
+}
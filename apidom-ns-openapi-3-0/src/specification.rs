@@ -62,13 +62,54 @@ pub struct VisitorSpec {
 pub struct OpenApiSpecification {
     /// Root visitors mapping
     pub visitors: SpecificationVisitors,
+    /// Visitor for unknown elements, overriding `visitors.value` when set
+    pub fallback: Option<VisitorFn>,
 }
 
 impl OpenApiSpecification {
-    /// Get the number of visitors in this specification
+    /// Route unknown elements to a custom visitor
+    pub fn with_fallback(mut self, visitor: VisitorFn) -> Self {
+        self.fallback = Some(visitor);
+        self
+    }
+
+    /// Set or clear the custom fallback visitor
+    pub fn set_fallback(&mut self, visitor: Option<VisitorFn>) {
+        self.fallback = visitor;
+    }
+
+    /// Visitor applied to unknown elements
+    pub fn fallback_visitor(&self) -> VisitorFn {
+        self.fallback.unwrap_or(self.visitors.value)
+    }
+
+    /// Replace the visitor for an element type such as `"schema"`.
+    /// Unknown element types leave the specification unchanged.
+    pub fn with_visitor(mut self, element_type: &str, visitor: VisitorFn) -> Self {
+        if let Some(spec) = self.visitors.document.objects.get_mut(element_type) {
+            spec.visitor = Some(visitor);
+        }
+        self
+    }
+
+    /// Set the visitor for one fixed field of an element type.
+    /// Unknown element types leave the specification unchanged.
+    pub fn with_fixed_field(mut self, element_type: &str, field: &str, vref: VisitorRef) -> Self {
+        if let Some(spec) = self.visitors.document.objects.get_mut(element_type) {
+            spec.fixed_fields
+                .get_or_insert_with(HashMap::new)
+                .insert(field.to_string(), vref);
+        }
+        self
+    }
+
+    /// Get the number of object visitors populated in this specification
     pub fn visitor_count(&self) -> usize {
-        // Count all the object visitors
-        30 // Approximate count of all OpenAPI object types
+        self.visitors.document.objects
+            .specs()
+            .iter()
+            .filter(|spec| spec.visitor.is_some())
+            .count()
     }
 }
 
@@ -90,42 +131,84 @@ pub struct DocumentVisitors {
     pub extension: VisitorFn,
 }
 
-/// All OpenAPI 3.0 object visitors
-#[derive(Clone)]
-pub struct ObjectVisitors {
-    // Core OpenAPI objects
-    pub open_api: VisitorSpec,
-    pub info: VisitorSpec,
-    pub contact: VisitorSpec,
-    pub license: VisitorSpec,
-    pub server: VisitorSpec,
-    pub server_variable: VisitorSpec,
-    pub components: VisitorSpec,
-    pub paths: VisitorSpec,
-    pub path_item: VisitorSpec,
-    pub operation: VisitorSpec,
-    pub external_documentation: VisitorSpec,
-    pub parameter: VisitorSpec,
-    pub request_body: VisitorSpec,
-    pub media_type: VisitorSpec,
-    pub encoding: VisitorSpec,
-    pub responses: VisitorSpec,
-    pub response: VisitorSpec,
-    pub callback: VisitorSpec,
-    pub example: VisitorSpec,
-    pub link: VisitorSpec,
-    pub header: VisitorSpec,
-    pub tag: VisitorSpec,
-    pub reference: VisitorSpec,
-    pub schema: VisitorSpec,
-    pub json_schema: VisitorSpec,
-    pub json_reference: VisitorSpec,
-    pub discriminator: VisitorSpec,
-    pub xml: VisitorSpec,
-    pub security_scheme: VisitorSpec,
-    pub oauth_flows: VisitorSpec,
-    pub oauth_flow: VisitorSpec,
-    pub security_requirement: VisitorSpec,
+/// Declares [`ObjectVisitors`] together with its lookup tables from one list
+/// of `(element type, reference name, field)` entries, so the field set and
+/// every name mapping stay in sync
+macro_rules! object_visitors {
+    ($(($element_type:literal, $reference_name:literal, $field:ident)),* $(,)?) => {
+        /// All OpenAPI 3.0 object visitors
+        #[derive(Clone)]
+        pub struct ObjectVisitors {
+            $(pub $field: VisitorSpec,)*
+        }
+
+        impl ObjectVisitors {
+            /// Visitor spec for an element type such as `"info"` or `"pathItem"`
+            pub fn get(&self, element_type: &str) -> Option<&VisitorSpec> {
+                match element_type {
+                    $($element_type => Some(&self.$field),)*
+                    _ => None,
+                }
+            }
+
+            /// Mutable visitor spec for an element type such as `"info"` or `"pathItem"`
+            pub fn get_mut(&mut self, element_type: &str) -> Option<&mut VisitorSpec> {
+                match element_type {
+                    $($element_type => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+
+            /// Visitor spec for the name used in visitor references
+            /// (`#/visitors/document/objects/<name>`), such as `"PathItem"`
+            pub fn get_by_reference_name(&self, name: &str) -> Option<&VisitorSpec> {
+                match name {
+                    $($reference_name => Some(&self.$field),)*
+                    _ => None,
+                }
+            }
+
+            /// All object visitor specs, in declaration order
+            pub fn specs(&self) -> Vec<&VisitorSpec> {
+                vec![$(&self.$field),*]
+            }
+        }
+    };
+}
+
+object_visitors! {
+    ("openApi3_0", "OpenApi", open_api),
+    ("info", "Info", info),
+    ("contact", "Contact", contact),
+    ("license", "License", license),
+    ("server", "Server", server),
+    ("serverVariable", "ServerVariable", server_variable),
+    ("components", "Components", components),
+    ("paths", "Paths", paths),
+    ("pathItem", "PathItem", path_item),
+    ("operation", "Operation", operation),
+    ("externalDocumentation", "ExternalDocumentation", external_documentation),
+    ("parameter", "Parameter", parameter),
+    ("requestBody", "RequestBody", request_body),
+    ("mediaType", "MediaType", media_type),
+    ("encoding", "Encoding", encoding),
+    ("responses", "Responses", responses),
+    ("response", "Response", response),
+    ("callback", "Callback", callback),
+    ("example", "Example", example),
+    ("link", "Link", link),
+    ("header", "Header", header),
+    ("tag", "Tag", tag),
+    ("reference", "Reference", reference),
+    ("schema", "Schema", schema),
+    ("jsonSchema", "JSONSchema", json_schema),
+    ("jsonReference", "JSONReference", json_reference),
+    ("discriminator", "Discriminator", discriminator),
+    ("xml", "XML", xml),
+    ("securityScheme", "SecurityScheme", security_scheme),
+    ("oAuthFlows", "OAuthFlows", oauth_flows),
+    ("oAuthFlow", "OAuthFlow", oauth_flow),
+    ("securityRequirement", "SecurityRequirement", security_requirement),
 }
 
 // Visitor function implementations
@@ -191,6 +274,15 @@ fn server_visitor(element: &Element, _folder: Option<&mut dyn Fold>) -> Option<E
     }
 }
 
+/// Servers array visitor
+fn servers_visitor(element: &Element, folder: Option<&mut dyn Fold>) -> Option<Element> {
+    if let Some(servers) = build_and_decorate_servers(element, folder) {
+        Some(Element::Array(servers))
+    } else {
+        Some(element.clone())
+    }
+}
+
 /// Server variable visitor
 fn server_variable_visitor(element: &Element, _folder: Option<&mut dyn Fold>) -> Option<Element> {
     if let Some(built) = build_server_variable(element) {
@@ -423,7 +515,7 @@ fn create_openapi_fixed_fields() -> FixedFieldsMap {
     let mut fields = HashMap::new();
     fields.insert("openapi".to_string(), VisitorRef::Direct(value_visitor));
     fields.insert("info".to_string(), VisitorRef::Reference("#/visitors/document/objects/Info".to_string()));
-    fields.insert("servers".to_string(), VisitorRef::Direct(value_visitor)); // ServersVisitor equivalent
+    fields.insert("servers".to_string(), VisitorRef::Direct(servers_visitor));
     fields.insert("paths".to_string(), VisitorRef::Reference("#/visitors/document/objects/Paths".to_string()));
     fields.insert("components".to_string(), VisitorRef::Reference("#/visitors/document/objects/Components".to_string()));
     fields.insert("security".to_string(), VisitorRef::Direct(value_visitor)); // SecurityVisitor equivalent
@@ -516,7 +608,7 @@ fn create_path_item_fixed_fields() -> FixedFieldsMap {
     fields.insert("head".to_string(), VisitorRef::Reference("#/visitors/document/objects/Operation".to_string()));
     fields.insert("patch".to_string(), VisitorRef::Reference("#/visitors/document/objects/Operation".to_string()));
     fields.insert("trace".to_string(), VisitorRef::Reference("#/visitors/document/objects/Operation".to_string()));
-    fields.insert("servers".to_string(), VisitorRef::Direct(value_visitor)); // ServersVisitor equivalent
+    fields.insert("servers".to_string(), VisitorRef::Direct(servers_visitor));
     fields.insert("parameters".to_string(), VisitorRef::Direct(value_visitor)); // ParametersVisitor equivalent
     fields
 }
@@ -535,7 +627,7 @@ fn create_operation_fixed_fields() -> FixedFieldsMap {
     fields.insert("callbacks".to_string(), VisitorRef::Direct(value_visitor)); // CallbacksVisitor equivalent
     fields.insert("deprecated".to_string(), VisitorRef::Reference("#/visitors/value".to_string()));
     fields.insert("security".to_string(), VisitorRef::Direct(value_visitor)); // SecurityVisitor equivalent
-    fields.insert("servers".to_string(), VisitorRef::Direct(value_visitor)); // ServersVisitor equivalent
+    fields.insert("servers".to_string(), VisitorRef::Direct(servers_visitor));
     fields
 }
 
@@ -789,6 +881,7 @@ fn create_security_requirement_fixed_fields() -> FixedFieldsMap {
 /// Create the complete OpenAPI 3.0 specification
 pub fn create_openapi_specification() -> OpenApiSpecification {
     OpenApiSpecification {
+        fallback: None,
         visitors: SpecificationVisitors {
             value: value_visitor,
             document: DocumentVisitors {
@@ -928,62 +1021,31 @@ pub fn create_openapi_specification() -> OpenApiSpecification {
     }
 }
 
-/// Get visitor function by element type
+/// Get visitor function by element type, or the specification's fallback
+/// visitor for unknown types
 pub fn get_visitor_by_element_type(spec: &OpenApiSpecification, element_type: &str) -> Option<VisitorFn> {
-    match element_type {
-        "openApi3_0" => spec.visitors.document.objects.open_api.visitor,
-        "info" => spec.visitors.document.objects.info.visitor,
-        "contact" => spec.visitors.document.objects.contact.visitor,
-        "license" => spec.visitors.document.objects.license.visitor,
-        "server" => spec.visitors.document.objects.server.visitor,
-        "serverVariable" => spec.visitors.document.objects.server_variable.visitor,
-        "components" => spec.visitors.document.objects.components.visitor,
-        "paths" => spec.visitors.document.objects.paths.visitor,
-        "pathItem" => spec.visitors.document.objects.path_item.visitor,
-        "operation" => spec.visitors.document.objects.operation.visitor,
-        "externalDocumentation" => spec.visitors.document.objects.external_documentation.visitor,
-        "parameter" => spec.visitors.document.objects.parameter.visitor,
-        "requestBody" => spec.visitors.document.objects.request_body.visitor,
-        "mediaType" => spec.visitors.document.objects.media_type.visitor,
-        "encoding" => spec.visitors.document.objects.encoding.visitor,
-        "responses" => spec.visitors.document.objects.responses.visitor,
-        "response" => spec.visitors.document.objects.response.visitor,
-        "callback" => spec.visitors.document.objects.callback.visitor,
-        "example" => spec.visitors.document.objects.example.visitor,
-        "link" => spec.visitors.document.objects.link.visitor,
-        "header" => spec.visitors.document.objects.header.visitor,
-        "tag" => spec.visitors.document.objects.tag.visitor,
-        "reference" => spec.visitors.document.objects.reference.visitor,
-        "schema" => spec.visitors.document.objects.schema.visitor,
-        "jsonSchema" => spec.visitors.document.objects.json_schema.visitor,
-        "jsonReference" => spec.visitors.document.objects.json_reference.visitor,
-        "discriminator" => spec.visitors.document.objects.discriminator.visitor,
-        "xml" => spec.visitors.document.objects.xml.visitor,
-        "securityScheme" => spec.visitors.document.objects.security_scheme.visitor,
-        "oAuthFlows" => spec.visitors.document.objects.oauth_flows.visitor,
-        "oAuthFlow" => spec.visitors.document.objects.oauth_flow.visitor,
-        "securityRequirement" => spec.visitors.document.objects.security_requirement.visitor,
-        _ => Some(spec.visitors.value), // Fallback to value visitor
+    match spec.visitors.document.objects.get(element_type) {
+        Some(visitor_spec) => visitor_spec.visitor,
+        None => Some(spec.fallback_visitor()),
     }
 }
 
 /// Resolve visitor reference (JSON pointer style)
 pub fn resolve_visitor_reference(spec: &OpenApiSpecification, reference: &str) -> Option<VisitorFn> {
-    match reference {
-        "#/visitors/value" => Some(spec.visitors.value),
-        "#/visitors/document/objects/Info" => spec.visitors.document.objects.info.visitor,
-        "#/visitors/document/objects/Contact" => spec.visitors.document.objects.contact.visitor,
-        "#/visitors/document/objects/License" => spec.visitors.document.objects.license.visitor,
-        "#/visitors/document/objects/Paths" => spec.visitors.document.objects.paths.visitor,
-        "#/visitors/document/objects/Components" => spec.visitors.document.objects.components.visitor,
-        "#/visitors/document/objects/ExternalDocumentation" => spec.visitors.document.objects.external_documentation.visitor,
-        "#/visitors/document/objects/Schema" => spec.visitors.document.objects.schema.visitor,
-        "#/visitors/document/objects/Reference" => spec.visitors.document.objects.reference.visitor,
-        "#/visitors/document/objects/Discriminator" => spec.visitors.document.objects.discriminator.visitor,
-        "#/visitors/document/objects/XML" => spec.visitors.document.objects.xml.visitor,
-        // Add more references as needed
-        _ => Some(spec.visitors.value), // Fallback
+    if reference == "#/visitors/value" {
+        return Some(spec.visitors.value);
     }
+    
+    reference
+        .strip_prefix("#/visitors/document/objects/")
+        .and_then(|name| spec.visitors.document.objects.get_by_reference_name(name))
+        .map(|visitor_spec| visitor_spec.visitor)
+        .unwrap_or_else(|| Some(spec.fallback_visitor())) // Fallback
+}
+
+/// Reborrow an optional folder so it can be handed to several visitors in turn
+fn reborrow_folder<'a>(folder: &'a mut Option<&mut dyn Fold>) -> Option<&'a mut dyn Fold> {
+    folder.as_mut().map(|f| &mut **f as &mut dyn Fold)
 }
 
 /// Enhanced visitor application with $ref resolution and fallback support
@@ -991,21 +1053,22 @@ pub fn apply_visitor_with_fallback(
     spec: &OpenApiSpecification,
     element: &Element,
     element_type: &str,
+    mut folder: Option<&mut dyn Fold>,
 ) -> Option<Element> {
     // First, try to get the specific visitor for this element type
     if let Some(visitor) = get_visitor_by_element_type(spec, element_type) {
-        if let Some(result) = visitor(element, None) {
+        if let Some(result) = visitor(element, reborrow_folder(&mut folder)) {
             return Some(result);
         }
     }
     
     // If no specific visitor or visitor failed, check if it's a reference
     if is_reference_element(element) {
-        return resolve_reference_and_apply(spec, element);
+        return resolve_reference_and_apply(spec, element, folder);
     }
     
-    // Fall back to value visitor
-    (spec.visitors.value)(element, None)
+    // Fall back to the configured fallback visitor
+    (spec.fallback_visitor())(element, folder)
 }
 
 /// Check if element is a reference ($ref)
@@ -1027,100 +1090,103 @@ fn is_reference_element(element: &Element) -> bool {
 fn resolve_reference_and_apply(
     spec: &OpenApiSpecification,
     element: &Element,
+    folder: Option<&mut dyn Fold>,
 ) -> Option<Element> {
     // For now, treat all references as Reference objects
     // In a full implementation, you would resolve the reference and apply the appropriate visitor
     if let Some(ref_visitor) = spec.visitors.document.objects.reference.visitor {
-        ref_visitor(element, None)
+        ref_visitor(element, folder)
     } else {
-        (spec.visitors.value)(element, None)
+        (spec.fallback_visitor())(element, folder)
     }
 }
 
-/// Apply fixed fields visitor pattern
+/// Default nesting limit for fixed fields processing
+pub const MAX_FIXED_FIELDS_DEPTH: usize = 32;
+
+/// Apply fixed fields visitor pattern, descending into nested objects up to
+/// [`MAX_FIXED_FIELDS_DEPTH`] levels deep
 pub fn apply_fixed_fields_visitor(
     spec: &OpenApiSpecification,
     element: &Element,
     element_type: &str,
+    folder: Option<&mut dyn Fold>,
+) -> Option<Element> {
+    apply_fixed_fields_visitor_with_depth(spec, element, element_type, MAX_FIXED_FIELDS_DEPTH, folder)
+}
+
+/// Apply fixed fields visitor pattern, descending into nested objects at most
+/// `max_depth` levels deep
+pub fn apply_fixed_fields_visitor_with_depth(
+    spec: &OpenApiSpecification,
+    element: &Element,
+    element_type: &str,
+    max_depth: usize,
+    mut folder: Option<&mut dyn Fold>,
 ) -> Option<Element> {
-    let visitor_spec = match element_type {
-        "openApi3_0" => &spec.visitors.document.objects.open_api,
-        "info" => &spec.visitors.document.objects.info,
-        "contact" => &spec.visitors.document.objects.contact,
-        "license" => &spec.visitors.document.objects.license,
-        "server" => &spec.visitors.document.objects.server,
-        "serverVariable" => &spec.visitors.document.objects.server_variable,
-        "components" => &spec.visitors.document.objects.components,
-        "paths" => &spec.visitors.document.objects.paths,
-        "pathItem" => &spec.visitors.document.objects.path_item,
-        "operation" => &spec.visitors.document.objects.operation,
-        "externalDocumentation" => &spec.visitors.document.objects.external_documentation,
-        "parameter" => &spec.visitors.document.objects.parameter,
-        "requestBody" => &spec.visitors.document.objects.request_body,
-        "mediaType" => &spec.visitors.document.objects.media_type,
-        "encoding" => &spec.visitors.document.objects.encoding,
-        "responses" => &spec.visitors.document.objects.responses,
-        "response" => &spec.visitors.document.objects.response,
-        "callback" => &spec.visitors.document.objects.callback,
-        "example" => &spec.visitors.document.objects.example,
-        "link" => &spec.visitors.document.objects.link,
-        "header" => &spec.visitors.document.objects.header,
-        "tag" => &spec.visitors.document.objects.tag,
-        "reference" => &spec.visitors.document.objects.reference,
-        "schema" => &spec.visitors.document.objects.schema,
-        "discriminator" => &spec.visitors.document.objects.discriminator,
-        "xml" => &spec.visitors.document.objects.xml,
-        "securityScheme" => &spec.visitors.document.objects.security_scheme,
-        "oAuthFlows" => &spec.visitors.document.objects.oauth_flows,
-        "oAuthFlow" => &spec.visitors.document.objects.oauth_flow,
-        "securityRequirement" => &spec.visitors.document.objects.security_requirement,
-        _ => return (spec.visitors.value)(element, None),
+    let Some(visitor_spec) = spec.visitors.document.objects.get(element_type) else {
+        return (spec.fallback_visitor())(element, folder);
     };
     
     // Apply main visitor first
     let mut result = if let Some(visitor) = visitor_spec.visitor {
-        visitor(element, None)?
+        visitor(element, reborrow_folder(&mut folder))?
     } else {
         element.clone()
     };
     
     // Then apply fixed fields processing if available
     if let Some(ref fixed_fields) = visitor_spec.fixed_fields {
-        result = apply_fixed_fields_processing(spec, result, fixed_fields)?;
+        result = apply_fixed_fields_processing(spec, result, fixed_fields, max_depth, folder)?;
     }
     
     Some(result)
 }
 
-/// Apply fixed fields processing to an element
+/// Apply fixed fields processing to an element; member values that are
+/// themselves objects with fixed fields are processed recursively while
+/// `depth` allows
 fn apply_fixed_fields_processing(
     spec: &OpenApiSpecification,
     mut element: Element,
     fixed_fields: &FixedFieldsMap,
+    depth: usize,
+    mut folder: Option<&mut dyn Fold>,
 ) -> Option<Element> {
     if let Element::Object(ref mut obj) = element {
         for member in &mut obj.content {
             if let Element::String(key) = &*member.key {
                 if let Some(visitor_ref) = fixed_fields.get(&key.content) {
-                    let processed_value = match visitor_ref {
+                    let (mut processed_value, nested_fields) = match visitor_ref {
                         VisitorRef::Direct(visitor_fn) => {
-                            visitor_fn(&*member.value, None)?
+                            (visitor_fn(&*member.value, reborrow_folder(&mut folder))?, None)
                         }
                         VisitorRef::Reference(reference) => {
-                            if let Some(visitor_fn) = resolve_visitor_reference(spec, reference) {
-                                visitor_fn(&*member.value, None)?
+                            let value = if let Some(visitor_fn) = resolve_visitor_reference(spec, reference) {
+                                visitor_fn(&*member.value, reborrow_folder(&mut folder))?
                             } else {
                                 (*member.value).clone()
-                            }
+                            };
+                            let nested_fields = reference
+                                .strip_prefix("#/visitors/document/objects/")
+                                .and_then(|name| spec.visitors.document.objects.get_by_reference_name(name))
+                                .and_then(|visitor_spec| visitor_spec.fixed_fields.as_ref());
+                            (value, nested_fields)
                         }
                         VisitorRef::Nested(nested_spec) => {
-                            if let Some(visitor_fn) = nested_spec.visitor {
-                                visitor_fn(&*member.value, None)?
+                            let value = if let Some(visitor_fn) = nested_spec.visitor {
+                                visitor_fn(&*member.value, reborrow_folder(&mut folder))?
                             } else {
                                 (*member.value).clone()
-                            }
+                            };
+                            (value, nested_spec.fixed_fields.as_ref())
                         }
                     };
+                    if let Some(nested_fields) = nested_fields
+                        && depth > 0
+                    {
+                        processed_value = apply_fixed_fields_processing(spec, processed_value, nested_fields, depth - 1, reborrow_folder(&mut folder))?;
+                    }
                     *member.value = processed_value;
                 }
             }
@@ -1178,6 +1244,158 @@ mod tests {
         assert!(unknown_ref.is_some());
     }
 
+    fn mark_processed(element: &Element, _folder: Option<&mut dyn Fold>) -> Option<Element> {
+        let mut element = element.clone();
+        if let Element::String(s) = &mut element {
+            s.content.push_str(" (processed)");
+        }
+        Some(element)
+    }
+
+    fn path_item_with_default_response() -> Element {
+        let mut response = ObjectElement::new();
+        response.set("description", Element::String(StringElement::new("OK")));
+        let mut responses = ObjectElement::new();
+        responses.set("default", Element::Object(response));
+        let mut operation = ObjectElement::new();
+        operation.set("responses", Element::Object(responses));
+        let mut path_item = ObjectElement::new();
+        path_item.set("get", Element::Object(operation));
+        Element::Object(path_item)
+    }
+
+    fn default_response_description(path_item: &Element) -> Option<String> {
+        let Element::Object(path_item) = path_item else { return None };
+        let Some(Element::Object(operation)) = path_item.get("get") else { return None };
+        let Some(Element::Object(responses)) = operation.get("responses") else { return None };
+        let Some(Element::Object(response)) = responses.get("default") else { return None };
+        match response.get("description") {
+            Some(Element::String(description)) => Some(description.content.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_fixed_fields_processing_recurses_into_nested_objects() {
+        let mut spec = create_openapi_specification();
+        spec.visitors.document.objects.response.fixed_fields
+            .as_mut()
+            .unwrap()
+            .insert("description".to_string(), VisitorRef::Direct(mark_processed));
+        
+        let result = apply_fixed_fields_visitor(&spec, &path_item_with_default_response(), "pathItem", None).unwrap();
+        assert_eq!(default_response_description(&result).as_deref(), Some("OK (processed)"));
+        
+        // pathItem -> get -> responses -> default -> description needs three
+        // levels below the path item
+        let shallow = apply_fixed_fields_visitor_with_depth(&spec, &path_item_with_default_response(), "pathItem", 2, None).unwrap();
+        assert_eq!(default_response_description(&shallow).as_deref(), Some("OK"));
+    }
+
+    #[test]
+    fn test_visitor_count_counts_populated_object_visitors() {
+        let mut spec = create_openapi_specification();
+        assert_eq!(spec.visitor_count(), 32);
+        
+        spec.visitors.document.objects.xml.visitor = None;
+        assert_eq!(spec.visitor_count(), 31);
+    }
+
+    fn custom_info_visitor(element: &Element, _folder: Option<&mut dyn Fold>) -> Option<Element> {
+        Some(element.clone())
+    }
+
+    #[test]
+    fn test_amend_specification_visitors() {
+        let spec = create_openapi_specification()
+            .with_visitor("info", custom_info_visitor)
+            .with_fixed_field("info", "x-logo", VisitorRef::Direct(custom_info_visitor))
+            .with_visitor("unknownType", custom_info_visitor);
+        
+        let info = get_visitor_by_element_type(&spec, "info").unwrap();
+        assert!(std::ptr::fn_addr_eq(info, custom_info_visitor as VisitorFn));
+        
+        let info_fields = spec.visitors.document.objects.info.fixed_fields.as_ref().unwrap();
+        assert!(matches!(info_fields.get("x-logo"), Some(VisitorRef::Direct(_))));
+        assert!(info_fields.contains_key("title"));
+        
+        assert!(spec.visitors.document.objects.get("unknownType").is_none());
+        assert_eq!(spec.visitor_count(), 32);
+    }
+
+    #[derive(Default)]
+    struct CountingFold {
+        strings: usize,
+    }
+
+    impl Fold for CountingFold {
+        fn fold_string_element(&mut self, element: StringElement) -> Element {
+            self.strings += 1;
+            Element::String(element)
+        }
+    }
+
+    #[test]
+    fn test_fixed_fields_visitor_passes_folder_to_visitors() {
+        let spec = create_openapi_specification();
+        let mut info = ObjectElement::new();
+        info.set("title", Element::String(StringElement::new("Petstore")));
+        info.set("version", Element::String(StringElement::new("1.0.0")));
+        let mut doc = ObjectElement::new();
+        doc.set("openapi", Element::String(StringElement::new("3.0.3")));
+        doc.set("info", Element::Object(info));
+        
+        let mut folder = CountingFold::default();
+        let result = apply_fixed_fields_visitor(&spec, &Element::Object(doc.clone()), "openApi3_0", Some(&mut folder));
+        assert!(result.is_some());
+        assert!(folder.strings > 0);
+        
+        let mut folder = CountingFold::default();
+        apply_visitor_with_fallback(&spec, &Element::Object(doc), "openApi3_0", Some(&mut folder)).unwrap();
+        assert!(folder.strings > 0);
+    }
+
+    #[test]
+    fn test_root_servers_are_built() {
+        let spec = create_openapi_specification();
+        let mut servers = ArrayElement::new_empty();
+        for url in ["https://api.example.com", "https://staging.example.com"] {
+            let mut server = ObjectElement::new();
+            server.set("url", Element::String(StringElement::new(url)));
+            servers.content.push(Element::Object(server));
+        }
+        let mut doc = ObjectElement::new();
+        doc.set("openapi", Element::String(StringElement::new("3.0.3")));
+        doc.set("servers", Element::Array(servers));
+        
+        let result = apply_fixed_fields_visitor(&spec, &Element::Object(doc), "openApi3_0", None).unwrap();
+        
+        let servers = result.as_object().unwrap().get("servers").unwrap().as_array().unwrap();
+        assert_eq!(servers.content.len(), 2);
+        for server in &servers.content {
+            assert_eq!(server.as_object().unwrap().element, "server");
+        }
+    }
+
+    #[test]
+    fn test_resolve_visitor_reference_covers_object_visitors() {
+        let spec = create_openapi_specification();
+        let objects = &spec.visitors.document.objects;
+        let expected = [
+            ("Operation", objects.operation.visitor),
+            ("Response", objects.response.visitor),
+            ("Parameter", objects.parameter.visitor),
+            ("Schema", objects.schema.visitor),
+        ];
+        
+        for (name, visitor) in expected {
+            let reference = format!("#/visitors/document/objects/{}", name);
+            let resolved = resolve_visitor_reference(&spec, &reference).unwrap();
+            assert!(std::ptr::fn_addr_eq(resolved, visitor.unwrap()), "{} resolved to the wrong visitor", name);
+            assert!(!std::ptr::fn_addr_eq(resolved, spec.fallback_visitor()), "{} resolved to the fallback", name);
+        }
+    }
+
     #[test]
     fn test_visitor_functions() {
         let spec = create_openapi_specification();
@@ -1261,4 +1479,37 @@ mod tests {
         assert!(objects.json_schema.visitor.is_some());
         assert!(objects.json_reference.visitor.is_some());
     }
-} 
\ No newline at end of file
+
+    fn tag_unknown_visitor(element: &Element, _folder: Option<&mut dyn Fold>) -> Option<Element> {
+        let mut tagged = element.clone();
+        if let Element::Object(obj) = &mut tagged {
+            obj.meta.properties.insert("preserved-unknown".to_string(), serde_json::Value::Bool(true));
+        }
+        Some(tagged)
+    }
+
+    #[test]
+    fn test_custom_fallback_visitor() {
+        let spec = create_openapi_specification().with_fallback(tag_unknown_visitor);
+
+        let mut obj = ObjectElement::new();
+        obj.set_element_type("vendorWidget");
+        obj.set("name", Element::String(StringElement::new("widget")));
+        let element = Element::Object(obj);
+
+        let result = apply_visitor_with_fallback(&spec, &element, "vendorWidget", None).unwrap();
+        let result = result.as_object().unwrap();
+        assert_eq!(
+            result.meta.properties.get("preserved-unknown"),
+            Some(&serde_json::Value::Bool(true))
+        );
+        assert!(result.has_key("name"));
+
+        // Known element types keep their own visitors
+        let mut info = ObjectElement::new();
+        info.set("title", Element::String(StringElement::new("API")));
+        info.set("version", Element::String(StringElement::new("1.0")));
+        let built = apply_visitor_with_fallback(&spec, &Element::Object(info), "info", None).unwrap();
+        assert!(!built.as_object().unwrap().meta.properties.contains_key("preserved-unknown"));
+    }
+}
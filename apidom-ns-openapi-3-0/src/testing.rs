@@ -0,0 +1,134 @@
+//! Test helpers shared by the crate's tests and by downstream crates.
+//!
+//! Available in this crate's own tests and, for other crates, behind the
+//! `test-utils` feature.
+
+use apidom_ast::fold::{json_source_to_ast, Fold};
+use apidom_ast::minim_model::Element;
+use serde_json::Value;
+
+use crate::fold::OpenApiBuilderFolder;
+use crate::passes::support::json_pointer;
+use crate::serializer::{serialize, OutputFormat, SerializeOptions};
+
+/// Differences reported in a failure message before the rest are elided
+const MAX_REPORTED_DIFFERENCES: usize = 20;
+
+/// Parse, fold, serialize and reparse `source`, asserting nothing was lost.
+///
+/// Panics with the list of differing JSON pointers when the serialized
+/// document no longer matches `source`, or when folding the reparsed
+/// document produces a different tree than folding the original.
+pub fn assert_roundtrip(source: &str) {
+    let expected: Value = serde_json::from_str(source).expect("round-trip source must be valid JSON");
+
+    let built = build(source);
+    let serialized = serialize(&built, OutputFormat::Json, &SerializeOptions::default())
+        .expect("built document should serialize");
+    let rebuilt = build(&serialized);
+
+    let reparsed: Value = serde_json::from_str(&serialized).expect("serialized document should be valid JSON");
+    assert_no_differences("source -> serialized", &expected, &reparsed);
+    assert_no_differences("built -> rebuilt", &built.to_value(), &rebuilt.to_value());
+}
+
+/// JSON pointers at which `left` and `right` differ, with both values
+pub fn structural_diff(left: &Value, right: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    collect_differences(left, right, String::new(), &mut differences);
+    differences
+}
+
+fn build(source: &str) -> Element {
+    OpenApiBuilderFolder::new().fold_element(json_source_to_ast(source))
+}
+
+fn assert_no_differences(stage: &str, left: &Value, right: &Value) {
+    let differences = structural_diff(left, right);
+    if differences.is_empty() {
+        return;
+    }
+
+    let mut report: Vec<String> = differences
+        .iter()
+        .take(MAX_REPORTED_DIFFERENCES)
+        .map(|d| format!("  {}", d))
+        .collect();
+    if differences.len() > MAX_REPORTED_DIFFERENCES {
+        report.push(format!("  ... and {} more", differences.len() - MAX_REPORTED_DIFFERENCES));
+    }
+    panic!(
+        "round-trip mismatch ({}), {} difference(s):\n{}",
+        stage,
+        differences.len(),
+        report.join("\n")
+    );
+}
+
+fn collect_differences(left: &Value, right: &Value, pointer: String, out: &mut Vec<String>) {
+    match (left, right) {
+        (Value::Object(l), Value::Object(r)) => {
+            for (key, value) in l {
+                let child = format!("{}{}", pointer, json_pointer(std::slice::from_ref(key)));
+                match r.get(key) {
+                    Some(other) => collect_differences(value, other, child, out),
+                    None => out.push(format!("{}: removed (was {})", display(&child), value)),
+                }
+            }
+            for (key, value) in r {
+                if !l.contains_key(key) {
+                    let child = format!("{}{}", pointer, json_pointer(std::slice::from_ref(key)));
+                    out.push(format!("{}: added ({})", display(&child), value));
+                }
+            }
+        }
+        (Value::Array(l), Value::Array(r)) if l.len() == r.len() => {
+            for (index, (a, b)) in l.iter().zip(r).enumerate() {
+                collect_differences(a, b, format!("{}/{}", pointer, index), out);
+            }
+        }
+        (Value::Number(l), Value::Number(r)) if l.as_f64() == r.as_f64() => {}
+        _ if left == right => {}
+        _ => out.push(format!("{}: {} != {}", display(&pointer), left, right)),
+    }
+}
+
+fn display(pointer: &str) -> &str {
+    if pointer.is_empty() { "/" } else { pointer }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_petstore_roundtrip() {
+        let yaml = std::fs::read_to_string("tests/test_data/petstore.yaml")
+            .expect("Failed to read tests/test_data/petstore.yaml");
+        let value: Value = serde_yaml::from_str(&yaml).expect("Failed to parse YAML");
+        assert_roundtrip(&value.to_string());
+    }
+
+    #[test]
+    fn test_structural_diff_reports_pointers() {
+        let left = json!({ "info": { "title": "Pets" }, "tags": ["a", "b"], "a/b": 1 });
+        let right = json!({ "info": { "title": "Cats" }, "tags": ["a", "b"], "extra": true });
+
+        assert_eq!(
+            structural_diff(&left, &right),
+            vec![
+                r#"/a~1b: removed (was 1)"#,
+                r#"/info/title: "Pets" != "Cats""#,
+                r#"/extra: added (true)"#,
+            ]
+        );
+        assert!(structural_diff(&json!({ "n": 1 }), &json!({ "n": 1.0 })).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "round-trip mismatch")]
+    fn test_mismatch_panics() {
+        assert_no_differences("test", &json!([1]), &json!([2]));
+    }
+}
@@ -4,7 +4,7 @@ use apidom_ns_openapi_3_0::builder::components_builder::build_and_decorate_compo
 use apidom_ns_openapi_3_0::builder::info_builder::build_and_decorate_info;
 use apidom_ns_openapi_3_0::builder::paths_builder::build_and_decorate_paths;
 use apidom_ast::minim_model::*;
-use apidom_ast::fold::{json_source_to_ast, JsonFolder};
+use apidom_ast::fold::{json_source_to_ast, yaml_source_to_ast, JsonFolder};
 use apidom_cst::CstParser;
 use serde_json;
 use serde_yaml;
@@ -291,6 +291,30 @@ fn test_petstore_validation() {
     }
 }
 
+#[test]
+fn test_petstore_yaml_source_to_ast() {
+    let yaml = fs::read_to_string("tests/test_data/petstore.yaml")
+        .expect("Failed to read tests/test_data/petstore.yaml");
+
+    let json_value: serde_json::Value = serde_yaml::from_str(&yaml)
+        .expect("Failed to convert YAML to JSON");
+    let from_json = json_source_to_ast(&json_value.to_string());
+    let from_yaml = yaml_source_to_ast(&yaml);
+
+    let (Element::Object(json_root), Element::Object(yaml_root)) = (&from_json, &from_yaml) else {
+        panic!("Expected object roots");
+    };
+    for field in ["openapi", "info", "paths", "components"] {
+        assert_eq!(
+            yaml_root.get(field).map(Element::to_value),
+            json_root.get(field).map(Element::to_value),
+            "root field '{}' differs",
+            field
+        );
+    }
+    assert_eq!(from_yaml.to_value(), from_json.to_value());
+}
+
 // Helper function to count $ref references in an element
 fn count_references(element: &Element) -> usize {
     match element {
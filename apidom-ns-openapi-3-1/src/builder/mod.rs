@@ -0,0 +1,4 @@
+pub mod path_item_builder;
+pub mod reference_builder;
+pub mod schema_builder;
+pub mod webhooks_builder;
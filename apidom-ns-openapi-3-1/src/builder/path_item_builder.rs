@@ -0,0 +1,147 @@
+//! OpenAPI 3.1 PathItem builder
+//!
+//! A 3.1 path item may hold a `$ref` next to other fields. Unlike 3.0, where
+//! siblings of `$ref` are ignored, the sibling `summary` and `description`
+//! are kept while building and take precedence over the referenced path
+//! item's values when the reference is merged in.
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::elements::path_item::PathItemElement;
+
+/// Fields of a referencing path item that override the referenced one
+pub const PATH_ITEM_OVERRIDE_FIELDS: [&str; 2] = ["summary", "description"];
+
+/// Build a PathItemElement, keeping every member including `$ref` siblings
+pub fn build_path_item(element: &Element) -> Option<PathItemElement> {
+    let mut path_item = PathItemElement::new(element.as_object()?.clone());
+
+    if let Some(reference) = path_item.r#ref().map(|r| r.content.clone()) {
+        path_item.base.add_class("reference-element");
+        path_item
+            .base
+            .meta
+            .properties
+            .insert("referenced-element".to_string(), Value::String("pathItem".to_string()));
+        path_item
+            .base
+            .meta
+            .properties
+            .insert("reference-path".to_string(), Value::String(reference));
+    }
+
+    Some(path_item)
+}
+
+/// Merge a referencing path item with the path item its `$ref` points to.
+///
+/// The result holds the members of `target`, with sibling `summary` and
+/// `description` of `path_item` replacing the target's values. Other
+/// siblings are only added when the target does not define them. The
+/// original reference is kept in `ref-origin` metadata.
+pub fn merge_referenced_path_item(path_item: &PathItemElement, target: &Element) -> Option<PathItemElement> {
+    let reference = path_item.r#ref()?.content.clone();
+    let mut merged = target.as_object()?.clone();
+
+    for member in &path_item.base.content {
+        let Element::String(key) = member.key.as_ref() else {
+            continue;
+        };
+        if key.content == "$ref" {
+            continue;
+        }
+        if PATH_ITEM_OVERRIDE_FIELDS.contains(&key.content.as_str()) || !merged.has_key(&key.content) {
+            merged.set(&key.content, member.value.as_ref().clone());
+        }
+    }
+
+    merged
+        .meta
+        .properties
+        .insert("ref-origin".to_string(), Value::String(reference));
+    Some(PathItemElement::new(merged))
+}
+
+/// Build a PathItemElement and merge in its `$ref` target.
+///
+/// `resolver` maps a `$ref` value to the referenced path item. When it
+/// cannot resolve the reference, the unmerged path item is returned.
+pub fn build_and_resolve_path_item<R>(element: &Element, resolver: R) -> Option<PathItemElement>
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let path_item = build_path_item(element)?;
+    let resolved = path_item
+        .r#ref()
+        .and_then(|reference| resolver(&reference.content))
+        .and_then(|target| merge_referenced_path_item(&path_item, &target));
+    Some(resolved.unwrap_or(path_item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn shared_path_item() -> Element {
+        json_source_to_ast(
+            r#"{
+                "summary": "Shared pets",
+                "description": "Pets shared across APIs",
+                "get": { "responses": { "200": { "description": "ok" } } }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn test_ref_siblings_preserved_when_building() {
+        let element = json_source_to_ast(
+            r##"{ "$ref": "#/components/pathItems/Pets", "summary": "Local pets" }"##,
+        );
+
+        let path_item = build_path_item(&element).unwrap();
+        assert!(path_item.is_reference());
+        assert_eq!(path_item.summary().unwrap().content, "Local pets");
+        assert_eq!(
+            path_item.base.meta.properties.get("reference-path"),
+            Some(&Value::String("#/components/pathItems/Pets".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_sibling_summary_and_description_override_target() {
+        let element = json_source_to_ast(
+            r##"{
+                "$ref": "#/components/pathItems/Pets",
+                "summary": "Local pets",
+                "x-owner": "team-a"
+            }"##,
+        );
+        let resolver = |reference: &str| {
+            (reference == "#/components/pathItems/Pets").then(shared_path_item)
+        };
+
+        let path_item = build_and_resolve_path_item(&element, resolver).unwrap();
+
+        assert!(!path_item.is_reference());
+        assert_eq!(path_item.summary().unwrap().content, "Local pets");
+        assert_eq!(path_item.description().unwrap().content, "Pets shared across APIs");
+        assert!(path_item.operation("get").is_some());
+        assert!(path_item.base.has_key("x-owner"));
+        assert_eq!(path_item.base.element, "pathItem");
+        assert_eq!(
+            path_item.base.meta.properties.get("ref-origin"),
+            Some(&Value::String("#/components/pathItems/Pets".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unresolved_reference_kept() {
+        let element = json_source_to_ast(r##"{ "$ref": "#/missing", "description": "kept" }"##);
+
+        let path_item = build_and_resolve_path_item(&element, |_| None).unwrap();
+        assert!(path_item.is_reference());
+        assert_eq!(path_item.description().unwrap().content, "kept");
+    }
+}
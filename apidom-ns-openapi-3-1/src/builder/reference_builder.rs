@@ -0,0 +1,164 @@
+//! OpenAPI 3.1 references with sibling keywords
+//!
+//! In 3.0 an object holding `$ref` is a pure reference and its other
+//! members are ignored. In 3.1 (and JSON Schema 2020-12) siblings such as
+//! `description` or `allOf` stay meaningful, so a reference is resolved by
+//! combining the target with its siblings instead of replacing it.
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+/// Whether `element` is an object holding a string `$ref`, with or without
+/// sibling members
+pub fn is_reference_element(element: &Element) -> bool {
+    reference_of(element).is_some()
+}
+
+/// Whether `element` is a reference with members besides `$ref`
+pub fn has_reference_siblings(element: &Element) -> bool {
+    is_reference_element(element) && element.as_object().is_some_and(|obj| obj.content.len() > 1)
+}
+
+/// Keywords that only annotate a schema and may override the target's value
+pub const ANNOTATION_KEYWORDS: &[&str] = &[
+    "title",
+    "summary",
+    "description",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+    "default",
+    "example",
+    "examples",
+    "$comment",
+];
+
+/// Resolve a reference additively.
+///
+/// Annotation siblings (see [`ANNOTATION_KEYWORDS`]) override the members of
+/// the object `resolver` returns for the `$ref`. Any other sibling is an
+/// assertion that must hold alongside the target, so when one is present the
+/// result is `allOf: [<target>, <other siblings>]` with the annotations next
+/// to it. The `$ref` member itself is dropped and recorded in `ref-origin`
+/// metadata. Returns `None` when `element` is not a reference or the target
+/// is not an object.
+pub fn resolve_reference_with_siblings<R>(element: &Element, resolver: R) -> Option<Element>
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    let reference = reference_of(element)?;
+    let Element::Object(target) = resolver(&reference)? else {
+        return None;
+    };
+
+    let mut annotations = Vec::new();
+    let mut assertions = ObjectElement::new();
+    for member in &element.as_object()?.content {
+        let Element::String(key) = member.key.as_ref() else {
+            continue;
+        };
+        if key.content == "$ref" {
+            continue;
+        }
+        if ANNOTATION_KEYWORDS.contains(&key.content.as_str()) {
+            annotations.push((key.content.clone(), member.value.as_ref().clone()));
+        } else {
+            assertions.set(&key.content, member.value.as_ref().clone());
+        }
+    }
+
+    let mut resolved = if assertions.content.is_empty() {
+        target
+    } else {
+        let mut all_of = ArrayElement::new_empty();
+        all_of.content.push(Element::Object(target));
+        all_of.content.push(Element::Object(assertions));
+        let mut combined = ObjectElement::new();
+        combined.set("allOf", Element::Array(all_of));
+        combined
+    };
+    for (key, value) in annotations {
+        resolved.set(&key, value);
+    }
+
+    resolved
+        .meta
+        .properties
+        .insert("ref-origin".to_string(), Value::String(reference));
+    Some(Element::Object(resolved))
+}
+
+fn reference_of(element: &Element) -> Option<String> {
+    element
+        .as_object()?
+        .get("$ref")
+        .and_then(Element::as_string)
+        .map(|reference| reference.content.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn pet_schema(reference: &str) -> Option<Element> {
+        (reference == "#/components/schemas/Pet").then(|| {
+            json_source_to_ast(
+                r#"{ "type": "object", "description": "A pet", "properties": { "name": { "type": "string" } } }"#,
+            )
+        })
+    }
+
+    #[test]
+    fn test_sibling_description_is_kept() {
+        let element = json_source_to_ast(
+            r##"{ "$ref": "#/components/schemas/Pet", "description": "override" }"##,
+        );
+        assert!(is_reference_element(&element));
+        assert!(has_reference_siblings(&element));
+
+        let resolved = resolve_reference_with_siblings(&element, pet_schema).unwrap();
+        let obj = resolved.as_object().unwrap();
+        assert_eq!(obj.get("description").and_then(Element::as_string).unwrap().content, "override");
+        assert_eq!(obj.get("type").and_then(Element::as_string).unwrap().content, "object");
+        assert!(obj.get("properties").is_some());
+        assert!(!obj.has_key("$ref"));
+        assert_eq!(
+            obj.meta.properties.get("ref-origin"),
+            Some(&Value::String("#/components/schemas/Pet".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_sibling_assertions_keep_target_constraints() {
+        let target = |_: &str| Some(json_source_to_ast(r#"{ "type": "object", "required": ["name"] }"#));
+        let element = json_source_to_ast(
+            r##"{ "$ref": "#/components/schemas/Pet", "required": ["id"], "description": "override" }"##,
+        );
+
+        let resolved = resolve_reference_with_siblings(&element, target).unwrap();
+        let obj = resolved.as_object().unwrap();
+        assert_eq!(obj.get("description").unwrap().to_value(), "override");
+        assert!(!obj.has_key("required"));
+        assert_eq!(
+            obj.get("allOf").unwrap().to_value(),
+            serde_json::json!([
+                { "type": "object", "required": ["name"] },
+                { "required": ["id"] }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_pure_and_unresolved_references() {
+        let pure = json_source_to_ast(r##"{ "$ref": "#/components/schemas/Pet" }"##);
+        assert!(is_reference_element(&pure));
+        assert!(!has_reference_siblings(&pure));
+        let resolved = resolve_reference_with_siblings(&pure, pet_schema).unwrap();
+        assert_eq!(resolved.as_object().unwrap().get("description").unwrap().to_value(), "A pet");
+
+        let missing = json_source_to_ast(r##"{ "$ref": "#/missing", "description": "kept" }"##);
+        assert!(resolve_reference_with_siblings(&missing, pet_schema).is_none());
+        assert!(!is_reference_element(&json_source_to_ast(r#"{ "$ref": 1 }"#)));
+    }
+}
@@ -0,0 +1,117 @@
+//! OpenAPI 3.1 Schema builder
+//!
+//! In 3.1 the `type` keyword follows JSON Schema 2020-12 and may be a single
+//! type name or an array of names (`["string", "null"]` replaces 3.0's
+//! `nullable`). Both forms are accepted; [`SchemaElement::types`] reads
+//! them as one list. Unknown, duplicated or non-string type names are
+//! rejected with `validationError_type` metadata.
+
+use apidom_ast::minim_model::*;
+use serde_json::Value;
+
+use crate::elements::schema::{SchemaElement, SCHEMA_TYPE_NAMES};
+
+/// Build a SchemaElement, validating its `type` keyword
+pub fn build_schema(element: &Element) -> Option<SchemaElement> {
+    let mut schema = SchemaElement::new(element.as_object()?.clone());
+
+    if let Some(error) = schema.type_().and_then(type_error) {
+        schema
+            .base
+            .meta
+            .properties
+            .insert("validationError_type".to_string(), Value::String(error));
+    } else if schema.type_().is_some() {
+        let types = schema.types().into_iter().map(Value::String).collect();
+        schema
+            .base
+            .meta
+            .properties
+            .insert("types".to_string(), Value::Array(types));
+    }
+
+    Some(schema)
+}
+
+/// Why a `type` value is invalid, if it is
+fn type_error(type_: &Element) -> Option<String> {
+    let names: Vec<&str> = match type_ {
+        Element::String(name) => vec![name.content.as_str()],
+        Element::Array(names) => {
+            let strings: Vec<&str> = names
+                .content
+                .iter()
+                .filter_map(Element::as_string)
+                .map(|name| name.content.as_str())
+                .collect();
+            if strings.len() != names.content.len() {
+                return Some("Type array must contain only strings".to_string());
+            }
+            strings
+        }
+        _ => return Some("Expected string or array of strings".to_string()),
+    };
+
+    if let Some(unknown) = names.iter().find(|name| !SCHEMA_TYPE_NAMES.contains(name)) {
+        return Some(format!("Unknown type '{}'", unknown));
+    }
+    if let Some((index, duplicate)) = names
+        .iter()
+        .enumerate()
+        .find(|(index, name)| names[..*index].contains(name))
+    {
+        return Some(format!("Duplicate type '{}' at index {}", duplicate, index));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    fn build(source: &str) -> SchemaElement {
+        build_schema(&json_source_to_ast(source)).unwrap()
+    }
+
+    #[test]
+    fn test_type_array() {
+        let schema = build(r#"{ "type": ["string", "null"], "maxLength": 10 }"#);
+
+        assert_eq!(schema.types(), vec!["string", "null"]);
+        assert!(schema.is_nullable());
+        assert!(!schema.base.meta.properties.contains_key("validationError_type"));
+        assert_eq!(
+            schema.base.meta.properties.get("types"),
+            Some(&serde_json::json!(["string", "null"]))
+        );
+        assert_eq!(schema.base.element, "schema");
+    }
+
+    #[test]
+    fn test_single_type() {
+        let schema = build(r#"{ "type": "integer" }"#);
+
+        assert_eq!(schema.types(), vec!["integer"]);
+        assert!(!schema.is_nullable());
+        assert_eq!(
+            schema.base.meta.properties.get("types"),
+            Some(&serde_json::json!(["integer"]))
+        );
+    }
+
+    #[test]
+    fn test_unknown_type_rejected() {
+        let schema = build(r#"{ "type": ["frob"] }"#);
+        assert_eq!(
+            schema.base.meta.properties.get("validationError_type"),
+            Some(&Value::String("Unknown type 'frob'".to_string()))
+        );
+        assert!(!schema.base.meta.properties.contains_key("types"));
+
+        for invalid in [r#"{ "type": ["string", "string"] }"#, r#"{ "type": ["string", 1] }"#, r#"{ "type": 1 }"#] {
+            assert!(build(invalid).base.meta.properties.contains_key("validationError_type"));
+        }
+        assert!(build("{}").types().is_empty());
+    }
+}
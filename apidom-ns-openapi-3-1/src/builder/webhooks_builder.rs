@@ -0,0 +1,93 @@
+//! OpenAPI 3.1 Webhooks builder
+//!
+//! `webhooks` maps webhook names to path items describing the requests the
+//! API may send. Every entry is built like an entry of `paths`, so `$ref`
+//! entries keep their siblings and can be merged with their target.
+
+use apidom_ast::minim_model::*;
+
+use crate::builder::path_item_builder::{build_and_resolve_path_item, build_path_item};
+use crate::elements::path_item::PathItemElement;
+use crate::elements::webhooks::WebhooksElement;
+
+/// Build a WebhooksElement, building every entry as a path item
+pub fn build_webhooks(element: &Element) -> Option<WebhooksElement> {
+    build_entries(element, build_path_item)
+}
+
+/// Build a WebhooksElement and merge every `$ref` entry with its target.
+///
+/// `resolver` maps a `$ref` value to the referenced path item; entries it
+/// cannot resolve are kept unmerged.
+pub fn build_and_resolve_webhooks<R>(element: &Element, resolver: R) -> Option<WebhooksElement>
+where
+    R: Fn(&str) -> Option<Element>,
+{
+    build_entries(element, |entry| build_and_resolve_path_item(entry, &resolver))
+}
+
+fn build_entries<B>(element: &Element, build_entry: B) -> Option<WebhooksElement>
+where
+    B: Fn(&Element) -> Option<PathItemElement>,
+{
+    let mut webhooks = element.as_object()?.clone();
+    for member in &mut webhooks.content {
+        if let Some(path_item) = build_entry(&member.value) {
+            *member.value = Element::Object(path_item.base);
+        }
+    }
+    Some(WebhooksElement::new(webhooks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::fold::json_source_to_ast;
+
+    #[test]
+    fn test_webhook_entries_are_path_items() {
+        let document = json_source_to_ast(
+            r##"{
+                "openapi": "3.1.0",
+                "webhooks": {
+                    "newPet": { "post": {
+                        "requestBody": { "content": { "application/json": { "schema": { "type": "object" } } } },
+                        "responses": { "200": { "description": "received" } }
+                    } },
+                    "petDeleted": { "$ref": "#/components/pathItems/PetEvent", "summary": "Pet deleted" }
+                }
+            }"##,
+        );
+        let element = document.as_object().and_then(|root| root.get("webhooks")).unwrap();
+
+        let webhooks = build_webhooks(element).unwrap();
+        assert_eq!(webhooks.base.element, "webhooks");
+        assert_eq!(webhooks.names(), vec!["newPet", "petDeleted"]);
+
+        let new_pet = webhooks.webhook("newPet").unwrap();
+        assert_eq!(new_pet.base.element, "pathItem");
+        assert!(new_pet.operation("post").is_some());
+
+        let deleted = webhooks.webhook("petDeleted").unwrap();
+        assert_eq!(deleted.base.element, "pathItem");
+        assert!(deleted.is_reference());
+    }
+
+    #[test]
+    fn test_resolved_webhook_reference() {
+        let element = json_source_to_ast(
+            r##"{ "petDeleted": { "$ref": "#/components/pathItems/PetEvent", "summary": "Pet deleted" } }"##,
+        );
+        let resolver = |reference: &str| {
+            (reference == "#/components/pathItems/PetEvent").then(|| {
+                json_source_to_ast(r#"{ "summary": "Pet event", "post": { "responses": {} } }"#)
+            })
+        };
+
+        let webhooks = build_and_resolve_webhooks(&element, resolver).unwrap();
+        let deleted = webhooks.webhook("petDeleted").unwrap();
+        assert!(!deleted.is_reference());
+        assert_eq!(deleted.summary().unwrap().content, "Pet deleted");
+        assert!(deleted.operation("post").is_some());
+    }
+}
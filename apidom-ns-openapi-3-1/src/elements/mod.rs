@@ -9,7 +9,10 @@ pub mod header;
 pub mod info;
 pub mod json_schema_dialect;
 pub mod license;
+pub mod path_item;
 pub mod reference;
 pub mod request_body;
 pub mod response;
 pub mod responses;
+pub mod schema;
+pub mod webhooks;
@@ -0,0 +1,40 @@
+use apidom_ast::minim_model::{Element, ObjectElement, StringElement};
+
+/// A semantic element representing an OpenAPI 3.1.x "Path Item" object.
+#[derive(Debug, Clone)]
+pub struct PathItemElement {
+    pub base: ObjectElement,
+}
+
+impl PathItemElement {
+    pub fn new(base: ObjectElement) -> Self {
+        let mut base = base;
+        base.set_element_type("pathItem");
+        Self { base }
+    }
+
+    pub fn element_name(&self) -> &'static str {
+        "pathItem"
+    }
+
+    pub fn r#ref(&self) -> Option<&StringElement> {
+        self.base.get("$ref").and_then(Element::as_string)
+    }
+
+    pub fn summary(&self) -> Option<&StringElement> {
+        self.base.get("summary").and_then(Element::as_string)
+    }
+
+    pub fn description(&self) -> Option<&StringElement> {
+        self.base.get("description").and_then(Element::as_string)
+    }
+
+    /// Operation stored under an HTTP method key (`get`, `post`, ...)
+    pub fn operation(&self, method: &str) -> Option<&Element> {
+        self.base.get(method)
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.base.has_key("$ref")
+    }
+}
@@ -0,0 +1,47 @@
+use apidom_ast::minim_model::{Element, ObjectElement};
+
+/// Type names allowed in a 3.1 (JSON Schema 2020-12) `type` keyword
+pub const SCHEMA_TYPE_NAMES: [&str; 7] = ["null", "boolean", "object", "array", "number", "string", "integer"];
+
+/// A semantic element representing an OpenAPI 3.1.x "Schema" object.
+#[derive(Debug, Clone)]
+pub struct SchemaElement {
+    pub base: ObjectElement,
+}
+
+impl SchemaElement {
+    pub fn new(base: ObjectElement) -> Self {
+        let mut base = base;
+        base.set_element_type("schema");
+        Self { base }
+    }
+
+    pub fn element_name(&self) -> &'static str {
+        "schema"
+    }
+
+    /// The `type` keyword as written: a string or an array of strings
+    pub fn type_(&self) -> Option<&Element> {
+        self.base.get("type")
+    }
+
+    /// Declared type names, whether `type` is a single string or an array;
+    /// empty when `type` is absent. Non-string array items are skipped.
+    pub fn types(&self) -> Vec<String> {
+        match self.type_() {
+            Some(Element::String(name)) => vec![name.content.clone()],
+            Some(Element::Array(names)) => names
+                .content
+                .iter()
+                .filter_map(Element::as_string)
+                .map(|name| name.content.clone())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether `null` is one of the declared types
+    pub fn is_nullable(&self) -> bool {
+        self.types().iter().any(|name| name == "null")
+    }
+}
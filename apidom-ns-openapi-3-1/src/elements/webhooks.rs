@@ -0,0 +1,40 @@
+use apidom_ast::minim_model::{Element, ObjectElement};
+
+use crate::elements::path_item::PathItemElement;
+
+/// A semantic element representing the OpenAPI 3.1.x top-level "webhooks"
+/// map of webhook names to path items.
+#[derive(Debug, Clone)]
+pub struct WebhooksElement {
+    pub base: ObjectElement,
+}
+
+impl WebhooksElement {
+    pub fn new(base: ObjectElement) -> Self {
+        let mut base = base;
+        base.set_element_type("webhooks");
+        Self { base }
+    }
+
+    pub fn element_name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    /// Path item registered under a webhook name
+    pub fn webhook(&self, name: &str) -> Option<PathItemElement> {
+        self.base
+            .get(name)
+            .and_then(Element::as_object)
+            .map(|obj| PathItemElement { base: obj.clone() })
+    }
+
+    /// Webhook names in document order
+    pub fn names(&self) -> Vec<&str> {
+        self.base
+            .content
+            .iter()
+            .filter_map(|member| member.key.as_string())
+            .map(|key| key.content.as_str())
+            .collect()
+    }
+}
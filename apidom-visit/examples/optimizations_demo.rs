@@ -141,9 +141,7 @@ fn main() {
             println!("   ⚠️  Max iterations ({}) exceeded, last change at iteration {}", 
                      max_iterations, last_change_iteration.unwrap_or(0));
         }
-        Err(FixedPointError::PassFailed { iteration, error }) => {
-            println!("   ❌ Pass failed at iteration {}: {}", iteration, error);
-        }
+        Err(e) => println!("   ❌ {}", e),
     }
 
     // 5. Optimized Path Usage with const-generics
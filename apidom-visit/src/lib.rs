@@ -1,5 +1,6 @@
 pub mod utils;
 
+use apidom_ast::minim_model::Element;
 use smallvec::SmallVec;
 use std::fmt;
 
@@ -23,6 +24,47 @@ where
     }
 }
 
+/// Visitor over an element tree. Implementations decide which children, if
+/// any, to descend into.
+pub trait Visit {
+    fn visit_element(&mut self, element: &Element);
+}
+
+/// Visitor that is called for every element of a tree. Wrap it in [`All`]
+/// to get a [`Visit`] implementation that performs the traversal.
+pub trait VisitAll {
+    fn visit_all_elements(&mut self, element: &Element);
+}
+
+impl<V> Visit for All<V>
+where
+    V: VisitAll,
+{
+    /// Visit `element`, then recursively every array item and every object
+    /// member key and value below it, in document order
+    fn visit_element(&mut self, element: &Element) {
+        self.visitor.visit_all_elements(element);
+        match element {
+            Element::Array(arr) => {
+                for item in &arr.content {
+                    self.visit_element(item);
+                }
+            }
+            Element::Object(obj) => {
+                for member in &obj.content {
+                    self.visit_element(&member.key);
+                    self.visit_element(&member.value);
+                }
+            }
+            Element::Member(member) => {
+                self.visit_element(&member.key);
+                self.visit_element(&member.value);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Conditional visitor that can be enabled, disabled, or conditionally enabled.
 /// 
 /// This enum provides a zero-cost abstraction for optional visitors that can be:
@@ -142,6 +184,50 @@ where
     }
 }
 
+/// Run `f` on every active visitor, highest priority first.
+///
+/// Disabled visitors and conditional visitors whose condition evaluates to
+/// `false` are skipped. Each condition is evaluated exactly once. Visitors
+/// sharing a priority run in the order they appear in `visitors`.
+///
+/// # Examples
+///
+/// ```
+/// use apidom_visit::{OptionalVisitor, run_in_priority_order};
+///
+/// let visitors = [
+///     OptionalVisitor::enabled("normal"),
+///     OptionalVisitor::disabled(),
+///     OptionalVisitor::high_priority("high"),
+/// ];
+///
+/// let order = run_in_priority_order(&visitors, |name| name.to_string());
+/// assert_eq!(order, vec!["high", "normal"]);
+/// ```
+pub fn run_in_priority_order<V, R>(
+    visitors: &[OptionalVisitor<V>],
+    f: impl Fn(&V) -> R,
+) -> Vec<R> {
+    let mut active: Vec<(VisitorPriority, &V)> = visitors
+        .iter()
+        .filter_map(|visitor| {
+            let inner = match visitor {
+                OptionalVisitor::Disabled => return None,
+                OptionalVisitor::Enabled(v) | OptionalVisitor::HighPriority(v) => v,
+                OptionalVisitor::Conditional { visitor, .. } => visitor,
+            };
+            match visitor.priority() {
+                VisitorPriority::Disabled => None,
+                priority => Some((priority, inner)),
+            }
+        })
+        .collect();
+
+    // The sort is stable, so equal priorities keep their input order
+    active.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
+    active.into_iter().map(|(_, visitor)| f(visitor)).collect()
+}
+
 /// Error types for fixed-point iteration operations.
 ///
 /// This provides more structured error handling compared to generic strings,
@@ -158,6 +244,12 @@ pub enum FixedPointError {
         iteration: usize,
         error: String,
     },
+    /// Pass panicked but succeeded after being retried. This is a diagnostic
+    /// reported by [`run_until_fixed_with_retries`], not a fatal error.
+    Recovered {
+        iteration: usize,
+        retries: usize,
+    },
 }
 
 impl fmt::Display for FixedPointError {
@@ -173,6 +265,9 @@ impl fmt::Display for FixedPointError {
             FixedPointError::PassFailed { iteration, error } => {
                 write!(f, "Pass failed at iteration {}: {}", iteration, error)
             }
+            FixedPointError::Recovered { iteration, retries } => {
+                write!(f, "Pass recovered at iteration {} after {} retries", iteration, retries)
+            }
         }
     }
 }
@@ -714,14 +809,35 @@ where
 
 /// Legacy run_until_fixed with runtime max_iterations for backward compatibility
 pub fn run_until_fixed_legacy<P, T, F>(
+    pass: &mut P,
+    target: &mut T,
+    apply_fn: F,
+    max_iterations: usize,
+) -> Result<usize, FixedPointError>
+where
+    P: Repeated,
+    F: FnMut(&mut P, &mut T),
+{
+    run_until_fixed_with_observer(pass, target, apply_fn, max_iterations, |_, _| {})
+}
+
+/// Run a pass until it reaches a fixed point, reporting progress to `observer`.
+///
+/// `observer` is called after every completed iteration with the zero-based
+/// iteration index and whether the pass changed anything in that iteration,
+/// which makes it suitable for logging or collecting convergence metrics.
+/// Iterations that panic are not reported.
+pub fn run_until_fixed_with_observer<P, T, F, O>(
     pass: &mut P,
     target: &mut T,
     mut apply_fn: F,
     max_iterations: usize,
+    mut observer: O,
 ) -> Result<usize, FixedPointError>
 where
     P: Repeated,
     F: FnMut(&mut P, &mut T),
+    O: FnMut(usize, bool),
 {
     assert!(max_iterations > 0, "max_iterations must be greater than 0");
     
@@ -736,7 +852,9 @@ where
         
         match panic_result {
             Ok(()) => {
-                if pass.changed() {
+                let changed = pass.changed();
+                observer(iteration, changed);
+                if changed {
                     last_change_iteration = Some(iteration);
                 } else {
                     return Ok(iteration + 1);
@@ -757,6 +875,66 @@ where
     })
 }
 
+/// Run a pass until it reaches a fixed point, retrying iterations that panic.
+///
+/// When the pass panics, it is reset and the same iteration is retried up to
+/// `max_retries` times. An iteration that eventually succeeds is recorded as
+/// [`FixedPointError::Recovered`] and processing continues normally; once the
+/// retries are exhausted the loop gives up with [`FixedPointError::PassFailed`].
+/// On success the iteration count is returned together with the recoveries.
+pub fn run_until_fixed_with_retries<P, T, F>(
+    pass: &mut P,
+    target: &mut T,
+    mut apply_fn: F,
+    max_iterations: usize,
+    max_retries: usize,
+) -> Result<(usize, Vec<FixedPointError>), FixedPointError>
+where
+    P: Repeated,
+    F: FnMut(&mut P, &mut T),
+{
+    assert!(max_iterations > 0, "max_iterations must be greater than 0");
+
+    let mut last_change_iteration = None;
+    let mut recoveries = Vec::new();
+
+    for iteration in 0..max_iterations {
+        let mut retries = 0;
+        loop {
+            pass.reset();
+
+            let panic_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                apply_fn(pass, target);
+            }));
+
+            match panic_result {
+                Ok(()) => break,
+                Err(_) if retries < max_retries => retries += 1,
+                Err(_) => {
+                    return Err(FixedPointError::PassFailed {
+                        iteration,
+                        error: format!("Pass panicked during execution ({} retries)", retries),
+                    });
+                }
+            }
+        }
+
+        if retries > 0 {
+            recoveries.push(FixedPointError::Recovered { iteration, retries });
+        }
+        if pass.changed() {
+            last_change_iteration = Some(iteration);
+        } else {
+            return Ok((iteration + 1, recoveries));
+        }
+    }
+
+    Err(FixedPointError::MaxIterationsExceeded {
+        max_iterations,
+        last_change_iteration,
+    })
+}
+
 /// Single AST kind path type with const-generic capacity and optional guards.
 /// 
 /// This is the unified path type that uses const-generics for all configuration.
@@ -837,6 +1015,16 @@ where
     }
 }
 
+impl<K, const N: usize, const GUARDS: bool> Default for AstKindPath<K, N, GUARDS>
+where
+    K: ParentKind,
+{
+    /// An empty path with capacity `N` reserved
+    fn default() -> Self {
+        Self::with_capacity(N)
+    }
+}
+
 /// Single AST node path type with const-generic configuration.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AstNodePath<N, const KN: usize = 8, const NP: usize = 8, const GUARDS: bool = true>
@@ -911,6 +1099,22 @@ where
     pub fn is_empty(&self) -> bool {
         self.path.is_empty()
     }
+    
+    /// Iterate the recorded nodes from the root to the innermost one
+    /// (empty when guards are disabled, since nothing is ever pushed)
+    pub fn ancestors(&self) -> impl Iterator<Item = &N> {
+        self.path.iter()
+    }
+    
+    /// Get the innermost recorded node, i.e. the parent of the node being visited
+    pub fn parent(&self) -> Option<&N> {
+        self.path.last()
+    }
+    
+    /// Get the node at `depth`, where depth 0 is the root
+    pub fn at_depth(&self, depth: usize) -> Option<&N> {
+        self.path.get(depth)
+    }
 }
 
 /// Trait for node references in AST paths
@@ -978,6 +1182,39 @@ pub trait ConfigTrait {
     const ENABLE_GUARDS: bool;
 }
 
+/// Bridge from a configuration to the path type it describes.
+///
+/// Const generics cannot be computed from `ConfigTrait` constants on stable
+/// Rust, so each configuration names its path type explicitly; the type's
+/// capacity and `GUARDS` parameters must match `PATH_CAPACITY` and
+/// `ENABLE_GUARDS`.
+pub trait PathConfig: ConfigTrait {
+    /// Kind path with this configuration's capacity and guard setting
+    type KindPath<K: ParentKind>: Default;
+}
+
+/// Create an empty kind path configured by `C`.
+///
+/// # Examples
+///
+/// ```
+/// use apidom_visit::{path_for_config, CompactConfig, ParentKind};
+///
+/// #[derive(Clone, Copy)]
+/// struct Kind;
+///
+/// impl ParentKind for Kind {
+///     fn set_index(&mut self, _index: usize) {}
+/// }
+///
+/// let mut path = path_for_config::<CompactConfig, Kind>();
+/// path.push(Kind);
+/// assert_eq!(path.depth(), 0); // guards are disabled
+/// ```
+pub fn path_for_config<C: PathConfig, K: ParentKind>() -> C::KindPath<K> {
+    C::KindPath::<K>::default()
+}
+
 /// Default configuration with balanced settings.
 pub struct DefaultConfig;
 impl ConfigTrait for DefaultConfig {
@@ -985,6 +1222,9 @@ impl ConfigTrait for DefaultConfig {
     const MAX_ITERATIONS: usize = 100;
     const ENABLE_GUARDS: bool = true;
 }
+impl PathConfig for DefaultConfig {
+    type KindPath<K: ParentKind> = AstKindPath<K, 8, true>;
+}
 
 /// High-performance configuration with larger buffers.
 pub struct PerformanceConfig;
@@ -993,6 +1233,9 @@ impl ConfigTrait for PerformanceConfig {
     const MAX_ITERATIONS: usize = 200;
     const ENABLE_GUARDS: bool = true;
 }
+impl PathConfig for PerformanceConfig {
+    type KindPath<K: ParentKind> = AstKindPath<K, 16, true>;
+}
 
 /// Memory-optimized configuration with smaller buffers.
 pub struct CompactConfig;
@@ -1001,6 +1244,9 @@ impl ConfigTrait for CompactConfig {
     const MAX_ITERATIONS: usize = 50;
     const ENABLE_GUARDS: bool = false;
 }
+impl PathConfig for CompactConfig {
+    type KindPath<K: ParentKind> = AstKindPath<K, 4, false>;
+}
 
 /// Simple RAII guard for automatic cleanup with zero overhead when guards are disabled.
 pub struct SimpleGuard<T> {
@@ -1046,3 +1292,219 @@ impl<T> Drop for SimpleGuard<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apidom_ast::minim_model::{ArrayElement, BooleanElement, ObjectElement, StringElement};
+
+    #[test]
+    fn test_run_in_priority_order_mixed_visitors() {
+        let visitors = [
+            OptionalVisitor::enabled("normal-1"),
+            OptionalVisitor::disabled(),
+            OptionalVisitor::high_priority("high-1"),
+            OptionalVisitor::conditional("conditional-off", (|| false) as fn() -> bool),
+            OptionalVisitor::conditional("conditional-on", (|| true) as fn() -> bool),
+            OptionalVisitor::enabled("normal-2"),
+            OptionalVisitor::high_priority("high-2"),
+        ];
+
+        let order = run_in_priority_order(&visitors, |name| *name);
+        assert_eq!(
+            order,
+            vec!["high-1", "high-2", "normal-1", "conditional-on", "normal-2"]
+        );
+    }
+
+    #[test]
+    fn test_run_in_priority_order_all_inactive() {
+        let visitors = [
+            OptionalVisitor::<u8>::disabled(),
+            OptionalVisitor::conditional(1, (|| false) as fn() -> bool),
+        ];
+
+        let results = run_in_priority_order(&visitors, |v| *v);
+        assert!(results.is_empty());
+    }
+
+    /// Pass that reports a change on each of its first `remaining` runs
+    struct CountdownPass {
+        remaining: usize,
+        changed: bool,
+    }
+
+    impl Repeated for CountdownPass {
+        fn changed(&self) -> bool {
+            self.changed
+        }
+
+        fn reset(&mut self) {
+            self.changed = false;
+        }
+    }
+
+    #[test]
+    fn test_run_until_fixed_with_observer_records_iterations() {
+        let mut pass = CountdownPass { remaining: 2, changed: false };
+        let mut target = 0;
+        let mut observed = Vec::new();
+
+        let result = run_until_fixed_with_observer(
+            &mut pass,
+            &mut target,
+            |pass, target| {
+                if pass.remaining > 0 {
+                    pass.remaining -= 1;
+                    pass.changed = true;
+                    *target += 1;
+                }
+            },
+            10,
+            |iteration, changed| observed.push((iteration, changed)),
+        );
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(target, 2);
+        assert_eq!(observed, vec![(0, true), (1, true), (2, false)]);
+    }
+
+    /// Pass that panics on its first run only
+    struct FlakyPass {
+        panicked: bool,
+        changed: bool,
+    }
+
+    impl Repeated for FlakyPass {
+        fn changed(&self) -> bool {
+            self.changed
+        }
+
+        fn reset(&mut self) {
+            self.changed = false;
+        }
+    }
+
+    fn flaky_apply(pass: &mut FlakyPass, _target: &mut ()) {
+        if !pass.panicked {
+            pass.panicked = true;
+            panic!("flaky pass");
+        }
+    }
+
+    #[test]
+    fn test_run_until_fixed_with_retries_recovers() {
+        let mut pass = FlakyPass { panicked: false, changed: false };
+
+        let result = run_until_fixed_with_retries(&mut pass, &mut (), flaky_apply, 10, 1);
+        assert_eq!(
+            result,
+            Ok((1, vec![FixedPointError::Recovered { iteration: 0, retries: 1 }]))
+        );
+    }
+
+    #[test]
+    fn test_run_until_fixed_with_retries_fails_without_retries() {
+        let mut pass = FlakyPass { panicked: false, changed: false };
+
+        let result = run_until_fixed_with_retries(&mut pass, &mut (), flaky_apply, 10, 0);
+        assert!(matches!(result, Err(FixedPointError::PassFailed { iteration: 0, .. })));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct TestKind;
+
+    impl ParentKind for TestKind {
+        fn set_index(&mut self, _index: usize) {}
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct TestNode(u8);
+
+    impl NodeRef for TestNode {
+        type ParentKind = TestKind;
+
+        fn kind(&self) -> TestKind {
+            TestKind
+        }
+
+        fn set_index(&mut self, _index: usize) {}
+    }
+
+    #[test]
+    fn test_ast_node_path_ancestor_queries() {
+        let mut path: AstNodePath<TestNode> = AstNodePath::with_capacity(4);
+        for id in 1..=3 {
+            path.push(TestNode(id));
+        }
+
+        let ancestors: Vec<u8> = path.ancestors().map(|node| node.0).collect();
+        assert_eq!(ancestors, vec![1, 2, 3]);
+        assert_eq!(path.parent(), Some(&TestNode(3)));
+        assert_eq!(path.at_depth(0), Some(&TestNode(1)));
+        assert_eq!(path.at_depth(2), Some(&TestNode(3)));
+        assert_eq!(path.at_depth(3), None);
+
+        path.pop();
+        assert_eq!(path.parent(), Some(&TestNode(2)));
+    }
+
+    #[test]
+    fn test_ast_node_path_without_guards_is_empty() {
+        let mut path: AstNodePath<TestNode, 4, 4, false> = AstNodePath::with_capacity(4);
+        path.push(TestNode(1));
+
+        assert_eq!(path.ancestors().count(), 0);
+        assert_eq!(path.parent(), None);
+        assert_eq!(path.at_depth(0), None);
+    }
+
+    #[test]
+    fn test_path_for_config_matches_configuration() {
+        let mut compact = path_for_config::<CompactConfig, TestKind>();
+        compact.push(TestKind);
+        assert_eq!(compact.depth(), 0);
+        assert!(compact.capacity() >= CompactConfig::PATH_CAPACITY);
+
+        let mut default = path_for_config::<DefaultConfig, TestKind>();
+        default.push(TestKind);
+        default.push(TestKind);
+        assert_eq!(default.depth(), 2);
+        assert!(default.capacity() >= DefaultConfig::PATH_CAPACITY);
+    }
+
+    #[derive(Default)]
+    struct NodeCounter {
+        total: usize,
+        strings: usize,
+    }
+
+    impl VisitAll for NodeCounter {
+        fn visit_all_elements(&mut self, element: &Element) {
+            self.total += 1;
+            if matches!(element, Element::String(_)) {
+                self.strings += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_visits_every_nested_element() {
+        // { "name": "pet", "tags": ["a", { "id": true }] }
+        let mut inner = ObjectElement::new();
+        inner.set("id", Element::Boolean(BooleanElement::new(true)));
+        let mut tags = ArrayElement::new_empty();
+        tags.content.push(Element::String(StringElement::new("a")));
+        tags.content.push(Element::Object(inner));
+        let mut root = ObjectElement::new();
+        root.set("name", Element::String(StringElement::new("pet")));
+        root.set("tags", Element::Array(tags));
+
+        let mut all = All::<NodeCounter>::default();
+        all.visit_element(&Element::Object(root));
+
+        // root, "name", "pet", "tags", [..], "a", {..}, "id", true
+        assert_eq!(all.visitor.total, 9);
+        assert_eq!(all.visitor.strings, 5);
+    }
+}
@@ -301,7 +301,7 @@ mod tests {
     fn test_vec_move_flat_map_empty() {
         let vec: Vec<i32> = vec![];
         let result = vec.move_flat_map(|x| vec![x, x + 1]);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -414,8 +414,8 @@ mod tests {
     #[test]
     fn test_empty_vec_operations() {
         let empty_vec: Vec<i32> = vec![];
-        assert_eq!(empty_vec.clone().move_map(|x| x + 1), vec![]);
-        assert_eq!(empty_vec.move_flat_map(|x| vec![x, x]), vec![]);
+        assert_eq!(empty_vec.clone().move_map(|x| x + 1), Vec::<i32>::new());
+        assert_eq!(empty_vec.move_flat_map(|x| vec![x, x]), Vec::<i32>::new());
     }
 
     #[test]